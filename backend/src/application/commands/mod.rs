@@ -1,9 +1,12 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::domain::value_objects::{Priority, ProjectStatus, TaskStatus, TeamMemberRole, UserRole};
+use crate::domain::entities::{CustomFieldType, WorkflowStatus, WorkflowTransition};
+use crate::domain::value_objects::{Priority, ProjectRole, ProjectStatus, TaskStatus, TeamMemberRole, UserRole};
 
 // ==================== USER COMMANDS ====================
 #[derive(Debug, Deserialize)]
@@ -31,6 +34,8 @@ pub struct CreateProjectCommand {
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
     pub budget: Option<Decimal>,
+    /// Project template to copy; its dates are shifted onto `start_date`, or now when unset
+    pub template_id: Option<Uuid>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -44,28 +49,348 @@ pub struct UpdateProjectCommand {
     pub budget: Option<Decimal>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct AddProjectMemberCommand {
+    pub user_id: Uuid,
+    /// Defaults to contributor
+    pub role: Option<ProjectRole>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateProjectMemberCommand {
+    pub role: ProjectRole,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SaveProjectTemplateCommand {
+    /// Project whose milestones, tasks and members are copied
+    pub project_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+}
+
 // ==================== TASK COMMANDS ====================
 #[derive(Debug, Deserialize)]
 pub struct CreateTaskCommand {
     pub project_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    /// Required unless a template provides the title
+    #[serde(default)]
     pub title: String,
     pub description: Option<String>,
     pub priority: Option<Priority>,
     pub assignee_id: Option<Uuid>,
+    pub start_date: Option<DateTime<Utc>>,
     pub due_date: Option<DateTime<Utc>>,
     pub estimated_hours: Option<f32>,
+    pub story_points: Option<i32>,
+    /// Milestone of the same project
+    pub milestone_id: Option<Uuid>,
+    /// Task template whose defaults, tags and checklist are applied
+    pub template_id: Option<Uuid>,
+    /// Values keyed by custom field id
+    #[serde(default)]
+    pub custom_fields: HashMap<Uuid, Value>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct UpdateTaskCommand {
+    pub parent_id: Option<Uuid>,
+    /// Detach the task from its parent instead of setting one
+    #[serde(default)]
+    pub clear_parent: bool,
     pub title: Option<String>,
     pub description: Option<String>,
     pub status: Option<TaskStatus>,
+    /// Project workflow status key; takes precedence over `status`
+    pub workflow_status: Option<String>,
     pub priority: Option<Priority>,
     pub assignee_id: Option<Uuid>,
+    pub start_date: Option<DateTime<Utc>>,
     pub due_date: Option<DateTime<Utc>>,
     pub estimated_hours: Option<f32>,
     pub actual_hours: Option<f32>,
+    pub story_points: Option<i32>,
+    /// Milestone of the same project
+    pub milestone_id: Option<Uuid>,
+    /// Clear the milestone instead of setting one
+    #[serde(default)]
+    pub clear_milestone: bool,
+    /// Values keyed by custom field id; `null` clears a value, omitted fields are kept
+    #[serde(default)]
+    pub custom_fields: HashMap<Uuid, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AddTaskDependencyCommand {
+    pub blocking_task_id: Uuid,
+}
+
+/// Drop a task into a board column between two neighbours
+#[derive(Debug, Deserialize)]
+pub struct MoveTaskCommand {
+    /// Project workflow status key of the target column; takes precedence over `status`
+    pub workflow_status: Option<String>,
+    /// Target column by status category; the task stays in its column when both are omitted
+    pub status: Option<TaskStatus>,
+    /// Task that ends up directly above the moved task
+    pub before_id: Option<Uuid>,
+    /// Task that ends up directly below the moved task; the task goes to the bottom when both are omitted
+    pub after_id: Option<Uuid>,
+    /// Move the task and its subtasks to another project
+    pub project_id: Option<Uuid>,
+}
+
+/// Copy a task, optionally into another project
+#[derive(Debug, Deserialize)]
+pub struct CloneTaskCommand {
+    /// Target project; defaults to the task's own project
+    pub project_id: Option<Uuid>,
+    /// Title of the copy; defaults to the original title
+    pub title: Option<String>,
+    #[serde(default)]
+    pub include_time_logs: bool,
+}
+
+/// One set of changes applied to many tasks at once
+#[derive(Debug, Deserialize)]
+pub struct BulkTaskCommand {
+    pub task_ids: Vec<Uuid>,
+    pub status: Option<TaskStatus>,
+    /// Project workflow status key; takes precedence over `status`
+    pub workflow_status: Option<String>,
+    pub priority: Option<Priority>,
+    pub assignee_id: Option<Uuid>,
+    /// Clear the assignee instead of setting one
+    #[serde(default)]
+    pub unassign: bool,
+    pub milestone_id: Option<Uuid>,
+    /// Clear the milestone instead of setting one
+    #[serde(default)]
+    pub clear_milestone: bool,
+    /// Move existing due dates by this many days (negative moves earlier)
+    pub due_date_shift_days: Option<i64>,
+    #[serde(default)]
+    pub add_tag_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub remove_tag_ids: Vec<Uuid>,
+    /// Delete the tasks; cannot be combined with other changes
+    #[serde(default)]
+    pub delete: bool,
+    /// Write nothing if any task fails its checks
+    #[serde(default)]
+    pub atomic: bool,
+}
+
+// ==================== CUSTOM FIELD COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateCustomFieldCommand {
+    pub name: String,
+    pub field_type: CustomFieldType,
+    #[serde(default)]
+    pub options: Vec<String>,
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// The field type cannot change once values may exist
+#[derive(Debug, Deserialize)]
+pub struct UpdateCustomFieldCommand {
+    pub name: Option<String>,
+    pub options: Option<Vec<String>>,
+    pub required: Option<bool>,
+}
+
+// ==================== MILESTONE COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateMilestoneCommand {
+    pub name: String,
+    pub description: Option<String>,
+    pub due_date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateMilestoneCommand {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub due_date: Option<DateTime<Utc>>,
+}
+
+// ==================== SPRINT COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateSprintCommand {
+    pub name: String,
+    pub goal: Option<String>,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateSprintCommand {
+    pub name: Option<String>,
+    pub goal: Option<String>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+}
+
+/// Tasks to plan into a sprint
+#[derive(Debug, Deserialize)]
+pub struct PlanSprintCommand {
+    pub task_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CloseSprintCommand {
+    /// Planned or active sprint receiving the unfinished tasks; they return to the backlog when omitted
+    pub carry_over_to: Option<Uuid>,
+}
+
+// ==================== SLA POLICY COMMANDS ====================
+/// Targets are minutes from task creation
+#[derive(Debug, Deserialize)]
+pub struct CreateSlaPolicyCommand {
+    pub priority: Priority,
+    pub response_minutes: Option<i32>,
+    pub resolution_minutes: Option<i32>,
+    /// Count working hours only; defaults to true
+    pub business_hours: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateSlaPolicyCommand {
+    pub response_minutes: Option<i32>,
+    pub resolution_minutes: Option<i32>,
+    pub business_hours: Option<bool>,
+}
+
+// ==================== BUDGET COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateCostRateCommand {
+    pub hourly_rate: Decimal,
+    /// Time logged from this date on is charged at the rate
+    pub effective_from: NaiveDate,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateExpenseCommand {
+    pub category: String,
+    pub description: Option<String>,
+    pub amount: Decimal,
+    pub incurred_on: NaiveDate,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateExpenseCommand {
+    pub category: Option<String>,
+    pub description: Option<String>,
+    pub amount: Option<Decimal>,
+    pub incurred_on: Option<NaiveDate>,
+}
+
+// ==================== RECURRING TASK COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateRecurringTaskCommand {
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Option<Priority>,
+    pub assignee_id: Option<Uuid>,
+    pub estimated_hours: Option<f32>,
+    /// RFC 5545 recurrence rule, e.g. `FREQ=WEEKLY;BYDAY=MO`
+    pub rrule: String,
+    pub dtstart: DateTime<Utc>,
+    pub lead_days: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateRecurringTaskCommand {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub priority: Option<Priority>,
+    pub assignee_id: Option<Uuid>,
+    pub estimated_hours: Option<f32>,
+    pub rrule: Option<String>,
+    pub dtstart: Option<DateTime<Utc>>,
+    pub lead_days: Option<i32>,
+    pub is_active: Option<bool>,
+}
+
+/// Skip or override a single occurrence without changing the series
+#[derive(Debug, Deserialize)]
+pub struct UpdateRecurringOccurrenceCommand {
+    #[serde(default)]
+    pub skipped: bool,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub priority: Option<Priority>,
+    pub assignee_id: Option<Uuid>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub estimated_hours: Option<f32>,
+}
+
+// ==================== TASK COMMENT COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateTaskCommentCommand {
+    pub content: String,
+    /// Comment being replied to
+    pub parent_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateTaskCommentCommand {
+    pub content: String,
+}
+
+// ==================== CHECKLIST COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateChecklistItemCommand {
+    pub content: String,
+    /// Zero-based position; appended when omitted
+    pub position: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateChecklistItemCommand {
+    pub content: Option<String>,
+    pub is_checked: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReorderChecklistCommand {
+    pub item_ids: Vec<Uuid>,
+}
+
+// ==================== TASK TEMPLATE COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct CreateTaskTemplateCommand {
+    pub name: String,
+    pub title_pattern: String,
+    pub description: Option<String>,
+    pub priority: Option<Priority>,
+    pub estimated_hours: Option<f32>,
+    #[serde(default)]
+    pub tag_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub checklist: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateTaskTemplateCommand {
+    pub name: Option<String>,
+    pub title_pattern: Option<String>,
+    pub description: Option<String>,
+    pub priority: Option<Priority>,
+    pub estimated_hours: Option<f32>,
+    pub tag_ids: Option<Vec<Uuid>>,
+    pub checklist: Option<Vec<String>>,
+}
+
+// ==================== WORKFLOW COMMANDS ====================
+#[derive(Debug, Deserialize)]
+pub struct SetWorkflowCommand {
+    pub statuses: Vec<WorkflowStatus>,
+    pub transitions: Vec<WorkflowTransition>,
+    #[serde(default)]
+    pub require_subtasks_done: bool,
 }
 
 // ==================== TEAM COMMANDS ====================
@@ -1,7 +1,10 @@
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 use crate::domain::value_objects::TaskStatus;
+use crate::shared::DomainError;
 
 #[derive(Debug, Deserialize, Default)]
 pub struct PaginationQuery {
@@ -10,10 +13,12 @@ pub struct PaginationQuery {
 }
 
 impl PaginationQuery {
+    pub fn page(&self) -> u32 {
+        self.page.unwrap_or(1).max(1)
+    }
+
     pub fn offset(&self) -> u32 {
-        let page = self.page.unwrap_or(1).max(1);
-        let per_page = self.per_page();
-        (page - 1) * per_page
+        (self.page() - 1) * self.per_page()
     }
 
     pub fn per_page(&self) -> u32 {
@@ -21,6 +26,35 @@ impl PaginationQuery {
     }
 }
 
+/// One page of results with the total number of matches
+#[derive(Debug, Serialize)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    pub page: u32,
+    pub per_page: u32,
+    pub total_pages: u32,
+}
+
+impl<T> Paginated<T> {
+    pub fn new(items: Vec<T>, total: i64, pagination: &PaginationQuery) -> Self {
+        let per_page = pagination.per_page();
+        let total_pages = if per_page == 0 {
+            0
+        } else {
+            (total.max(0) as u32).div_ceil(per_page)
+        };
+
+        Self {
+            items,
+            total,
+            page: pagination.page(),
+            per_page,
+            total_pages,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Default)]
 pub struct ProjectQuery {
     pub owner_id: Option<Uuid>,
@@ -32,7 +66,75 @@ pub struct ProjectQuery {
 pub struct TaskQuery {
     pub project_id: Option<Uuid>,
     pub assignee_id: Option<Uuid>,
+    /// Only tasks without an assignee
+    pub unassigned: Option<bool>,
     pub status: Option<TaskStatus>,
-    #[serde(flatten)]
-    pub pagination: PaginationQuery,
+    /// Comma separated priorities, e.g. `high,critical`
+    pub priority: Option<String>,
+    pub due_from: Option<DateTime<Utc>>,
+    pub due_to: Option<DateTime<Utc>>,
+    /// Only unfinished tasks past their due date
+    pub overdue: Option<bool>,
+    /// Comma separated tag ids; tasks with any of the tags match
+    pub tags: Option<String>,
+    pub milestone_id: Option<Uuid>,
+    pub sprint_id: Option<Uuid>,
+    /// Only tasks that breached an SLA target
+    pub sla_breached: Option<bool>,
+    /// Full-text match on title and description
+    pub q: Option<String>,
+    /// Comma separated sort keys, `-` for descending: `-priority,due_date`
+    pub sort: Option<String>,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+    /// Custom field filters given as `cf.<field_id>=<value>`
+    #[serde(skip)]
+    pub custom_fields: HashMap<Uuid, String>,
+}
+
+impl TaskQuery {
+    /// Collect `cf.<field_id>=<value>` parameters into `custom_fields`
+    pub fn with_custom_field_params(mut self, params: HashMap<String, String>) -> Result<Self, DomainError> {
+        for (key, value) in params {
+            if let Some(field_id) = key.strip_prefix("cf.") {
+                let field_id = Uuid::parse_str(field_id)
+                    .map_err(|_| DomainError::ValidationError(format!("Invalid custom field filter '{}'", key)))?;
+                self.custom_fields.insert(field_id, value);
+            }
+        }
+        Ok(self)
+    }
+
+    pub fn pagination(&self) -> PaginationQuery {
+        PaginationQuery {
+            page: self.page,
+            per_page: self.per_page,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct SearchQuery {
+    pub q: String,
+    /// Comma separated entity types: project, task, comment
+    pub r#type: Option<String>,
+    pub project_id: Option<Uuid>,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
+impl SearchQuery {
+    pub fn pagination(&self) -> PaginationQuery {
+        PaginationQuery {
+            page: self.page,
+            per_page: self.per_page,
+        }
+    }
+}
+
+/// Reporting period; open ends are unbounded
+#[derive(Debug, Deserialize, Default)]
+pub struct SlaReportQuery {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
 }
@@ -10,7 +10,8 @@ use tokio::io::AsyncWriteExt;
 use uuid::Uuid;
 
 use crate::domain::entities::Attachment;
-use crate::domain::repositories::AttachmentRepository;
+use crate::domain::repositories::{AttachmentRepository, TaskRepository};
+use crate::domain::value_objects::ProjectRole;
 use crate::shared::DomainError;
 
 const MAX_FILE_SIZE: i64 = 10 * 1024 * 1024; // 10MB
@@ -21,17 +22,34 @@ const ALLOWED_EXTENSIONS: &[&str] = &[
 
 pub struct AttachmentAppService {
     attachment_repository: Arc<dyn AttachmentRepository>,
+    task_repository: Arc<dyn TaskRepository>,
     upload_dir: PathBuf,
 }
 
 impl AttachmentAppService {
-    pub fn new(attachment_repository: Arc<dyn AttachmentRepository>, upload_dir: PathBuf) -> Self {
+    pub fn new(
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        upload_dir: PathBuf,
+    ) -> Self {
         Self {
             attachment_repository,
+            task_repository,
             upload_dir,
         }
     }
 
+    /// Check if user owns the project containing the task or is a member with at least `role`
+    pub async fn has_role(&self, task_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.task_repository.has_role(task_id, user_id, role).await
+    }
+
+    /// Check the user's role on the project of the task the attachment belongs to
+    pub async fn has_attachment_role(&self, id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        let attachment = self.get_attachment(id).await?;
+        self.task_repository.has_role(attachment.task_id, user_id, role).await
+    }
+
     pub async fn get_task_attachments(&self, task_id: Uuid) -> Result<Vec<Attachment>, DomainError> {
         self.attachment_repository.find_by_task(task_id).await
     }
@@ -110,6 +128,44 @@ impl AttachmentAppService {
         self.attachment_repository.create(&attachment).await
     }
 
+    /// Copy every attachment of a task, files included, to another task
+    pub async fn copy_task_attachments(&self, from_task_id: Uuid, to_task_id: Uuid) -> Result<Vec<Attachment>, DomainError> {
+        let attachments = self.attachment_repository.find_by_task(from_task_id).await?;
+        if attachments.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let task_dir = self.upload_dir.join(to_task_id.to_string());
+        fs::create_dir_all(&task_dir).await.map_err(|e| {
+            DomainError::InternalError(format!("Failed to create upload directory: {}", e))
+        })?;
+
+        let mut copies = Vec::with_capacity(attachments.len());
+        for attachment in attachments {
+            let extension = attachment.filename.rsplit('.').next().unwrap_or("");
+            let filename = format!("{}.{}", Uuid::new_v4(), extension);
+            fs::copy(
+                self.upload_dir.join(&attachment.storage_path),
+                task_dir.join(&filename),
+            )
+            .await
+            .map_err(|e| DomainError::InternalError(format!("Failed to copy file: {}", e)))?;
+
+            let storage_path = format!("{}/{}", to_task_id, filename);
+            let copy = Attachment::new(
+                to_task_id,
+                attachment.uploaded_by,
+                filename,
+                attachment.original_filename,
+                attachment.content_type,
+                attachment.size_bytes,
+                storage_path,
+            );
+            copies.push(self.attachment_repository.create(&copy).await?);
+        }
+        Ok(copies)
+    }
+
     pub async fn delete_attachment(&self, id: Uuid) -> Result<(), DomainError> {
         let attachment = self
             .attachment_repository
@@ -129,6 +185,16 @@ impl AttachmentAppService {
         self.attachment_repository.delete(id).await
     }
 
+    /// Remove the stored files of a task whose attachment rows are already gone
+    pub async fn remove_task_files(&self, task_id: Uuid) -> Result<(), DomainError> {
+        match fs::remove_dir_all(self.upload_dir.join(task_id.to_string())).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(DomainError::InternalError(
+                format!("Failed to delete files: {}", e),
+            )),
+            _ => Ok(()),
+        }
+    }
+
     pub async fn get_file_path(&self, id: Uuid) -> Result<PathBuf, DomainError> {
         let attachment = self
             .attachment_repository
@@ -0,0 +1,163 @@
+use chrono::Utc;
+use rust_decimal::Decimal;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateCostRateCommand, CreateExpenseCommand, UpdateExpenseCommand};
+use crate::domain::entities::{CostRate, Expense, Project};
+use crate::domain::repositories::{BudgetRepository, ProjectRepository, UserRepository};
+use crate::domain::services::{Budget, BudgetReport};
+use crate::domain::value_objects::ProjectRole;
+use crate::shared::DomainError;
+
+pub struct BudgetAppService {
+    budget_repository: Arc<dyn BudgetRepository>,
+    project_repository: Arc<dyn ProjectRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl BudgetAppService {
+    pub fn new(
+        budget_repository: Arc<dyn BudgetRepository>,
+        project_repository: Arc<dyn ProjectRepository>,
+        user_repository: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            budget_repository,
+            project_repository,
+            user_repository,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.project_repository.can_user_access(project_id, user_id).await
+    }
+
+    /// Check if user is owner of project or a member with at least `role`
+    pub async fn has_project_role(&self, project_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.project_repository.has_role(project_id, user_id, role).await
+    }
+
+    /// Check if user is owner of the project of the expense or a member with at least `role`
+    pub async fn has_expense_role(&self, id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        let expense = self.get_expense(id).await?;
+        self.project_repository.has_role(expense.project_id, user_id, role).await
+    }
+
+    pub async fn list_rates(&self, user_id: Uuid) -> Result<Vec<CostRate>, DomainError> {
+        self.budget_repository.find_rates(user_id).await
+    }
+
+    /// A user has at most one rate per effective date
+    pub async fn create_rate(&self, user_id: Uuid, cmd: CreateCostRateCommand) -> Result<CostRate, DomainError> {
+        self.user_repository
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+
+        let existing = self.budget_repository.find_rates(user_id).await?;
+        if existing.iter().any(|rate| rate.effective_from == cmd.effective_from) {
+            return Err(DomainError::AlreadyExists(format!(
+                "User already has a cost rate effective from {}",
+                cmd.effective_from
+            )));
+        }
+
+        let rate = CostRate::new(user_id, cmd.hourly_rate, cmd.effective_from);
+        rate.validate()?;
+
+        self.budget_repository.create_rate(&rate).await
+    }
+
+    /// Time logged while the rate applied falls back to the user's previous rate
+    pub async fn delete_rate(&self, id: Uuid) -> Result<(), DomainError> {
+        self.budget_repository
+            .find_rate(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Cost rate not found".into()))?;
+        self.budget_repository.delete_rate(id).await
+    }
+
+    pub async fn list_expenses(&self, project_id: Uuid) -> Result<Vec<Expense>, DomainError> {
+        self.budget_repository.find_expenses(project_id).await
+    }
+
+    pub async fn get_expense(&self, id: Uuid) -> Result<Expense, DomainError> {
+        self.budget_repository
+            .find_expense(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Expense not found".into()))
+    }
+
+    pub async fn create_expense(
+        &self,
+        project_id: Uuid,
+        cmd: CreateExpenseCommand,
+        user_id: Uuid,
+    ) -> Result<Expense, DomainError> {
+        self.get_project(project_id).await?;
+
+        let expense = Expense::new(
+            project_id,
+            cmd.category.trim().to_string(),
+            cmd.description,
+            cmd.amount,
+            cmd.incurred_on,
+            user_id,
+        );
+        expense.validate()?;
+
+        self.budget_repository.create_expense(&expense).await
+    }
+
+    pub async fn update_expense(&self, id: Uuid, cmd: UpdateExpenseCommand) -> Result<Expense, DomainError> {
+        let mut expense = self.get_expense(id).await?;
+        if let Some(category) = cmd.category {
+            expense.category = category.trim().to_string();
+        }
+        if let Some(description) = cmd.description {
+            expense.description = Some(description);
+        }
+        if let Some(amount) = cmd.amount {
+            expense.amount = amount;
+        }
+        if let Some(incurred_on) = cmd.incurred_on {
+            expense.incurred_on = incurred_on;
+        }
+        expense.validate()?;
+
+        self.budget_repository.update_expense(&expense).await
+    }
+
+    pub async fn delete_expense(&self, id: Uuid) -> Result<(), DomainError> {
+        self.get_expense(id).await?;
+        self.budget_repository.delete_expense(id).await
+    }
+
+    /// Spend, remaining budget, burn rate and forecast, broken down by user, team and month
+    pub async fn get_report(&self, project_id: Uuid) -> Result<BudgetReport, DomainError> {
+        let project = self.get_project(project_id).await?;
+        let labour = self.budget_repository.find_labour(project_id).await?;
+        let rates = self.budget_repository.find_project_rates(project_id).await?;
+        let expenses = self.budget_repository.find_expenses(project_id).await?;
+
+        Ok(Budget::report(&project, &labour, &rates, &expenses, Utc::now().date_naive()))
+    }
+
+    /// Labour and expenses charged to the project so far
+    pub async fn get_spent(&self, project: &Project) -> Result<Decimal, DomainError> {
+        let labour = self.budget_repository.find_labour(project.id).await?;
+        let rates = self.budget_repository.find_project_rates(project.id).await?;
+        let expenses = self.budget_repository.find_expenses(project.id).await?;
+
+        Ok(Budget::spent(&labour, &rates, &expenses))
+    }
+
+    async fn get_project(&self, project_id: Uuid) -> Result<Project, DomainError> {
+        self.project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Project not found".into()))
+    }
+}
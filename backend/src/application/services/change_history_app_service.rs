@@ -0,0 +1,63 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::entities::{ChangeEntityType, ChangeRecord};
+use crate::domain::repositories::{ChangeHistoryRepository, ProjectRepository, TaskRepository};
+use crate::shared::DomainError;
+
+pub struct ChangeHistoryAppService {
+    history_repository: Arc<dyn ChangeHistoryRepository>,
+    project_repository: Arc<dyn ProjectRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+}
+
+impl ChangeHistoryAppService {
+    pub fn new(
+        history_repository: Arc<dyn ChangeHistoryRepository>,
+        project_repository: Arc<dyn ProjectRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+    ) -> Self {
+        Self {
+            history_repository,
+            project_repository,
+            task_repository,
+        }
+    }
+
+    /// Changes of the entity, newest first; empty when nothing was recorded
+    pub async fn get_history(
+        &self,
+        entity_type: ChangeEntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<ChangeRecord>, DomainError> {
+        self.history_repository.find_by_entity(entity_type, entity_id).await
+    }
+
+    /// Check if user can access the entity. Live tasks and projects use their own access
+    /// check; deleted ones fall back to the project they last belonged to.
+    pub async fn can_user_access(
+        &self,
+        entity_type: ChangeEntityType,
+        entity_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        match entity_type {
+            ChangeEntityType::Task => {
+                if self.task_repository.find_by_id(entity_id).await?.is_some() {
+                    return self.task_repository.can_user_access(entity_id, user_id).await;
+                }
+            }
+            ChangeEntityType::Project => {
+                if self.project_repository.find_by_id(entity_id).await?.is_some() {
+                    return self.project_repository.can_user_access(entity_id, user_id).await;
+                }
+            }
+        }
+
+        let history = self.history_repository.find_by_entity(entity_type, entity_id).await?;
+        match history.first() {
+            Some(latest) => self.project_repository.can_user_access(latest.project_id, user_id).await,
+            None => Ok(false),
+        }
+    }
+}
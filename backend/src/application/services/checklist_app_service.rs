@@ -0,0 +1,116 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{
+    CreateChecklistItemCommand, ReorderChecklistCommand, UpdateChecklistItemCommand,
+};
+use crate::domain::entities::{Checklist, ChecklistItem};
+use crate::domain::repositories::{ChecklistRepository, TaskRepository};
+use crate::domain::value_objects::ProjectRole;
+use crate::shared::DomainError;
+
+pub struct ChecklistAppService {
+    checklist_repository: Arc<dyn ChecklistRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+}
+
+impl ChecklistAppService {
+    pub fn new(
+        checklist_repository: Arc<dyn ChecklistRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+    ) -> Self {
+        Self {
+            checklist_repository,
+            task_repository,
+        }
+    }
+
+    /// Check if user can access task (via project access)
+    pub async fn can_user_access(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.task_repository.can_user_access(task_id, user_id).await
+    }
+
+    /// Check if user owns the project containing the task or is a member with at least `role`
+    pub async fn has_role(&self, task_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.task_repository.has_role(task_id, user_id, role).await
+    }
+
+    pub async fn get_checklist(&self, task_id: Uuid) -> Result<Checklist, DomainError> {
+        self.ensure_task_exists(task_id).await?;
+        let items = self.checklist_repository.find_by_task(task_id).await?;
+        Ok(Checklist::new(items))
+    }
+
+    pub async fn add_item(
+        &self,
+        task_id: Uuid,
+        cmd: CreateChecklistItemCommand,
+    ) -> Result<ChecklistItem, DomainError> {
+        self.ensure_task_exists(task_id).await?;
+        let content = validate_content(cmd.content)?;
+
+        let count = self.checklist_repository.find_by_task(task_id).await?.len() as i32;
+        let position = cmd.position.unwrap_or(count).clamp(0, count);
+
+        let item = ChecklistItem::new(task_id, content, position);
+        self.checklist_repository.create(&item).await
+    }
+
+    pub async fn update_item(
+        &self,
+        task_id: Uuid,
+        item_id: Uuid,
+        cmd: UpdateChecklistItemCommand,
+        user_id: Uuid,
+    ) -> Result<ChecklistItem, DomainError> {
+        let mut item = self.get_item(task_id, item_id).await?;
+
+        if let Some(content) = cmd.content {
+            item.content = validate_content(content)?;
+        }
+        if let Some(is_checked) = cmd.is_checked {
+            item.set_checked(is_checked, user_id);
+        }
+
+        self.checklist_repository.update(&item).await
+    }
+
+    pub async fn delete_item(&self, task_id: Uuid, item_id: Uuid) -> Result<(), DomainError> {
+        self.get_item(task_id, item_id).await?;
+        self.checklist_repository.delete(item_id).await
+    }
+
+    /// Reorder the checklist; the command must list every item once
+    pub async fn reorder(&self, task_id: Uuid, cmd: ReorderChecklistCommand) -> Result<Checklist, DomainError> {
+        self.ensure_task_exists(task_id).await?;
+        let items = self.checklist_repository.find_by_task(task_id).await?;
+        Checklist::validate_order(&items, &cmd.item_ids)?;
+
+        self.checklist_repository.reorder(task_id, &cmd.item_ids).await?;
+        self.get_checklist(task_id).await
+    }
+
+    async fn get_item(&self, task_id: Uuid, item_id: Uuid) -> Result<ChecklistItem, DomainError> {
+        self.checklist_repository
+            .find_by_id(item_id)
+            .await?
+            .filter(|item| item.task_id == task_id)
+            .ok_or_else(|| DomainError::NotFound("Checklist item not found".into()))
+    }
+
+    async fn ensure_task_exists(&self, task_id: Uuid) -> Result<(), DomainError> {
+        self.task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Task not found".into()))?;
+        Ok(())
+    }
+}
+
+fn validate_content(content: String) -> Result<String, DomainError> {
+    let content = content.trim();
+    if content.is_empty() {
+        return Err(DomainError::ValidationError("Checklist item cannot be empty".into()));
+    }
+    Ok(content.to_string())
+}
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateCustomFieldCommand, UpdateCustomFieldCommand};
+use crate::domain::entities::CustomField;
+use crate::domain::repositories::{CustomFieldRepository, ProjectRepository};
+use crate::domain::value_objects::ProjectRole;
+use crate::shared::DomainError;
+
+pub struct CustomFieldAppService {
+    custom_field_repository: Arc<dyn CustomFieldRepository>,
+    project_repository: Arc<dyn ProjectRepository>,
+}
+
+impl CustomFieldAppService {
+    pub fn new(
+        custom_field_repository: Arc<dyn CustomFieldRepository>,
+        project_repository: Arc<dyn ProjectRepository>,
+    ) -> Self {
+        Self {
+            custom_field_repository,
+            project_repository,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.project_repository.can_user_access(project_id, user_id).await
+    }
+
+    /// Check if user is owner of project or a member with at least `role`
+    pub async fn has_project_role(&self, project_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.project_repository.has_role(project_id, user_id, role).await
+    }
+
+    /// Check if user is owner of the project of the field or a member with at least `role`
+    pub async fn has_field_role(&self, id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        let field = self.get_field(id).await?;
+        self.project_repository.has_role(field.project_id, user_id, role).await
+    }
+
+    pub async fn list_fields(&self, project_id: Uuid) -> Result<Vec<CustomField>, DomainError> {
+        self.custom_field_repository.find_by_project(project_id).await
+    }
+
+    pub async fn get_field(&self, id: Uuid) -> Result<CustomField, DomainError> {
+        self.custom_field_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Custom field not found".into()))
+    }
+
+    pub async fn create_field(
+        &self,
+        project_id: Uuid,
+        cmd: CreateCustomFieldCommand,
+    ) -> Result<CustomField, DomainError> {
+        self.project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
+
+        let field = CustomField::new(
+            project_id,
+            cmd.name.trim().to_string(),
+            cmd.field_type,
+            cmd.options,
+            cmd.required,
+        );
+        field.validate()?;
+        self.ensure_unique_name(&field).await?;
+
+        self.custom_field_repository.create(&field).await
+    }
+
+    /// Existing tasks are not re-validated when a field becomes required;
+    /// they must provide a value the next time the field is changed
+    pub async fn update_field(&self, id: Uuid, cmd: UpdateCustomFieldCommand) -> Result<CustomField, DomainError> {
+        let mut field = self.get_field(id).await?;
+
+        if let Some(name) = cmd.name {
+            field.name = name.trim().to_string();
+        }
+        if let Some(options) = cmd.options {
+            for removed in field.options.iter().filter(|o| !options.contains(o)) {
+                if self.custom_field_repository.is_option_used(id, removed).await? {
+                    return Err(DomainError::ValidationError(format!(
+                        "Option '{}' is still used by tasks",
+                        removed
+                    )));
+                }
+            }
+            field.options = options;
+        }
+        if let Some(required) = cmd.required {
+            field.required = required;
+        }
+        field.validate()?;
+        self.ensure_unique_name(&field).await?;
+
+        self.custom_field_repository.update(&field).await
+    }
+
+    pub async fn delete_field(&self, id: Uuid) -> Result<(), DomainError> {
+        self.get_field(id).await?;
+        self.custom_field_repository.delete(id).await
+    }
+
+    async fn ensure_unique_name(&self, field: &CustomField) -> Result<(), DomainError> {
+        let fields = self.custom_field_repository.find_by_project(field.project_id).await?;
+        if fields
+            .iter()
+            .any(|f| f.id != field.id && f.name.eq_ignore_ascii_case(&field.name))
+        {
+            return Err(DomainError::AlreadyExists(format!(
+                "Custom field '{}' already exists in this project",
+                field.name
+            )));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+use crate::application::services::NotificationAppService;
+use crate::domain::entities::NotificationType;
+use crate::domain::repositories::{DueItem, DueItemType, DueReminderRepository};
+use crate::domain::services::{DueReminder, ReminderSchedule};
+use crate::shared::DomainError;
+
+pub struct DueReminderAppService {
+    due_reminder_repository: Arc<dyn DueReminderRepository>,
+    notification_service: Arc<NotificationAppService>,
+    schedule: ReminderSchedule,
+}
+
+impl DueReminderAppService {
+    pub fn new(
+        due_reminder_repository: Arc<dyn DueReminderRepository>,
+        notification_service: Arc<NotificationAppService>,
+        schedule: ReminderSchedule,
+    ) -> Self {
+        Self {
+            due_reminder_repository,
+            notification_service,
+            schedule,
+        }
+    }
+
+    /// Notify assignees and project owners of tasks and milestones that are
+    /// due soon or overdue. Each reminder is sent once per due date, so moving
+    /// the due date re-arms them. Returns how many reminders were sent.
+    pub async fn send_due_reminders(&self, now: DateTime<Utc>) -> Result<usize, DomainError> {
+        let items = self
+            .due_reminder_repository
+            .find_due_items(now + self.schedule.horizon())
+            .await?;
+
+        let mut sent = 0;
+        for item in items {
+            let Some(reminder) = self.schedule.next_reminder(item.due_date, now, item.last_lead_minutes) else {
+                continue;
+            };
+            // Another instance may have claimed the reminder first
+            if !self.due_reminder_repository.record(&item, reminder.lead_minutes()).await? {
+                continue;
+            }
+            self.notify(&item, reminder).await?;
+            sent += 1;
+        }
+        Ok(sent)
+    }
+
+    async fn notify(&self, item: &DueItem, reminder: DueReminder) -> Result<(), DomainError> {
+        let (kind, link) = match item.item_type {
+            DueItemType::Task => ("Task", format!("/tasks/{}", item.id)),
+            DueItemType::Milestone => ("Milestone", format!("/projects/{}", item.project_id)),
+        };
+        let (notification_type, title) = match (item.item_type, reminder) {
+            (DueItemType::Task, DueReminder::DueSoon(lead)) => (
+                NotificationType::TaskDueSoon,
+                format!("{} \"{}\" is due in {}", kind, item.title, DueReminder::describe_lead(lead)),
+            ),
+            (DueItemType::Task, DueReminder::Overdue) => (
+                NotificationType::TaskOverdue,
+                format!("{} \"{}\" is overdue", kind, item.title),
+            ),
+            (DueItemType::Milestone, DueReminder::DueSoon(lead)) => (
+                NotificationType::MilestoneDueSoon,
+                format!("{} \"{}\" is due in {}", kind, item.title, DueReminder::describe_lead(lead)),
+            ),
+            (DueItemType::Milestone, DueReminder::Overdue) => (
+                NotificationType::MilestoneOverdue,
+                format!("{} \"{}\" is overdue", kind, item.title),
+            ),
+        };
+        let message = format!("Due {}", item.due_date.format("%Y-%m-%d %H:%M UTC"));
+
+        for user_id in &item.recipients {
+            self.notification_service
+                .create_notification(
+                    *user_id,
+                    notification_type.clone(),
+                    title.clone(),
+                    message.clone(),
+                    Some(link.clone()),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
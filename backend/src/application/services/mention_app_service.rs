@@ -0,0 +1,106 @@
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::entities::{Notification, NotificationType, Task, User};
+use crate::domain::repositories::{NotificationRepository, UserRepository};
+use crate::domain::services::MentionParser;
+use crate::shared::DomainError;
+
+const NOTIFICATION_PREVIEW_LENGTH: usize = 200;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MentionedUser {
+    pub id: Uuid,
+    pub name: String,
+    pub email: String,
+}
+
+impl From<&User> for MentionedUser {
+    fn from(user: &User) -> Self {
+        Self {
+            id: user.id,
+            name: user.name.clone(),
+            email: user.email.to_string(),
+        }
+    }
+}
+
+/// A response item together with the users mentioned in its text
+#[derive(Debug, Serialize)]
+pub struct WithMentions<T> {
+    #[serde(flatten)]
+    pub item: T,
+    pub mentions: Vec<MentionedUser>,
+}
+
+pub struct MentionAppService {
+    user_repository: Arc<dyn UserRepository>,
+    notification_repository: Arc<dyn NotificationRepository>,
+}
+
+impl MentionAppService {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        notification_repository: Arc<dyn NotificationRepository>,
+    ) -> Self {
+        Self {
+            user_repository,
+            notification_repository,
+        }
+    }
+
+    /// Resolve mentions in `text` against the users who can access the project
+    pub async fn resolve(&self, project_id: Uuid, text: Option<&str>) -> Result<Vec<MentionedUser>, DomainError> {
+        let Some(text) = text.filter(|t| t.contains('@')) else {
+            return Ok(Vec::new());
+        };
+
+        let candidates = self.user_repository.find_with_project_access(project_id).await?;
+        Ok(MentionParser::resolve(text, &candidates)
+            .into_iter()
+            .map(MentionedUser::from)
+            .collect())
+    }
+
+    /// Resolve mentions in `text` written on `task` and notify the users that were
+    /// not already mentioned in `previous_text`. The author is never notified.
+    pub async fn notify_mentions(
+        &self,
+        task: &Task,
+        author_id: Option<Uuid>,
+        text: Option<&str>,
+        previous_text: Option<&str>,
+    ) -> Result<Vec<MentionedUser>, DomainError> {
+        let mentions = self.resolve(task.project_id, text).await?;
+        if mentions.is_empty() {
+            return Ok(mentions);
+        }
+
+        let previous = self.resolve(task.project_id, previous_text).await?;
+        for user in mentions
+            .iter()
+            .filter(|u| Some(u.id) != author_id && !previous.iter().any(|p| p.id == u.id))
+        {
+            let notification = Notification::new(
+                user.id,
+                NotificationType::Mention,
+                format!("You were mentioned in \"{}\"", task.title),
+                notification_preview(text.unwrap_or_default()),
+                Some(format!("/tasks/{}", task.id)),
+            );
+            self.notification_repository.create(&notification).await?;
+        }
+
+        Ok(mentions)
+    }
+}
+
+/// Shorten text for use as a notification message
+pub(crate) fn notification_preview(content: &str) -> String {
+    if content.chars().count() <= NOTIFICATION_PREVIEW_LENGTH {
+        return content.to_string();
+    }
+    let truncated: String = content.chars().take(NOTIFICATION_PREVIEW_LENGTH).collect();
+    format!("{}...", truncated)
+}
@@ -0,0 +1,155 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateMilestoneCommand, UpdateMilestoneCommand};
+use crate::domain::entities::{Milestone, MilestoneProgress, Task};
+use crate::domain::repositories::{MilestoneRepository, ProjectRepository, TaskRepository};
+use crate::domain::value_objects::ProjectRole;
+use crate::shared::DomainError;
+
+/// A milestone with the progress of its tasks
+#[derive(Debug, Serialize)]
+pub struct MilestoneWithProgress {
+    #[serde(flatten)]
+    pub milestone: Milestone,
+    pub progress: MilestoneProgress,
+}
+
+pub struct MilestoneAppService {
+    milestone_repository: Arc<dyn MilestoneRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    project_repository: Arc<dyn ProjectRepository>,
+}
+
+impl MilestoneAppService {
+    pub fn new(
+        milestone_repository: Arc<dyn MilestoneRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        project_repository: Arc<dyn ProjectRepository>,
+    ) -> Self {
+        Self {
+            milestone_repository,
+            task_repository,
+            project_repository,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.project_repository.can_user_access(project_id, user_id).await
+    }
+
+    /// Check if user can access the project of the milestone
+    pub async fn can_access_milestone(&self, id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        let milestone = self.find_milestone(id).await?;
+        self.project_repository.can_user_access(milestone.project_id, user_id).await
+    }
+
+    /// Check if user is owner of project or a member with at least `role`
+    pub async fn has_project_role(&self, project_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.project_repository.has_role(project_id, user_id, role).await
+    }
+
+    /// Check if user is owner of the project of the milestone or a member with at least `role`
+    pub async fn has_milestone_role(&self, id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        let milestone = self.find_milestone(id).await?;
+        self.project_repository.has_role(milestone.project_id, user_id, role).await
+    }
+
+    pub async fn list_milestones(&self, project_id: Uuid) -> Result<Vec<MilestoneWithProgress>, DomainError> {
+        self.project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
+
+        let mut tasks: HashMap<Uuid, Vec<Task>> = HashMap::new();
+        for task in self.task_repository.find_by_project(project_id).await? {
+            if let Some(milestone_id) = task.milestone_id {
+                tasks.entry(milestone_id).or_default().push(task);
+            }
+        }
+
+        let milestones = self.milestone_repository.find_by_project(project_id).await?;
+        Ok(milestones
+            .into_iter()
+            .map(|milestone| {
+                let progress = MilestoneProgress::of(tasks.get(&milestone.id).map_or(&[], Vec::as_slice));
+                MilestoneWithProgress { milestone, progress }
+            })
+            .collect())
+    }
+
+    pub async fn get_milestone(&self, id: Uuid) -> Result<MilestoneWithProgress, DomainError> {
+        let milestone = self.find_milestone(id).await?;
+        self.with_progress(milestone).await
+    }
+
+    pub async fn get_milestone_tasks(&self, id: Uuid) -> Result<Vec<Task>, DomainError> {
+        self.find_milestone(id).await?;
+        self.task_repository.find_by_milestone(id).await
+    }
+
+    pub async fn create_milestone(&self, project_id: Uuid, cmd: CreateMilestoneCommand) -> Result<Milestone, DomainError> {
+        self.project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
+
+        let milestone = Milestone::new(project_id, cmd.name.trim().to_string(), cmd.description, cmd.due_date);
+        milestone.validate()?;
+
+        self.milestone_repository.create(&milestone).await
+    }
+
+    pub async fn update_milestone(&self, id: Uuid, cmd: UpdateMilestoneCommand) -> Result<MilestoneWithProgress, DomainError> {
+        let mut milestone = self.find_milestone(id).await?;
+        if let Some(name) = cmd.name {
+            milestone.name = name.trim().to_string();
+        }
+        if let Some(description) = cmd.description {
+            milestone.description = Some(description);
+        }
+        if let Some(due_date) = cmd.due_date {
+            milestone.due_date = Some(due_date);
+        }
+        milestone.validate()?;
+
+        let milestone = self.milestone_repository.update(&milestone).await?;
+        self.with_progress(milestone).await
+    }
+
+    /// Mark the milestone completed; open tasks stay assigned to it
+    pub async fn complete_milestone(&self, id: Uuid) -> Result<MilestoneWithProgress, DomainError> {
+        let mut milestone = self.find_milestone(id).await?;
+        if milestone.completed {
+            return Err(DomainError::ValidationError("Milestone is already completed".into()));
+        }
+        milestone.mark_completed();
+
+        let milestone = self.milestone_repository.update(&milestone).await?;
+        self.with_progress(milestone).await
+    }
+
+    /// Tasks of the deleted milestone are kept without a milestone
+    pub async fn delete_milestone(&self, id: Uuid) -> Result<(), DomainError> {
+        self.find_milestone(id).await?;
+        self.milestone_repository.delete(id).await
+    }
+
+    async fn find_milestone(&self, id: Uuid) -> Result<Milestone, DomainError> {
+        self.milestone_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Milestone not found".into()))
+    }
+
+    async fn with_progress(&self, milestone: Milestone) -> Result<MilestoneWithProgress, DomainError> {
+        let tasks = self.task_repository.find_by_milestone(milestone.id).await?;
+        Ok(MilestoneWithProgress {
+            progress: MilestoneProgress::of(&tasks),
+            milestone,
+        })
+    }
+}
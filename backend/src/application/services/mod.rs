@@ -1,19 +1,57 @@
 mod activity_app_service;
 mod attachment_app_service;
 mod auth_app_service;
+mod budget_app_service;
+mod change_history_app_service;
+mod checklist_app_service;
+mod custom_field_app_service;
+mod due_reminder_app_service;
+mod mention_app_service;
+mod milestone_app_service;
 mod notification_app_service;
 mod project_app_service;
+mod project_health_app_service;
+mod project_template_app_service;
+mod recurring_task_app_service;
+mod schedule_app_service;
+mod search_app_service;
+mod sla_app_service;
+mod sprint_app_service;
 mod tag_app_service;
 mod task_app_service;
+mod task_comment_app_service;
+mod task_template_app_service;
 mod team_app_service;
 mod time_log_app_service;
+mod trash_app_service;
+mod workflow_app_service;
 
 pub use activity_app_service::ActivityAppService;
 pub use attachment_app_service::AttachmentAppService;
 pub use auth_app_service::{AuthAppService, AuthResponse, Claims};
+pub use budget_app_service::BudgetAppService;
+pub use change_history_app_service::ChangeHistoryAppService;
+pub use checklist_app_service::ChecklistAppService;
+pub use custom_field_app_service::CustomFieldAppService;
+pub use due_reminder_app_service::DueReminderAppService;
+pub use mention_app_service::{MentionAppService, MentionedUser, WithMentions};
+pub use milestone_app_service::{MilestoneAppService, MilestoneWithProgress};
 pub use notification_app_service::NotificationAppService;
 pub use project_app_service::ProjectAppService;
+pub use project_health_app_service::ProjectHealthAppService;
+pub use project_template_app_service::ProjectTemplateAppService;
+pub use recurring_task_app_service::{RecurringTaskAppService, ScheduledOccurrence};
+pub use schedule_app_service::ScheduleAppService;
+pub use search_app_service::SearchAppService;
+pub use sla_app_service::{SlaAppService, TaskSla};
+pub use sprint_app_service::{ClosedSprint, SprintAppService};
 pub use tag_app_service::TagAppService;
-pub use task_app_service::TaskAppService;
+pub use task_app_service::{
+    BulkTaskOutcome, BulkTaskResponse, BulkTaskResult, TaskAppService, TaskDependencies,
+};
+pub use task_comment_app_service::TaskCommentAppService;
+pub use task_template_app_service::TaskTemplateAppService;
 pub use team_app_service::TeamAppService;
 pub use time_log_app_service::{TimeLogAppService, CreateTimeLogDto, UpdateTimeLogDto};
+pub use trash_app_service::TrashAppService;
+pub use workflow_app_service::WorkflowAppService;
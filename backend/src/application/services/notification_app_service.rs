@@ -1,18 +1,23 @@
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::domain::entities::{Notification, NotificationType};
-use crate::domain::repositories::NotificationRepository;
+use crate::domain::entities::{Notification, NotificationType, Task, TaskWatcher};
+use crate::domain::repositories::{NotificationRepository, TaskWatcherRepository};
 use crate::shared::DomainError;
 
 pub struct NotificationAppService {
     notification_repository: Arc<dyn NotificationRepository>,
+    watcher_repository: Arc<dyn TaskWatcherRepository>,
 }
 
 impl NotificationAppService {
-    pub fn new(notification_repository: Arc<dyn NotificationRepository>) -> Self {
+    pub fn new(
+        notification_repository: Arc<dyn NotificationRepository>,
+        watcher_repository: Arc<dyn TaskWatcherRepository>,
+    ) -> Self {
         Self {
             notification_repository,
+            watcher_repository,
         }
     }
 
@@ -67,4 +72,39 @@ impl NotificationAppService {
             None => Err(DomainError::NotFound("Notification not found".into())),
         }
     }
+
+    pub async fn get_task_watchers(&self, task_id: Uuid) -> Result<Vec<TaskWatcher>, DomainError> {
+        self.watcher_repository.find_by_task(task_id).await
+    }
+
+    pub async fn watch_task(&self, task_id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
+        self.watcher_repository.watch(&TaskWatcher::new(task_id, user_id)).await
+    }
+
+    pub async fn unwatch_task(&self, task_id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
+        self.watcher_repository.unwatch(task_id, user_id).await
+    }
+
+    /// Notify everyone watching the task, except the user who made the change
+    pub async fn notify_watchers(
+        &self,
+        task: &Task,
+        actor_id: Uuid,
+        notification_type: NotificationType,
+        title: String,
+        message: String,
+    ) -> Result<(), DomainError> {
+        let watchers = self.watcher_repository.find_by_task(task.id).await?;
+        for watcher in watchers.into_iter().filter(|w| w.user_id != actor_id) {
+            self.create_notification(
+                watcher.user_id,
+                notification_type.clone(),
+                title.clone(),
+                message.clone(),
+                Some(format!("/tasks/{}", task.id)),
+            )
+            .await?;
+        }
+        Ok(())
+    }
 }
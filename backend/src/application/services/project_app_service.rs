@@ -1,18 +1,33 @@
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::application::commands::{CreateProjectCommand, UpdateProjectCommand};
-use crate::domain::entities::{Milestone, Project, Task};
-use crate::domain::repositories::ProjectRepository;
+use crate::application::commands::{AddProjectMemberCommand, CreateProjectCommand, UpdateProjectCommand, UpdateProjectMemberCommand};
+use crate::domain::entities::{ChangeRecord, Project, ProjectMember, Task, Trashed};
+use crate::domain::repositories::{ChangeHistoryRepository, ProjectRepository, ProjectTemplateRepository, UserRepository};
+use crate::domain::services::TaskRank;
+use crate::domain::value_objects::ProjectRole;
 use crate::shared::DomainError;
 
 pub struct ProjectAppService {
     project_repository: Arc<dyn ProjectRepository>,
+    history_repository: Arc<dyn ChangeHistoryRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    template_repository: Arc<dyn ProjectTemplateRepository>,
 }
 
 impl ProjectAppService {
-    pub fn new(project_repository: Arc<dyn ProjectRepository>) -> Self {
-        Self { project_repository }
+    pub fn new(
+        project_repository: Arc<dyn ProjectRepository>,
+        history_repository: Arc<dyn ChangeHistoryRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        template_repository: Arc<dyn ProjectTemplateRepository>,
+    ) -> Self {
+        Self {
+            project_repository,
+            history_repository,
+            user_repository,
+            template_repository,
+        }
     }
 
     /// List all projects (admin only - use list_accessible_projects for regular users)
@@ -35,6 +50,11 @@ impl ProjectAppService {
         self.project_repository.is_owner(project_id, user_id).await
     }
 
+    /// Check if user is owner of project or a member with at least `role`
+    pub async fn has_role(&self, project_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.project_repository.has_role(project_id, user_id, role).await
+    }
+
     pub async fn get_project(&self, id: Uuid) -> Result<Project, DomainError> {
         self.project_repository
             .find_by_id(id)
@@ -42,35 +62,63 @@ impl ProjectAppService {
             .ok_or_else(|| DomainError::NotFound("Project not found".into()))
     }
 
+    /// With a template, its milestones, tasks and members are created along with the project
     pub async fn create_project(
         &self,
         cmd: CreateProjectCommand,
         owner_id: Uuid,
     ) -> Result<Project, DomainError> {
+        let template = match cmd.template_id {
+            Some(template_id) => Some(
+                self.template_repository
+                    .find_by_id(template_id)
+                    .await?
+                    .ok_or_else(|| DomainError::NotFound("Project template not found".into()))?,
+            ),
+            None => None,
+        };
         let project = Project::new(
             cmd.name,
             cmd.description,
             owner_id,
             cmd.status,
-            cmd.priority,
+            cmd.priority.or_else(|| template.as_ref().map(|template| template.priority.clone())),
             cmd.start_date,
             cmd.end_date,
             cmd.budget,
         );
 
-        self.project_repository.create(&project).await
+        let (project, tasks) = match template {
+            Some(template) => {
+                let mut skeleton = template.instantiate(project);
+                let mut last_rank = None;
+                for task in &mut skeleton.tasks {
+                    task.rank = TaskRank::after(last_rank);
+                    last_rank = Some(task.rank);
+                }
+                (self.project_repository.create_skeleton(&skeleton).await?, skeleton.tasks)
+            }
+            None => (self.project_repository.create(&project).await?, Vec::new()),
+        };
+
+        let mut history = vec![ChangeRecord::created(&project, Some(owner_id))];
+        history.extend(tasks.iter().map(|task| ChangeRecord::created(task, Some(owner_id))));
+        self.history_repository.record(&history).await?;
+        Ok(project)
     }
 
     pub async fn update_project(
         &self,
         id: Uuid,
         cmd: UpdateProjectCommand,
+        user_id: Uuid,
     ) -> Result<Project, DomainError> {
         let mut project = self
             .project_repository
             .find_by_id(id)
             .await?
             .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
+        let previous = project.clone();
 
         if let Some(name) = cmd.name {
             project.name = name;
@@ -94,17 +142,50 @@ impl ProjectAppService {
             project.budget = Some(budget);
         }
 
-        self.project_repository.update(&project).await
+        let project = self.project_repository.update(&project).await?;
+        if let Some(record) = ChangeRecord::updated(&previous, &project, Some(user_id)) {
+            self.history_repository.record(&[record]).await?;
+        }
+        Ok(project)
     }
 
-    pub async fn delete_project(&self, id: Uuid) -> Result<(), DomainError> {
+    pub async fn delete_project(&self, id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
         // Check if project exists
-        self.project_repository
+        let project = self
+            .project_repository
             .find_by_id(id)
             .await?
             .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
 
-        self.project_repository.delete(id).await
+        // Tasks go to the trash with the project, so snapshot them first
+        let tasks = self.project_repository.find_tasks(id).await?;
+        self.project_repository.delete(id).await?;
+
+        let mut history = vec![ChangeRecord::deleted(&project, Some(user_id))];
+        history.extend(tasks.iter().map(|task| ChangeRecord::deleted(task, Some(user_id))));
+        self.history_repository.record(&history).await
+    }
+
+    /// List trashed projects (admin sees all, others only their own)
+    pub async fn get_trashed_projects(&self, user_id: Uuid, is_admin: bool) -> Result<Vec<Trashed<Project>>, DomainError> {
+        let owner_id = if is_admin { None } else { Some(user_id) };
+        self.project_repository.find_trashed(owner_id).await
+    }
+
+    /// Restore a trashed project together with the tasks deleted along with it
+    pub async fn restore_project(&self, id: Uuid, user_id: Uuid) -> Result<Project, DomainError> {
+        self.project_repository
+            .find_trashed_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Project not found in trash".into()))?;
+
+        let project = self.project_repository.restore(id).await?;
+        let tasks = self.project_repository.find_tasks(id).await?;
+
+        let mut history = vec![ChangeRecord::restored(&project, Some(user_id))];
+        history.extend(tasks.iter().map(|task| ChangeRecord::restored(task, Some(user_id))));
+        self.history_repository.record(&history).await?;
+        Ok(project)
     }
 
     pub async fn get_project_tasks(&self, project_id: Uuid) -> Result<Vec<Task>, DomainError> {
@@ -117,16 +198,51 @@ impl ProjectAppService {
         self.project_repository.find_tasks(project_id).await
     }
 
-    pub async fn get_project_milestones(
+    pub async fn get_members(&self, project_id: Uuid) -> Result<Vec<ProjectMember>, DomainError> {
+        self.get_project(project_id).await?;
+        self.project_repository.find_members(project_id).await
+    }
+
+    /// The owner holds every right already, so cannot be added as a member
+    pub async fn add_member(&self, project_id: Uuid, cmd: AddProjectMemberCommand) -> Result<ProjectMember, DomainError> {
+        let project = self.get_project(project_id).await?;
+        self.user_repository
+            .find_by_id(cmd.user_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("User not found".into()))?;
+
+        if project.owner_id == cmd.user_id {
+            return Err(DomainError::ValidationError("Project owner cannot be added as a member".into()));
+        }
+        if self.project_repository.find_member(project_id, cmd.user_id).await?.is_some() {
+            return Err(DomainError::AlreadyExists("User is already a member of this project".into()));
+        }
+
+        let member = ProjectMember::new(project_id, cmd.user_id, cmd.role);
+        self.project_repository.add_member(&member).await
+    }
+
+    pub async fn update_member(
         &self,
         project_id: Uuid,
-    ) -> Result<Vec<Milestone>, DomainError> {
-        // Verify project exists
+        user_id: Uuid,
+        cmd: UpdateProjectMemberCommand,
+    ) -> Result<ProjectMember, DomainError> {
+        let mut member = self.get_member(project_id, user_id).await?;
+        member.role = cmd.role;
+        self.project_repository.update_member(&member).await
+    }
+
+    pub async fn remove_member(&self, project_id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
+        self.get_member(project_id, user_id).await?;
+        self.project_repository.remove_member(project_id, user_id).await
+    }
+
+    async fn get_member(&self, project_id: Uuid, user_id: Uuid) -> Result<ProjectMember, DomainError> {
+        self.get_project(project_id).await?;
         self.project_repository
-            .find_by_id(project_id)
+            .find_member(project_id, user_id)
             .await?
-            .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
-
-        self.project_repository.find_milestones(project_id).await
+            .ok_or_else(|| DomainError::NotFound("Project member not found".into()))
     }
 }
@@ -0,0 +1,88 @@
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::services::BudgetAppService;
+use crate::domain::entities::Project;
+use crate::domain::repositories::{MilestoneRepository, ProjectRepository};
+use crate::domain::services::{HealthPolicy, ProjectHealth};
+use crate::domain::value_objects::ProjectRole;
+use crate::shared::DomainError;
+
+pub struct ProjectHealthAppService {
+    project_repository: Arc<dyn ProjectRepository>,
+    milestone_repository: Arc<dyn MilestoneRepository>,
+    budget_service: Arc<BudgetAppService>,
+    policy: HealthPolicy,
+}
+
+impl ProjectHealthAppService {
+    pub fn new(
+        project_repository: Arc<dyn ProjectRepository>,
+        milestone_repository: Arc<dyn MilestoneRepository>,
+        budget_service: Arc<BudgetAppService>,
+        policy: HealthPolicy,
+    ) -> Self {
+        Self {
+            project_repository,
+            milestone_repository,
+            budget_service,
+            policy,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.project_repository.can_user_access(project_id, user_id).await
+    }
+
+    /// Health of the project; the budget factor is left out unless the user may see budgets
+    pub async fn get_health(&self, project_id: Uuid, user_id: Uuid, is_admin: bool) -> Result<ProjectHealth, DomainError> {
+        let project = self
+            .project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
+        let include_budget = self.can_view_budget(&project, user_id, is_admin).await?;
+        self.evaluate(&project, include_budget).await
+    }
+
+    /// Health of every project the user can access (all for admin), worst first
+    pub async fn get_portfolio_health(&self, user_id: Uuid, is_admin: bool) -> Result<Vec<ProjectHealth>, DomainError> {
+        let projects = if is_admin {
+            self.project_repository.find_all().await?
+        } else {
+            self.project_repository.find_accessible_by_user(user_id).await?
+        };
+
+        let mut portfolio = Vec::with_capacity(projects.len());
+        for project in &projects {
+            let include_budget = self.can_view_budget(project, user_id, is_admin).await?;
+            portfolio.push(self.evaluate(project, include_budget).await?);
+        }
+        portfolio.sort_by(|a, b| b.status.cmp(&a.status).then_with(|| a.project_name.cmp(&b.project_name)));
+        Ok(portfolio)
+    }
+
+    /// Budgets are shown to maintainers and the owner only, like budget reports
+    async fn can_view_budget(&self, project: &Project, user_id: Uuid, is_admin: bool) -> Result<bool, DomainError> {
+        if is_admin {
+            return Ok(true);
+        }
+        // Nothing to show without a budget
+        if project.budget.is_none() {
+            return Ok(false);
+        }
+        self.project_repository.has_role(project.id, user_id, ProjectRole::Maintainer).await
+    }
+
+    async fn evaluate(&self, project: &Project, include_budget: bool) -> Result<ProjectHealth, DomainError> {
+        let tasks = self.project_repository.find_tasks(project.id).await?;
+        let milestones = self.milestone_repository.find_by_project(project.id).await?;
+        let spent = match project.budget {
+            Some(_) if include_budget => Some(self.budget_service.get_spent(project).await?),
+            _ => None,
+        };
+        Ok(self.policy.evaluate(project, &tasks, &milestones, spent, Utc::now()))
+    }
+}
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::SaveProjectTemplateCommand;
+use crate::domain::entities::{ProjectSkeleton, ProjectTemplate, TaskTag};
+use crate::domain::repositories::{
+    ChecklistRepository, MilestoneRepository, ProjectRepository, ProjectTemplateRepository, TagRepository,
+    TaskDependencyRepository,
+};
+use crate::domain::value_objects::ProjectRole;
+use crate::shared::DomainError;
+
+pub struct ProjectTemplateAppService {
+    template_repository: Arc<dyn ProjectTemplateRepository>,
+    project_repository: Arc<dyn ProjectRepository>,
+    milestone_repository: Arc<dyn MilestoneRepository>,
+    checklist_repository: Arc<dyn ChecklistRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+    task_dependency_repository: Arc<dyn TaskDependencyRepository>,
+}
+
+impl ProjectTemplateAppService {
+    pub fn new(
+        template_repository: Arc<dyn ProjectTemplateRepository>,
+        project_repository: Arc<dyn ProjectRepository>,
+        milestone_repository: Arc<dyn MilestoneRepository>,
+        checklist_repository: Arc<dyn ChecklistRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+        task_dependency_repository: Arc<dyn TaskDependencyRepository>,
+    ) -> Self {
+        Self {
+            template_repository,
+            project_repository,
+            milestone_repository,
+            checklist_repository,
+            tag_repository,
+            task_dependency_repository,
+        }
+    }
+
+    /// Check if user is owner of project or a member with at least `role`
+    pub async fn has_project_role(&self, project_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.project_repository.has_role(project_id, user_id, role).await
+    }
+
+    /// Check if user saved the template
+    pub async fn is_creator(&self, id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        let template = self.get_template(id).await?;
+        Ok(template.created_by == Some(user_id))
+    }
+
+    pub async fn list_templates(&self) -> Result<Vec<ProjectTemplate>, DomainError> {
+        self.template_repository.find_all().await
+    }
+
+    pub async fn get_template(&self, id: Uuid) -> Result<ProjectTemplate, DomainError> {
+        self.template_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Project template not found".into()))
+    }
+
+    /// Save the project's milestones, tasks with their checklists, tags and dependencies, and member roles
+    pub async fn save_template(&self, cmd: SaveProjectTemplateCommand, user_id: Uuid) -> Result<ProjectTemplate, DomainError> {
+        let project = self
+            .project_repository
+            .find_by_id(cmd.project_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
+        let tasks = self.project_repository.find_tasks(project.id).await?;
+
+        let mut checklist_items = Vec::new();
+        let mut task_tags = Vec::new();
+        for task in &tasks {
+            checklist_items.extend(self.checklist_repository.find_by_task(task.id).await?);
+            let tags = self.tag_repository.find_tags_by_task(task.id).await?;
+            task_tags.extend(tags.iter().map(|tag| TaskTag::new(task.id, tag.id)));
+        }
+
+        let source = ProjectSkeleton {
+            members: self.project_repository.find_members(project.id).await?,
+            milestones: self.milestone_repository.find_by_project(project.id).await?,
+            dependencies: self.task_dependency_repository.find_by_project(project.id).await?,
+            project,
+            tasks,
+            checklist_items,
+            task_tags,
+        };
+        let template = ProjectTemplate::capture(cmd.name.trim().to_string(), cmd.description, &source, user_id);
+        template.validate()?;
+
+        self.template_repository.create(&template).await
+    }
+
+    pub async fn delete_template(&self, id: Uuid) -> Result<(), DomainError> {
+        self.get_template(id).await?;
+        self.template_repository.delete(id).await
+    }
+}
@@ -0,0 +1,378 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{
+    CreateRecurringTaskCommand, CreateTaskCommand, UpdateRecurringOccurrenceCommand,
+    UpdateRecurringTaskCommand,
+};
+use crate::application::services::TaskAppService;
+use crate::domain::entities::{RecurringTask, RecurringTaskOccurrence};
+use crate::domain::repositories::{ProjectRepository, RecurringTaskRepository};
+use crate::domain::value_objects::{ProjectRole, RecurrenceRule};
+use crate::shared::DomainError;
+
+const MAX_LEAD_DAYS: i32 = 365;
+const DEFAULT_PREVIEW_DAYS: i64 = 30;
+const MAX_PREVIEW_DAYS: i64 = 366;
+
+/// An upcoming or past occurrence of a series with its overrides applied
+#[derive(Debug, Serialize)]
+pub struct ScheduledOccurrence {
+    pub occurrence_date: NaiveDate,
+    pub due_date: DateTime<Utc>,
+    pub title: String,
+    pub skipped: bool,
+    pub edited: bool,
+    pub generated: bool,
+    pub task_id: Option<Uuid>,
+}
+
+pub struct RecurringTaskAppService {
+    recurring_task_repository: Arc<dyn RecurringTaskRepository>,
+    project_repository: Arc<dyn ProjectRepository>,
+    task_service: Arc<TaskAppService>,
+}
+
+impl RecurringTaskAppService {
+    pub fn new(
+        recurring_task_repository: Arc<dyn RecurringTaskRepository>,
+        project_repository: Arc<dyn ProjectRepository>,
+        task_service: Arc<TaskAppService>,
+    ) -> Self {
+        Self {
+            recurring_task_repository,
+            project_repository,
+            task_service,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.project_repository.can_user_access(project_id, user_id).await
+    }
+
+    /// Check if user is owner of project or a member with at least `role`
+    pub async fn has_project_role(&self, project_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.project_repository.has_role(project_id, user_id, role).await
+    }
+
+    /// Check if user can access the project of the recurring task
+    pub async fn can_user_access(&self, id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        let recurring_task = self.get_recurring_task(id).await?;
+        self.project_repository.can_user_access(recurring_task.project_id, user_id).await
+    }
+
+    /// Check if user is owner of the project of the recurring task or a member with at least `role`
+    pub async fn has_role(&self, id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        let recurring_task = self.get_recurring_task(id).await?;
+        self.project_repository.has_role(recurring_task.project_id, user_id, role).await
+    }
+
+    pub async fn list_recurring_tasks(&self, project_id: Uuid) -> Result<Vec<RecurringTask>, DomainError> {
+        self.recurring_task_repository.find_by_project(project_id).await
+    }
+
+    pub async fn get_recurring_task(&self, id: Uuid) -> Result<RecurringTask, DomainError> {
+        self.recurring_task_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Recurring task not found".into()))
+    }
+
+    pub async fn create_recurring_task(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+        cmd: CreateRecurringTaskCommand,
+    ) -> Result<RecurringTask, DomainError> {
+        self.project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
+        validate_title(&cmd.title)?;
+        let lead_days = validate_lead_days(cmd.lead_days.unwrap_or(0))?;
+        let rule = RecurrenceRule::parse(&cmd.rrule)?;
+
+        let recurring_task = RecurringTask::new(
+            project_id,
+            cmd.title,
+            cmd.description,
+            cmd.priority,
+            cmd.assignee_id,
+            cmd.estimated_hours,
+            rule.to_string(),
+            cmd.dtstart,
+            lead_days,
+            user_id,
+        );
+
+        self.recurring_task_repository.create(&recurring_task).await
+    }
+
+    /// Update the series; tasks already generated are left untouched
+    pub async fn update_recurring_task(
+        &self,
+        id: Uuid,
+        cmd: UpdateRecurringTaskCommand,
+    ) -> Result<RecurringTask, DomainError> {
+        let mut recurring_task = self.get_recurring_task(id).await?;
+
+        if let Some(title) = cmd.title {
+            validate_title(&title)?;
+            recurring_task.title = title;
+        }
+        if let Some(description) = cmd.description {
+            recurring_task.description = Some(description);
+        }
+        if let Some(priority) = cmd.priority {
+            recurring_task.priority = priority;
+        }
+        if let Some(assignee_id) = cmd.assignee_id {
+            recurring_task.assignee_id = Some(assignee_id);
+        }
+        if let Some(estimated_hours) = cmd.estimated_hours {
+            recurring_task.estimated_hours = Some(estimated_hours);
+        }
+        if let Some(rrule) = cmd.rrule {
+            recurring_task.rrule = RecurrenceRule::parse(&rrule)?.to_string();
+        }
+        if let Some(dtstart) = cmd.dtstart {
+            recurring_task.dtstart = dtstart;
+        }
+        if let Some(lead_days) = cmd.lead_days {
+            recurring_task.lead_days = validate_lead_days(lead_days)?;
+        }
+        if let Some(is_active) = cmd.is_active {
+            recurring_task.is_active = is_active;
+        }
+
+        self.recurring_task_repository.update(&recurring_task).await
+    }
+
+    /// Delete the series; tasks already generated are kept
+    pub async fn delete_recurring_task(&self, id: Uuid) -> Result<(), DomainError> {
+        self.get_recurring_task(id).await?;
+        self.recurring_task_repository.delete(id).await
+    }
+
+    /// List occurrences between `from` and `to` (default: the next 30 days)
+    pub async fn list_occurrences(
+        &self,
+        id: Uuid,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> Result<Vec<ScheduledOccurrence>, DomainError> {
+        let recurring_task = self.get_recurring_task(id).await?;
+        let rule = recurring_task.rule()?;
+
+        let from = from.unwrap_or_else(|| Utc::now().date_naive());
+        let to = to.unwrap_or(from + Duration::days(DEFAULT_PREVIEW_DAYS));
+        if to < from || (to - from).num_days() > MAX_PREVIEW_DAYS {
+            return Err(DomainError::ValidationError(format!(
+                "Date range must be between 0 and {} days",
+                MAX_PREVIEW_DAYS
+            )));
+        }
+
+        let stored = self.recurring_task_repository.find_occurrences(id, from, to).await?;
+        let occurrences = rule
+            .occurrences(recurring_task.dtstart.date_naive(), to)
+            .into_iter()
+            .filter(|date| *date >= from)
+            .map(|date| {
+                let occurrence = stored.iter().find(|o| o.occurrence_date == date);
+                ScheduledOccurrence {
+                    occurrence_date: date,
+                    due_date: occurrence
+                        .and_then(|o| o.due_date)
+                        .unwrap_or_else(|| recurring_task.due_at(date)),
+                    title: occurrence
+                        .and_then(|o| o.title.clone())
+                        .unwrap_or_else(|| recurring_task.title.clone()),
+                    skipped: occurrence.is_some_and(|o| o.skipped),
+                    edited: occurrence.is_some_and(has_overrides),
+                    generated: occurrence.is_some_and(|o| o.is_generated()),
+                    task_id: occurrence.and_then(|o| o.task_id),
+                }
+            })
+            .collect();
+
+        Ok(occurrences)
+    }
+
+    /// Skip or edit a single occurrence that has not been generated yet
+    pub async fn update_occurrence(
+        &self,
+        id: Uuid,
+        occurrence_date: NaiveDate,
+        cmd: UpdateRecurringOccurrenceCommand,
+    ) -> Result<RecurringTaskOccurrence, DomainError> {
+        let mut occurrence = self.editable_occurrence(id, occurrence_date).await?;
+        if let Some(title) = &cmd.title {
+            validate_title(title)?;
+        }
+
+        occurrence.skipped = cmd.skipped;
+        occurrence.title = cmd.title;
+        occurrence.description = cmd.description;
+        occurrence.priority = cmd.priority;
+        occurrence.assignee_id = cmd.assignee_id;
+        occurrence.due_date = cmd.due_date;
+        occurrence.estimated_hours = cmd.estimated_hours;
+
+        self.recurring_task_repository.save_occurrence(&occurrence).await
+    }
+
+    /// Drop the skip or overrides of an occurrence so it follows the series again
+    pub async fn reset_occurrence(&self, id: Uuid, occurrence_date: NaiveDate) -> Result<(), DomainError> {
+        self.editable_occurrence(id, occurrence_date).await?;
+        self.recurring_task_repository.delete_occurrence(id, occurrence_date).await
+    }
+
+    /// Create the tasks of every active series whose occurrences fall within their lead time.
+    /// Returns the number of tasks created.
+    pub async fn generate_due_tasks(&self, now: DateTime<Utc>) -> Result<usize, DomainError> {
+        let mut created = 0;
+        for recurring_task in self.recurring_task_repository.find_active().await? {
+            match self.generate_for(&recurring_task, now).await {
+                Ok(count) => created += count,
+                Err(e) => tracing::warn!(
+                    recurring_task_id = %recurring_task.id,
+                    error = %e,
+                    "Failed to generate recurring task occurrences"
+                ),
+            }
+        }
+        Ok(created)
+    }
+
+    async fn generate_for(&self, recurring_task: &RecurringTask, now: DateTime<Utc>) -> Result<usize, DomainError> {
+        let rule = recurring_task.rule()?;
+        let horizon = (now + Duration::days(recurring_task.lead_days as i64)).date_naive();
+        if recurring_task.generated_through.is_some_and(|d| d >= horizon) {
+            return Ok(0);
+        }
+
+        // Never backfill occurrences from before the series was created
+        let first = match recurring_task.generated_through {
+            Some(through) => through + Duration::days(1),
+            None => recurring_task.created_at.date_naive(),
+        };
+
+        let mut created = 0;
+        for date in rule
+            .occurrences(recurring_task.dtstart.date_naive(), horizon)
+            .into_iter()
+            .filter(|date| *date >= first)
+        {
+            let Some(occurrence) = self.recurring_task_repository.claim_occurrence(recurring_task.id, date).await? else {
+                continue;
+            };
+
+            let cmd = CreateTaskCommand {
+                project_id: recurring_task.project_id,
+                parent_id: None,
+                title: occurrence.title.clone().unwrap_or_else(|| recurring_task.title.clone()),
+                description: occurrence.description.clone().or_else(|| recurring_task.description.clone()),
+                priority: Some(occurrence.priority.clone().unwrap_or_else(|| recurring_task.priority.clone())),
+                assignee_id: occurrence.assignee_id.or(recurring_task.assignee_id),
+                start_date: None,
+                due_date: Some(occurrence.due_date.unwrap_or_else(|| recurring_task.due_at(date))),
+                estimated_hours: occurrence.estimated_hours.or(recurring_task.estimated_hours),
+                story_points: None,
+                milestone_id: None,
+                template_id: None,
+                custom_fields: HashMap::new(),
+            };
+
+            // Only release the claim while no task exists for it, so a failure after the
+            // insert can never lead to a duplicate on the next run
+            let task = match self.task_service.create_generated_task(cmd).await {
+                Ok(task) => task,
+                Err(e) => {
+                    self.recurring_task_repository.release_occurrence(occurrence.id).await?;
+                    return Err(e);
+                }
+            };
+            self.recurring_task_repository
+                .set_occurrence_task(occurrence.id, task.id)
+                .await?;
+            created += 1;
+            self.task_service
+                .finish_generated_task(task, recurring_task.created_by)
+                .await?;
+        }
+
+        self.recurring_task_repository
+            .set_generated_through(recurring_task.id, horizon)
+            .await?;
+
+        if created > 0 {
+            tracing::info!(
+                recurring_task_id = %recurring_task.id,
+                created,
+                "Generated recurring tasks"
+            );
+        }
+        Ok(created)
+    }
+
+    async fn editable_occurrence(
+        &self,
+        id: Uuid,
+        occurrence_date: NaiveDate,
+    ) -> Result<RecurringTaskOccurrence, DomainError> {
+        let recurring_task = self.get_recurring_task(id).await?;
+        if !recurring_task
+            .rule()?
+            .is_occurrence(recurring_task.dtstart.date_naive(), occurrence_date)
+        {
+            return Err(DomainError::NotFound(format!(
+                "No occurrence of this series on {}",
+                occurrence_date
+            )));
+        }
+
+        let occurrence = self
+            .recurring_task_repository
+            .find_occurrence(id, occurrence_date)
+            .await?
+            .unwrap_or_else(|| RecurringTaskOccurrence::new(id, occurrence_date));
+        if occurrence.is_generated() {
+            return Err(DomainError::ValidationError(
+                "Occurrence has already been generated; edit its task instead".into(),
+            ));
+        }
+
+        Ok(occurrence)
+    }
+}
+
+fn has_overrides(occurrence: &RecurringTaskOccurrence) -> bool {
+    occurrence.title.is_some()
+        || occurrence.description.is_some()
+        || occurrence.priority.is_some()
+        || occurrence.assignee_id.is_some()
+        || occurrence.due_date.is_some()
+        || occurrence.estimated_hours.is_some()
+}
+
+fn validate_title(title: &str) -> Result<(), DomainError> {
+    if title.trim().is_empty() {
+        return Err(DomainError::ValidationError("Title cannot be empty".into()));
+    }
+    Ok(())
+}
+
+fn validate_lead_days(lead_days: i32) -> Result<i32, DomainError> {
+    if !(0..=MAX_LEAD_DAYS).contains(&lead_days) {
+        return Err(DomainError::ValidationError(format!(
+            "lead_days must be between 0 and {}",
+            MAX_LEAD_DAYS
+        )));
+    }
+    Ok(lead_days)
+}
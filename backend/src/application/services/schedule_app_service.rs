@@ -0,0 +1,56 @@
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::repositories::{MilestoneRepository, ProjectRepository, TaskDependencyRepository};
+use crate::domain::services::{ProjectSchedule, Schedule};
+use crate::shared::DomainError;
+
+pub struct ScheduleAppService {
+    project_repository: Arc<dyn ProjectRepository>,
+    task_dependency_repository: Arc<dyn TaskDependencyRepository>,
+    milestone_repository: Arc<dyn MilestoneRepository>,
+    hours_per_day: f32,
+}
+
+impl ScheduleAppService {
+    pub fn new(
+        project_repository: Arc<dyn ProjectRepository>,
+        task_dependency_repository: Arc<dyn TaskDependencyRepository>,
+        milestone_repository: Arc<dyn MilestoneRepository>,
+        hours_per_day: f32,
+    ) -> Self {
+        Self {
+            project_repository,
+            task_dependency_repository,
+            milestone_repository,
+            hours_per_day,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.project_repository.can_user_access(project_id, user_id).await
+    }
+
+    /// Earliest and latest dates of the open tasks, the critical path and milestone forecasts from today
+    pub async fn get_schedule(&self, project_id: Uuid) -> Result<ProjectSchedule, DomainError> {
+        let project = self
+            .project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
+        let tasks = self.project_repository.find_tasks(project_id).await?;
+        let dependencies = self.task_dependency_repository.find_by_project(project_id).await?;
+        let milestones = self.milestone_repository.find_by_project(project_id).await?;
+
+        Schedule::build(
+            &project,
+            &tasks,
+            &dependencies,
+            &milestones,
+            self.hours_per_day,
+            Utc::now().date_naive(),
+        )
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::queries::SearchQuery;
+use crate::domain::entities::{SearchEntityType, SearchHit};
+use crate::domain::repositories::{SearchFilter, SearchRepository};
+use crate::shared::DomainError;
+
+pub struct SearchAppService {
+    search_repository: Arc<dyn SearchRepository>,
+}
+
+impl SearchAppService {
+    pub fn new(search_repository: Arc<dyn SearchRepository>) -> Self {
+        Self { search_repository }
+    }
+
+    /// Search projects, tasks and comments; non-admins only see projects they can access
+    pub async fn search(
+        &self,
+        query: SearchQuery,
+        user_id: Uuid,
+        is_admin: bool,
+    ) -> Result<Vec<SearchHit>, DomainError> {
+        let text = query.q.trim();
+        if text.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Search query cannot be empty".into(),
+            ));
+        }
+        let pagination = query.pagination();
+
+        let filter = SearchFilter {
+            query: text.to_string(),
+            types: SearchEntityType::parse_list(query.r#type.as_deref())?,
+            project_id: query.project_id,
+            user_id: (!is_admin).then_some(user_id),
+            limit: pagination.per_page() as i64,
+            offset: pagination.offset() as i64,
+        };
+        self.search_repository.search(&filter).await
+    }
+}
@@ -0,0 +1,229 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateSlaPolicyCommand, UpdateSlaPolicyCommand};
+use crate::application::queries::SlaReportQuery;
+use crate::application::services::NotificationAppService;
+use crate::domain::entities::{NotificationType, SlaBreach, SlaBreachCount, SlaPolicy, SlaTarget};
+use crate::domain::repositories::{ProjectRepository, SlaRepository, SlaTrackedTask, TaskRepository};
+use crate::domain::services::{DueReminder, SlaClock, SlaTimer};
+use crate::domain::value_objects::ProjectRole;
+use crate::shared::DomainError;
+
+/// SLA state of a task; no policy and timers when none applies to its priority
+#[derive(Debug, Serialize)]
+pub struct TaskSla {
+    pub task_id: Uuid,
+    pub policy: Option<SlaPolicy>,
+    pub timers: Vec<SlaTimer>,
+    pub breaches: Vec<SlaBreach>,
+}
+
+pub struct SlaAppService {
+    sla_repository: Arc<dyn SlaRepository>,
+    project_repository: Arc<dyn ProjectRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    notification_service: Arc<NotificationAppService>,
+    clock: SlaClock,
+}
+
+impl SlaAppService {
+    pub fn new(
+        sla_repository: Arc<dyn SlaRepository>,
+        project_repository: Arc<dyn ProjectRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        notification_service: Arc<NotificationAppService>,
+    ) -> Self {
+        Self {
+            sla_repository,
+            project_repository,
+            task_repository,
+            notification_service,
+            clock: SlaClock::default(),
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.project_repository.can_user_access(project_id, user_id).await
+    }
+
+    /// Check if user can access task (via project access)
+    pub async fn can_access_task(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.task_repository.can_user_access(task_id, user_id).await
+    }
+
+    /// Check if user is owner of project or a member with at least `role`
+    pub async fn has_project_role(&self, project_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.project_repository.has_role(project_id, user_id, role).await
+    }
+
+    /// Check if user is owner of the project of the policy or a member with at least `role`
+    pub async fn has_policy_role(&self, id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        let policy = self.get_policy(id).await?;
+        self.project_repository.has_role(policy.project_id, user_id, role).await
+    }
+
+    pub async fn list_policies(&self, project_id: Uuid) -> Result<Vec<SlaPolicy>, DomainError> {
+        self.sla_repository.find_policies(project_id).await
+    }
+
+    pub async fn get_policy(&self, id: Uuid) -> Result<SlaPolicy, DomainError> {
+        self.sla_repository
+            .find_policy(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("SLA policy not found".into()))
+    }
+
+    /// A project has at most one policy per priority
+    pub async fn create_policy(&self, project_id: Uuid, cmd: CreateSlaPolicyCommand) -> Result<SlaPolicy, DomainError> {
+        self.project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
+
+        let existing = self.sla_repository.find_policies(project_id).await?;
+        if existing.iter().any(|policy| policy.priority == cmd.priority) {
+            return Err(DomainError::AlreadyExists(format!(
+                "Project already has an SLA policy for {} priority",
+                cmd.priority.as_str()
+            )));
+        }
+
+        let policy = SlaPolicy::new(
+            project_id,
+            cmd.priority,
+            cmd.response_minutes,
+            cmd.resolution_minutes,
+            cmd.business_hours.unwrap_or(true),
+        );
+        policy.validate()?;
+
+        self.sla_repository.create_policy(&policy).await
+    }
+
+    pub async fn update_policy(&self, id: Uuid, cmd: UpdateSlaPolicyCommand) -> Result<SlaPolicy, DomainError> {
+        let mut policy = self.get_policy(id).await?;
+        if let Some(response_minutes) = cmd.response_minutes {
+            policy.response_minutes = Some(response_minutes);
+        }
+        if let Some(resolution_minutes) = cmd.resolution_minutes {
+            policy.resolution_minutes = Some(resolution_minutes);
+        }
+        if let Some(business_hours) = cmd.business_hours {
+            policy.business_hours = business_hours;
+        }
+        policy.validate()?;
+
+        self.sla_repository.update_policy(&policy).await
+    }
+
+    /// Recorded breaches are kept for reporting
+    pub async fn delete_policy(&self, id: Uuid) -> Result<(), DomainError> {
+        self.get_policy(id).await?;
+        self.sla_repository.delete_policy(id).await
+    }
+
+    /// Elapsed time and breaches of each SLA target of the task
+    pub async fn get_task_sla(&self, task_id: Uuid) -> Result<TaskSla, DomainError> {
+        let breaches = self.sla_repository.find_breaches(task_id).await?;
+        let Some(tracked) = self.sla_repository.find_tracked_task(task_id).await? else {
+            self.task_repository
+                .find_by_id(task_id)
+                .await?
+                .ok_or_else(|| DomainError::NotFound("Task not found".into()))?;
+            return Ok(TaskSla {
+                task_id,
+                policy: None,
+                timers: Vec::new(),
+                breaches,
+            });
+        };
+
+        Ok(TaskSla {
+            task_id,
+            timers: self.timers(&tracked, Utc::now()),
+            policy: Some(tracked.policy),
+            breaches,
+        })
+    }
+
+    /// Breaches of the project in the period, per priority and target
+    pub async fn breach_report(&self, project_id: Uuid, query: SlaReportQuery) -> Result<Vec<SlaBreachCount>, DomainError> {
+        if let (Some(from), Some(to)) = (query.from, query.to) {
+            if from > to {
+                return Err(DomainError::ValidationError("'from' must be before 'to'".into()));
+            }
+        }
+        self.sla_repository.count_breaches(project_id, query.from, query.to).await
+    }
+
+    /// Flag newly breached targets and escalate them to the project owner and the
+    /// assignee's team leads. Returns how many breaches were recorded.
+    pub async fn check_breaches(&self, now: DateTime<Utc>) -> Result<usize, DomainError> {
+        // Look back far enough to catch tasks finished late since the previous run
+        let tracked = self.sla_repository.find_tracked(now - Duration::days(1)).await?;
+
+        let mut recorded = 0;
+        for task in tracked {
+            for timer in self.timers(&task, now) {
+                if !timer.breached || task.breached.contains(&timer.target) {
+                    continue;
+                }
+                let breach = SlaBreach::new(task.task_id, &task.policy, timer.target, timer.due_at, task.escalate_to.clone());
+                if !self.sla_repository.record_breach(&breach).await? {
+                    continue;
+                }
+                self.escalate(&task, &breach).await?;
+                recorded += 1;
+            }
+        }
+        Ok(recorded)
+    }
+
+    fn timers(&self, task: &SlaTrackedTask, now: DateTime<Utc>) -> Vec<SlaTimer> {
+        SlaTarget::ALL
+            .into_iter()
+            .filter_map(|target| {
+                let stopped_at = match target {
+                    SlaTarget::Response => task.responded_at,
+                    SlaTarget::Resolution => task.resolved_at,
+                };
+                self.clock.evaluate(&task.policy, target, task.created_at, stopped_at, now)
+            })
+            .collect()
+    }
+
+    async fn escalate(&self, task: &SlaTrackedTask, breach: &SlaBreach) -> Result<(), DomainError> {
+        let goal = match breach.target {
+            SlaTarget::Response => "leave Todo",
+            SlaTarget::Resolution => "be done",
+        };
+        let mut limit = DueReminder::describe_lead(Duration::minutes(breach.target_minutes as i64));
+        if task.policy.business_hours {
+            limit.push_str(" of working time");
+        }
+        let title = format!("SLA breached: \"{}\"", task.title);
+        let message = format!(
+            "Tasks of {} priority must {} within {}",
+            breach.priority.as_str(),
+            goal,
+            limit
+        );
+
+        for user_id in &breach.escalated_to {
+            self.notification_service
+                .create_notification(
+                    *user_id,
+                    NotificationType::SlaBreached,
+                    title.clone(),
+                    message.clone(),
+                    Some(format!("/tasks/{}", task.task_id)),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,284 @@
+use chrono::{NaiveDate, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CloseSprintCommand, CreateSprintCommand, PlanSprintCommand, UpdateSprintCommand};
+use crate::domain::entities::{ChangeRecord, Sprint, SprintSnapshot, SprintStatus, Task};
+use crate::domain::repositories::{ChangeHistoryRepository, ProjectRepository, SprintRepository, TaskBatch, TaskRepository};
+use crate::domain::services::{Burndown, SprintBurndown};
+use crate::domain::value_objects::ProjectRole;
+use crate::shared::DomainError;
+
+/// A closed sprint with the unfinished tasks that were carried over
+#[derive(Debug, Serialize)]
+pub struct ClosedSprint {
+    #[serde(flatten)]
+    pub sprint: Sprint,
+    pub carried_over: Vec<Uuid>,
+}
+
+pub struct SprintAppService {
+    sprint_repository: Arc<dyn SprintRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    project_repository: Arc<dyn ProjectRepository>,
+    history_repository: Arc<dyn ChangeHistoryRepository>,
+}
+
+impl SprintAppService {
+    pub fn new(
+        sprint_repository: Arc<dyn SprintRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        project_repository: Arc<dyn ProjectRepository>,
+        history_repository: Arc<dyn ChangeHistoryRepository>,
+    ) -> Self {
+        Self {
+            sprint_repository,
+            task_repository,
+            project_repository,
+            history_repository,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.project_repository.can_user_access(project_id, user_id).await
+    }
+
+    /// Check if user can access the project of the sprint
+    pub async fn can_access_sprint(&self, id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        let sprint = self.get_sprint(id).await?;
+        self.project_repository.can_user_access(sprint.project_id, user_id).await
+    }
+
+    /// Check if user is owner of project or a member with at least `role`
+    pub async fn has_project_role(&self, project_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.project_repository.has_role(project_id, user_id, role).await
+    }
+
+    /// Check if user is owner of the project of the sprint or a member with at least `role`
+    pub async fn has_sprint_role(&self, id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        let sprint = self.get_sprint(id).await?;
+        self.project_repository.has_role(sprint.project_id, user_id, role).await
+    }
+
+    pub async fn list_sprints(&self, project_id: Uuid) -> Result<Vec<Sprint>, DomainError> {
+        self.sprint_repository.find_by_project(project_id).await
+    }
+
+    pub async fn get_sprint(&self, id: Uuid) -> Result<Sprint, DomainError> {
+        self.sprint_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Sprint not found".into()))
+    }
+
+    pub async fn create_sprint(&self, project_id: Uuid, cmd: CreateSprintCommand) -> Result<Sprint, DomainError> {
+        self.project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
+
+        let sprint = Sprint::new(project_id, cmd.name.trim().to_string(), cmd.goal, cmd.start_date, cmd.end_date);
+        sprint.validate()?;
+
+        self.sprint_repository.create(&sprint).await
+    }
+
+    pub async fn update_sprint(&self, id: Uuid, cmd: UpdateSprintCommand) -> Result<Sprint, DomainError> {
+        let mut sprint = self.get_sprint(id).await?;
+        if sprint.is_closed() {
+            return Err(DomainError::ValidationError("Closed sprints cannot be changed".into()));
+        }
+
+        if let Some(name) = cmd.name {
+            sprint.name = name.trim().to_string();
+        }
+        if let Some(goal) = cmd.goal {
+            sprint.goal = Some(goal);
+        }
+        if let Some(start_date) = cmd.start_date {
+            if sprint.status != SprintStatus::Planned {
+                return Err(DomainError::ValidationError(
+                    "Start date of a running sprint cannot be changed".into(),
+                ));
+            }
+            sprint.start_date = start_date;
+        }
+        if let Some(end_date) = cmd.end_date {
+            sprint.end_date = end_date;
+        }
+        sprint.validate()?;
+
+        self.sprint_repository.update(&sprint).await
+    }
+
+    /// Only planned sprints can be deleted; their tasks return to the backlog
+    pub async fn delete_sprint(&self, id: Uuid) -> Result<(), DomainError> {
+        let sprint = self.get_sprint(id).await?;
+        if sprint.status != SprintStatus::Planned {
+            return Err(DomainError::ValidationError("Only planned sprints can be deleted".into()));
+        }
+        self.sprint_repository.delete(id).await
+    }
+
+    pub async fn get_sprint_tasks(&self, id: Uuid) -> Result<Vec<Task>, DomainError> {
+        self.get_sprint(id).await?;
+        self.task_repository.find_by_sprint(id).await
+    }
+
+    /// Plan unfinished tasks of the sprint's project into it, taking them out of any other sprint
+    pub async fn plan_tasks(&self, id: Uuid, cmd: PlanSprintCommand, user_id: Uuid) -> Result<Vec<Task>, DomainError> {
+        let sprint = self.get_sprint(id).await?;
+        if sprint.is_closed() {
+            return Err(DomainError::ValidationError("Tasks cannot be planned into a closed sprint".into()));
+        }
+
+        let tasks = self.task_repository.find_by_ids(&cmd.task_ids).await?;
+        if let Some(missing) = cmd.task_ids.iter().find(|id| !tasks.iter().any(|t| t.id == **id)) {
+            return Err(DomainError::NotFound(format!("Task {} not found", missing)));
+        }
+
+        let mut records = Vec::new();
+        let mut updated = Vec::new();
+        for task in tasks {
+            if task.project_id != sprint.project_id {
+                return Err(DomainError::ValidationError(format!(
+                    "Task {} belongs to another project",
+                    task.id
+                )));
+            }
+            if task.is_done() {
+                return Err(DomainError::ValidationError(format!("Task {} is already finished", task.id)));
+            }
+            let mut planned = task.clone();
+            planned.sprint_id = Some(sprint.id);
+            records.extend(ChangeRecord::updated(&task, &planned, Some(user_id)));
+            updated.push(planned);
+        }
+
+        self.task_repository
+            .apply_batch(&TaskBatch { updated, ..TaskBatch::default() })
+            .await?;
+        self.history_repository.record(&records).await?;
+        self.refresh_snapshot(&sprint).await?;
+
+        self.task_repository.find_by_sprint(sprint.id).await
+    }
+
+    /// Take a task out of the sprint, back into the backlog
+    pub async fn unplan_task(&self, id: Uuid, task_id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
+        let sprint = self.get_sprint(id).await?;
+        if sprint.is_closed() {
+            return Err(DomainError::ValidationError("Closed sprints cannot be changed".into()));
+        }
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .filter(|task| task.sprint_id == Some(sprint.id))
+            .ok_or_else(|| DomainError::NotFound("Task is not planned in this sprint".into()))?;
+
+        let mut unplanned = task.clone();
+        unplanned.sprint_id = None;
+        self.task_repository.update(&unplanned).await?;
+        if let Some(record) = ChangeRecord::updated(&task, &unplanned, Some(user_id)) {
+            self.history_repository.record(&[record]).await?;
+        }
+        self.refresh_snapshot(&sprint).await
+    }
+
+    /// Start the sprint, committing to the story points planned so far
+    pub async fn start_sprint(&self, id: Uuid) -> Result<Sprint, DomainError> {
+        let mut sprint = self.get_sprint(id).await?;
+        if self.sprint_repository.find_active(sprint.project_id).await?.is_some() {
+            return Err(DomainError::ValidationError("Project already has an active sprint".into()));
+        }
+
+        let tasks = self.task_repository.find_by_sprint(sprint.id).await?;
+        let snapshot = SprintSnapshot::capture(sprint.id, Utc::now().date_naive(), &tasks);
+        sprint.start(snapshot.total_points)?;
+
+        let sprint = self.sprint_repository.update(&sprint).await?;
+        self.sprint_repository.save_snapshot(&snapshot).await?;
+        Ok(sprint)
+    }
+
+    /// Close the sprint; unfinished tasks move to `carry_over_to` or back to the backlog
+    pub async fn close_sprint(&self, id: Uuid, cmd: CloseSprintCommand, user_id: Uuid) -> Result<ClosedSprint, DomainError> {
+        let mut sprint = self.get_sprint(id).await?;
+        if let Some(target_id) = cmd.carry_over_to {
+            let target = self
+                .sprint_repository
+                .find_by_id(target_id)
+                .await?
+                .ok_or_else(|| DomainError::NotFound("Carry-over sprint not found".into()))?;
+            if target.id == sprint.id || target.project_id != sprint.project_id || target.is_closed() {
+                return Err(DomainError::ValidationError(
+                    "Unfinished tasks can only carry over to another open sprint of the project".into(),
+                ));
+            }
+        }
+
+        let tasks = self.task_repository.find_by_sprint(sprint.id).await?;
+        let snapshot = SprintSnapshot::capture(sprint.id, Utc::now().date_naive(), &tasks);
+        sprint.close(snapshot.completed_points)?;
+
+        let unfinished: Vec<&Task> = tasks.iter().filter(|task| !task.is_done()).collect();
+        let carried_over: Vec<Uuid> = unfinished.iter().map(|task| task.id).collect();
+        let sprint = self
+            .sprint_repository
+            .close(&sprint, &snapshot, &carried_over, cmd.carry_over_to)
+            .await?;
+
+        let records: Vec<ChangeRecord> = unfinished
+            .into_iter()
+            .filter_map(|task| {
+                let mut carried = task.clone();
+                carried.sprint_id = cmd.carry_over_to;
+                ChangeRecord::updated(task, &carried, Some(user_id))
+            })
+            .collect();
+        self.history_repository.record(&records).await?;
+
+        Ok(ClosedSprint { sprint, carried_over })
+    }
+
+    /// Daily remaining, completed and total points; a running sprint includes today's live totals
+    pub async fn get_burndown(&self, id: Uuid) -> Result<SprintBurndown, DomainError> {
+        let sprint = self.get_sprint(id).await?;
+        let today = Utc::now().date_naive();
+
+        let mut snapshots = self.sprint_repository.find_snapshots(sprint.id).await?;
+        if sprint.status == SprintStatus::Active {
+            let tasks = self.task_repository.find_by_sprint(sprint.id).await?;
+            snapshots.retain(|s| s.snapshot_date != today);
+            snapshots.push(SprintSnapshot::capture(sprint.id, today, &tasks));
+        }
+
+        Ok(Burndown::build(&sprint, &snapshots, today))
+    }
+
+    /// Record the day's snapshot of every active sprint; returns how many were taken
+    pub async fn capture_snapshots(&self, date: NaiveDate) -> Result<usize, DomainError> {
+        let sprints = self.sprint_repository.find_all_active().await?;
+        for sprint in &sprints {
+            let tasks = self.task_repository.find_by_sprint(sprint.id).await?;
+            self.sprint_repository
+                .save_snapshot(&SprintSnapshot::capture(sprint.id, date, &tasks))
+                .await?;
+        }
+        Ok(sprints.len())
+    }
+
+    /// Keep today's snapshot of a running sprint in line with a change of its scope
+    async fn refresh_snapshot(&self, sprint: &Sprint) -> Result<(), DomainError> {
+        if sprint.status != SprintStatus::Active {
+            return Ok(());
+        }
+        let tasks = self.task_repository.find_by_sprint(sprint.id).await?;
+        self.sprint_repository
+            .save_snapshot(&SprintSnapshot::capture(sprint.id, Utc::now().date_naive(), &tasks))
+            .await
+    }
+}
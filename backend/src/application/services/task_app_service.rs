@@ -1,27 +1,138 @@
+use chrono::{Duration, Utc};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::application::commands::{CreateTaskCommand, UpdateTaskCommand};
-use crate::domain::entities::Task;
-use crate::domain::repositories::TaskRepository;
+use crate::application::commands::{BulkTaskCommand, CloneTaskCommand, CreateTaskCommand, MoveTaskCommand, UpdateTaskCommand};
+use crate::application::queries::{Paginated, TaskQuery};
+use crate::application::services::{AttachmentAppService, MentionAppService, NotificationAppService, WithMentions};
+use crate::domain::entities::{
+    ActivityLog, ChangeEntityType, ChangeRecord, ChecklistItem, CustomField, CustomFieldType, Milestone, NotificationType, ProjectWorkflow, Task, TaskDependency, TaskTemplate,
+    TaskWatcher, TimeLog, Trashed, WorkflowStatus,
+};
+use crate::domain::repositories::{
+    ActivityLogRepository, ChangeHistoryRepository, ChecklistRepository, CustomFieldRepository, MilestoneRepository, TagRepository, TaskBatch, TaskDependencyRepository, TaskFilter,
+    TaskRepository, TaskTemplateRepository, TimeLogRepository, WorkflowRepository,
+};
+use crate::domain::services::{DependencyGraph, TaskRank, TaskTree, TaskTreeNode};
+use crate::domain::value_objects::{Priority, ProjectRole, TaskSort, TaskStatus};
 use crate::shared::DomainError;
 
+const MAX_BULK_TASKS: usize = 500;
+
+#[derive(Debug, Serialize)]
+pub struct TaskDependencies {
+    pub blocked_by: Vec<Task>,
+    pub blocks: Vec<Task>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkTaskOutcome {
+    Updated,
+    Deleted,
+    NotFound,
+    Forbidden,
+    Invalid,
+    /// Valid, but not written because another task failed an atomic request
+    Skipped,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BulkTaskResult {
+    pub task_id: Uuid,
+    pub outcome: BulkTaskOutcome,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BulkTaskResponse {
+    pub updated: usize,
+    pub deleted: usize,
+    pub failed: usize,
+    pub results: Vec<BulkTaskResult>,
+}
+
 pub struct TaskAppService {
     task_repository: Arc<dyn TaskRepository>,
+    workflow_repository: Arc<dyn WorkflowRepository>,
+    dependency_repository: Arc<dyn TaskDependencyRepository>,
+    mention_service: Arc<MentionAppService>,
+    template_repository: Arc<dyn TaskTemplateRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+    checklist_repository: Arc<dyn ChecklistRepository>,
+    milestone_repository: Arc<dyn MilestoneRepository>,
+    custom_field_repository: Arc<dyn CustomFieldRepository>,
+    history_repository: Arc<dyn ChangeHistoryRepository>,
+    notification_service: Arc<NotificationAppService>,
+    attachment_service: Arc<AttachmentAppService>,
+    time_log_repository: Arc<dyn TimeLogRepository>,
+    activity_repository: Arc<dyn ActivityLogRepository>,
 }
 
 impl TaskAppService {
-    pub fn new(task_repository: Arc<dyn TaskRepository>) -> Self {
-        Self { task_repository }
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+        dependency_repository: Arc<dyn TaskDependencyRepository>,
+        mention_service: Arc<MentionAppService>,
+        template_repository: Arc<dyn TaskTemplateRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+        checklist_repository: Arc<dyn ChecklistRepository>,
+        milestone_repository: Arc<dyn MilestoneRepository>,
+        custom_field_repository: Arc<dyn CustomFieldRepository>,
+        history_repository: Arc<dyn ChangeHistoryRepository>,
+        notification_service: Arc<NotificationAppService>,
+        attachment_service: Arc<AttachmentAppService>,
+        time_log_repository: Arc<dyn TimeLogRepository>,
+        activity_repository: Arc<dyn ActivityLogRepository>,
+    ) -> Self {
+        Self {
+            task_repository,
+            workflow_repository,
+            dependency_repository,
+            mention_service,
+            template_repository,
+            tag_repository,
+            checklist_repository,
+            milestone_repository,
+            custom_field_repository,
+            history_repository,
+            notification_service,
+            attachment_service,
+            time_log_repository,
+            activity_repository,
+        }
     }
 
-    pub async fn list_tasks(&self) -> Result<Vec<Task>, DomainError> {
-        self.task_repository.find_all().await
-    }
+    /// List tasks matching the query; non-admins only see tasks of projects they can access
+    pub async fn list_tasks(
+        &self,
+        query: TaskQuery,
+        user_id: Uuid,
+        is_admin: bool,
+    ) -> Result<Paginated<Task>, DomainError> {
+        let pagination = query.pagination();
+        let mut custom_fields = HashMap::new();
+        for (field_id, value) in &query.custom_fields {
+            let field = self
+                .custom_field_repository
+                .find_by_id(*field_id)
+                .await?
+                .filter(|f| query.project_id.is_none_or(|project_id| f.project_id == project_id))
+                .ok_or_else(|| DomainError::ValidationError(format!("Unknown custom field {}", field_id)))?;
+            custom_fields.insert(*field_id, field.filter_value(value)?);
+        }
 
-    /// List tasks from projects user can access (owner OR member)
-    pub async fn list_accessible_tasks(&self, user_id: Uuid) -> Result<Vec<Task>, DomainError> {
-        self.task_repository.find_accessible_by_user(user_id).await
+        let mut filter = build_task_filter(query, (!is_admin).then_some(user_id))?;
+        filter.custom_fields = custom_fields;
+        let (tasks, total) = self.task_repository.find_filtered(&filter).await?;
+        Ok(Paginated::new(tasks, total, &pagination))
     }
 
     /// Check if user can access task (via project access)
@@ -29,16 +140,26 @@ impl TaskAppService {
         self.task_repository.can_user_access(task_id, user_id).await
     }
 
-    /// Check if user is owner of the project containing the task
-    pub async fn is_project_owner(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
-        self.task_repository.is_project_owner(task_id, user_id).await
+    /// Check if user owns the project containing the task or is a member with at least `role`
+    pub async fn has_role(&self, task_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.task_repository.has_role(task_id, user_id, role).await
     }
 
-    /// Check if user can access project (for create task)
+    /// Check the user's role on the project of a trashed task
+    pub async fn has_trashed_role(&self, task_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.task_repository.has_trashed_role(task_id, user_id, role).await
+    }
+
+    /// Check if user can access project
     pub async fn can_access_project(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
         self.task_repository.can_access_project(project_id, user_id).await
     }
 
+    /// Check if user owns the project or is a member with at least `role`
+    pub async fn has_project_role(&self, project_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.task_repository.has_project_role(project_id, user_id, role).await
+    }
+
     pub async fn get_task(&self, id: Uuid) -> Result<Task, DomainError> {
         self.task_repository
             .find_by_id(id)
@@ -46,26 +167,184 @@ impl TaskAppService {
             .ok_or_else(|| DomainError::NotFound("Task not found".into()))
     }
 
-    pub async fn create_task(&self, cmd: CreateTaskCommand) -> Result<Task, DomainError> {
-        let task = Task::new(
-            cmd.project_id,
-            cmd.title,
-            cmd.description,
-            cmd.priority,
-            cmd.assignee_id,
-            cmd.due_date,
-            cmd.estimated_hours,
+    /// Get a task together with the users mentioned in its description
+    pub async fn get_task_with_mentions(&self, id: Uuid) -> Result<WithMentions<Task>, DomainError> {
+        let task = self.get_task(id).await?;
+        let mentions = self
+            .mention_service
+            .resolve(task.project_id, task.description.as_deref())
+            .await?;
+        Ok(WithMentions { item: task, mentions })
+    }
+
+    /// Create a task; fields given in the command override the template's defaults
+    pub async fn create_task(
+        &self,
+        cmd: CreateTaskCommand,
+        user_id: Uuid,
+    ) -> Result<WithMentions<Task>, DomainError> {
+        let (task, template) = self.insert_task(cmd, true).await?;
+        self.finish_create(task, template.as_ref(), Some(user_id)).await
+    }
+
+    /// Insert a task generated by a recurring series; required custom fields
+    /// are not enforced as nobody is there to fill them in. Once this succeeds the
+    /// task exists, so the caller links it before running `finish_generated_task`.
+    pub async fn create_generated_task(&self, cmd: CreateTaskCommand) -> Result<Task, DomainError> {
+        let (task, _) = self.insert_task(cmd, false).await?;
+        Ok(task)
+    }
+
+    /// Record history, watchers, rollups and mentions for a generated task. Without an
+    /// actor (the series' creator was deleted) the history has no author and nobody is
+    /// subscribed.
+    pub async fn finish_generated_task(
+        &self,
+        task: Task,
+        actor: Option<Uuid>,
+    ) -> Result<WithMentions<Task>, DomainError> {
+        self.finish_create(task, None, actor).await
+    }
+
+    async fn insert_task(
+        &self,
+        cmd: CreateTaskCommand,
+        require_custom_fields: bool,
+    ) -> Result<(Task, Option<TaskTemplate>), DomainError> {
+        let workflow = self.get_workflow(cmd.project_id).await?;
+        if let Some(parent_id) = cmd.parent_id {
+            self.validate_parent(cmd.project_id, parent_id).await?;
+        }
+
+        let template = match cmd.template_id {
+            Some(template_id) => Some(self.get_template(cmd.project_id, template_id).await?),
+            None => None,
+        };
+        let mut task = match &template {
+            Some(template) => Task::new(
+                cmd.project_id,
+                template.render_title(&cmd.title, Utc::now().date_naive())?,
+                cmd.description.or_else(|| template.description.clone()),
+                cmd.priority.or_else(|| Some(template.priority.clone())),
+                cmd.assignee_id,
+                cmd.due_date,
+                cmd.estimated_hours.or(template.estimated_hours),
+            ),
+            None => {
+                if cmd.title.trim().is_empty() {
+                    return Err(DomainError::ValidationError("Task title cannot be empty".into()));
+                }
+                Task::new(
+                    cmd.project_id,
+                    cmd.title,
+                    cmd.description,
+                    cmd.priority,
+                    cmd.assignee_id,
+                    cmd.due_date,
+                    cmd.estimated_hours,
+                )
+            }
+        };
+        task.parent_id = cmd.parent_id;
+        task.start_date = cmd.start_date;
+        task.validate_dates()?;
+        if let Some(milestone_id) = cmd.milestone_id {
+            self.validate_milestone(task.project_id, milestone_id).await?;
+            task.milestone_id = Some(milestone_id);
+        }
+        if let Some(story_points) = cmd.story_points {
+            task.story_points = Some(validate_story_points(story_points)?);
+        }
+        let fields = self.custom_field_repository.find_by_project(task.project_id).await?;
+        self.set_custom_fields(&mut task, &fields, &cmd.custom_fields).await?;
+        if require_custom_fields {
+            CustomField::check_required(&fields, &task.custom_fields)?;
+        }
+        if let Some(initial) = workflow.initial_status() {
+            task.move_to_workflow_status(initial);
+        }
+        task.rank = TaskRank::after(
+            self.task_repository
+                .find_last_rank(task.project_id, &task.workflow_status)
+                .await?,
         );
 
-        self.task_repository.create(&task).await
+        let task = self.task_repository.create(&task).await?;
+        Ok((task, template))
     }
 
-    pub async fn update_task(&self, id: Uuid, cmd: UpdateTaskCommand) -> Result<Task, DomainError> {
+    async fn finish_create(
+        &self,
+        task: Task,
+        template: Option<&TaskTemplate>,
+        actor: Option<Uuid>,
+    ) -> Result<WithMentions<Task>, DomainError> {
+        self.history_repository
+            .record(&[ChangeRecord::created(&task, actor)])
+            .await?;
+        if let Some(user_id) = actor {
+            self.notification_service.watch_task(task.id, user_id).await?;
+        }
+        if let Some(template) = template {
+            self.apply_template(&task, template).await?;
+        }
+        self.rollup_hours(task.parent_id).await?;
+        let mentions = self
+            .mention_service
+            .notify_mentions(&task, actor, task.description.as_deref(), None)
+            .await?;
+
+        Ok(WithMentions { item: task, mentions })
+    }
+
+    pub async fn update_task(
+        &self,
+        id: Uuid,
+        cmd: UpdateTaskCommand,
+        user_id: Uuid,
+    ) -> Result<WithMentions<Task>, DomainError> {
         let mut task = self
             .task_repository
             .find_by_id(id)
             .await?
             .ok_or_else(|| DomainError::NotFound("Task not found".into()))?;
+        let previous = task.clone();
+
+        if cmd.parent_id.is_some() && cmd.clear_parent {
+            return Err(DomainError::ValidationError(
+                "Cannot both set and clear the parent".into(),
+            ));
+        }
+        if let Some(parent_id) = cmd.parent_id {
+            if task.parent_id != Some(parent_id) {
+                self.validate_parent(task.project_id, parent_id).await?;
+                let subtree = self.task_repository.find_subtree(task.id).await?;
+                if subtree.iter().any(|t| t.id == parent_id) {
+                    return Err(DomainError::ValidationError(
+                        "Task cannot be moved under itself or its own subtask".into(),
+                    ));
+                }
+                task.parent_id = Some(parent_id);
+            }
+        } else if cmd.clear_parent {
+            task.parent_id = None;
+        }
+        if cmd.milestone_id.is_some() && cmd.clear_milestone {
+            return Err(DomainError::ValidationError(
+                "Cannot both set and clear the milestone".into(),
+            ));
+        }
+        if cmd.estimated_hours.is_some() || cmd.actual_hours.is_some() {
+            // Only figures some subtask carries are rolled up; the others stay editable
+            let children = self.task_repository.find_children(task.id).await?;
+            if (cmd.estimated_hours.is_some() && children.iter().any(|c| c.estimated_hours.is_some()))
+                || (cmd.actual_hours.is_some() && children.iter().any(|c| c.actual_hours.is_some()))
+            {
+                return Err(DomainError::ValidationError(
+                    "Hours of a task with subtasks are rolled up from its subtasks".into(),
+                ));
+            }
+        }
 
         if let Some(title) = cmd.title {
             task.title = title;
@@ -73,8 +352,12 @@ impl TaskAppService {
         if let Some(description) = cmd.description {
             task.description = Some(description);
         }
-        if let Some(status) = cmd.status {
-            task.update_status(status);
+        if cmd.workflow_status.is_some() || cmd.status.is_some() {
+            let workflow = self.get_workflow(task.project_id).await?;
+            let target = self
+                .resolve_status_change(&workflow, &task, cmd.workflow_status.as_deref(), cmd.status.as_ref())
+                .await?;
+            self.move_to_column(&mut task, &target).await?;
         }
         if let Some(priority) = cmd.priority {
             task.priority = priority;
@@ -82,27 +365,956 @@ impl TaskAppService {
         if cmd.assignee_id.is_some() {
             task.assign_to(cmd.assignee_id);
         }
+        if let Some(start_date) = cmd.start_date {
+            task.start_date = Some(start_date);
+        }
         if let Some(due_date) = cmd.due_date {
             task.due_date = Some(due_date);
         }
+        task.validate_dates()?;
         if let Some(estimated_hours) = cmd.estimated_hours {
             task.estimated_hours = Some(estimated_hours);
         }
         if let Some(actual_hours) = cmd.actual_hours {
             task.actual_hours = Some(actual_hours);
         }
+        if let Some(story_points) = cmd.story_points {
+            task.story_points = Some(validate_story_points(story_points)?);
+        }
+        if let Some(milestone_id) = cmd.milestone_id {
+            if task.milestone_id != Some(milestone_id) {
+                self.validate_milestone(task.project_id, milestone_id).await?;
+                task.milestone_id = Some(milestone_id);
+            }
+        } else if cmd.clear_milestone {
+            task.milestone_id = None;
+        }
+        if !cmd.custom_fields.is_empty() {
+            let fields = self.custom_field_repository.find_by_project(task.project_id).await?;
+            self.set_custom_fields(&mut task, &fields, &cmd.custom_fields).await?;
+        }
 
-        self.task_repository.update(&task).await
+        let task = self.task_repository.update(&task).await?;
+        self.record_update(&previous, &task, Some(user_id)).await?;
+        if previous.is_done() != task.is_done() {
+            self.refresh_dependents(task.id).await?;
+        }
+        if previous.parent_id != task.parent_id {
+            self.rollup_hours(previous.parent_id).await?;
+        }
+        self.rollup_hours(task.parent_id).await?;
+        self.notify_watchers(&previous, &task, user_id).await?;
+        let mentions = self
+            .mention_service
+            .notify_mentions(
+                &task,
+                Some(user_id),
+                task.description.as_deref(),
+                previous.description.as_deref(),
+            )
+            .await?;
+
+        Ok(WithMentions { item: task, mentions })
     }
 
-    pub async fn delete_task(&self, id: Uuid) -> Result<(), DomainError> {
+    /// Validate and apply custom field changes; users must have access to the project
+    async fn set_custom_fields(
+        &self,
+        task: &mut Task,
+        fields: &[CustomField],
+        changes: &HashMap<Uuid, Value>,
+    ) -> Result<(), DomainError> {
+        let values = CustomField::merge_values(fields, &task.custom_fields, changes)?;
+        for field in fields.iter().filter(|f| f.field_type == CustomFieldType::User) {
+            let user_id = changes
+                .get(&field.id)
+                .and(values.get(&field.id))
+                .and_then(Value::as_str)
+                .and_then(|id| Uuid::parse_str(id).ok());
+            if let Some(user_id) = user_id {
+                if !self.task_repository.can_access_project(task.project_id, user_id).await? {
+                    return Err(DomainError::ValidationError(format!(
+                        "Field '{}' must be a user with access to the project",
+                        field.name
+                    )));
+                }
+            }
+        }
+
+        task.custom_fields = values;
+        Ok(())
+    }
+
+    pub async fn delete_task(&self, id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
         // Check if task exists
-        self.task_repository
+        let task = self
+            .task_repository
             .find_by_id(id)
             .await?
             .ok_or_else(|| DomainError::NotFound("Task not found".into()))?;
 
-        self.task_repository.delete(id).await
+        // Subtasks go to the trash with the task, so collect them first
+        let subtree = self.task_repository.find_subtree(id).await?;
+        self.task_repository.delete(id).await?;
+        let deleted: Vec<ChangeRecord> = subtree
+            .iter()
+            .map(|task| ChangeRecord::deleted(task, Some(user_id)))
+            .collect();
+        self.history_repository.record(&deleted).await?;
+        for task in &subtree {
+            self.refresh_dependents(task.id).await?;
+        }
+        self.rollup_hours(task.parent_id).await?;
+
+        Ok(())
+    }
+
+    /// List the trashed tasks of a project; subtasks are restored with their parent
+    pub async fn get_trash(&self, project_id: Uuid) -> Result<Vec<Trashed<Task>>, DomainError> {
+        self.task_repository.find_trashed(project_id).await
+    }
+
+    /// Restore a trashed task together with the subtasks deleted along with it
+    pub async fn restore_task(&self, id: Uuid, user_id: Uuid) -> Result<Task, DomainError> {
+        let trashed = self
+            .task_repository
+            .find_trashed_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Task not found in trash".into()))?;
+        let task = trashed.item;
+
+        if let Some(parent_id) = task.parent_id {
+            if self.task_repository.find_by_id(parent_id).await?.is_none() {
+                return Err(DomainError::ValidationError(
+                    "Parent task is in the trash; restore the parent first".into(),
+                ));
+            }
+        }
+
+        let restored = self.task_repository.restore(id).await?;
+        if restored.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Project is in the trash; restore the project first".into(),
+            ));
+        }
+        let history: Vec<ChangeRecord> = restored
+            .iter()
+            .map(|task| ChangeRecord::restored(task, Some(user_id)))
+            .collect();
+        self.history_repository.record(&history).await?;
+
+        for task in &restored {
+            self.refresh_blocked_state(task.id).await?;
+            self.refresh_dependents(task.id).await?;
+        }
+        self.rollup_hours(task.parent_id).await?;
+
+        self.get_task(id).await
+    }
+
+    /// Apply one set of changes to many tasks. Every task is checked on its own;
+    /// the tasks that pass are written in a single transaction.
+    pub async fn bulk_update(
+        &self,
+        cmd: BulkTaskCommand,
+        user_id: Uuid,
+        is_admin: bool,
+    ) -> Result<BulkTaskResponse, DomainError> {
+        validate_bulk_command(&cmd)?;
+        for tag_id in &cmd.add_tag_ids {
+            self.tag_repository
+                .find_by_id(*tag_id)
+                .await?
+                .ok_or_else(|| DomainError::NotFound(format!("Tag {} not found", tag_id)))?;
+        }
+        let milestone = match cmd.milestone_id {
+            Some(milestone_id) => Some(
+                self.milestone_repository
+                    .find_by_id(milestone_id)
+                    .await?
+                    .ok_or_else(|| DomainError::NotFound("Milestone not found".into()))?,
+            ),
+            None => None,
+        };
+
+        let mut seen = HashSet::new();
+        let task_ids: Vec<Uuid> = cmd.task_ids.iter().copied().filter(|id| seen.insert(*id)).collect();
+        let mut tasks: HashMap<Uuid, Task> = self
+            .task_repository
+            .find_by_ids(&task_ids)
+            .await?
+            .into_iter()
+            .map(|task| (task.id, task))
+            .collect();
+
+        let mut workflows: HashMap<Uuid, ProjectWorkflow> = HashMap::new();
+        let mut last_ranks = HashMap::new();
+        let mut batch = TaskBatch {
+            add_tag_ids: cmd.add_tag_ids.clone(),
+            remove_tag_ids: cmd.remove_tag_ids.clone(),
+            ..Default::default()
+        };
+        let mut previous_versions = Vec::new();
+        let mut deleted_parents = Vec::new();
+        let mut results = Vec::with_capacity(task_ids.len());
+
+        for id in task_ids {
+            let Some(mut task) = tasks.remove(&id) else {
+                results.push(BulkTaskResult::failed(id, BulkTaskOutcome::NotFound, "Task not found"));
+                continue;
+            };
+
+            if cmd.delete {
+                if !is_admin && !self.task_repository.has_role(id, user_id, ProjectRole::Maintainer).await? {
+                    results.push(BulkTaskResult::failed(
+                        id,
+                        BulkTaskOutcome::Forbidden,
+                        "Only project owner or maintainers can delete tasks",
+                    ));
+                    continue;
+                }
+                batch.deleted.push(id);
+                deleted_parents.push(task.parent_id);
+                results.push(BulkTaskResult::succeeded(id, BulkTaskOutcome::Deleted));
+                continue;
+            }
+
+            if !is_admin && !self.task_repository.has_role(id, user_id, ProjectRole::Contributor).await? {
+                results.push(BulkTaskResult::failed(
+                    id,
+                    BulkTaskOutcome::Forbidden,
+                    "You don't have permission to edit this task",
+                ));
+                continue;
+            }
+
+            let previous = task.clone();
+            match self
+                .apply_bulk_changes(&mut task, &cmd, milestone.as_ref(), &mut workflows, &mut last_ranks)
+                .await
+            {
+                Ok(()) => {
+                    previous_versions.push(previous);
+                    batch.updated.push(task);
+                    results.push(BulkTaskResult::succeeded(id, BulkTaskOutcome::Updated));
+                }
+                Err(DomainError::ValidationError(message)) => {
+                    results.push(BulkTaskResult::failed(id, BulkTaskOutcome::Invalid, &message));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        let failed = results.iter().filter(|result| result.error.is_some()).count();
+        if cmd.atomic && failed > 0 {
+            for result in results.iter_mut().filter(|result| result.error.is_none()) {
+                result.outcome = BulkTaskOutcome::Skipped;
+            }
+            return Ok(BulkTaskResponse { updated: 0, deleted: 0, failed, results });
+        }
+
+        // Subtasks go to the trash with deleted tasks, so collect them first
+        let mut dependents = Vec::new();
+        let mut deleted_records = Vec::new();
+        let mut snapshotted = HashSet::new();
+        for id in &batch.deleted {
+            for task in self.task_repository.find_subtree(*id).await? {
+                dependents.extend(self.dependency_repository.find_blocked(task.id).await?);
+                if snapshotted.insert(task.id) {
+                    deleted_records.push(ChangeRecord::deleted(&task, Some(user_id)));
+                }
+            }
+        }
+
+        self.task_repository.apply_batch(&batch).await?;
+
+        let mut history = deleted_records;
+        history.extend(
+            previous_versions
+                .iter()
+                .zip(&batch.updated)
+                .filter_map(|(previous, task)| ChangeRecord::updated(previous, task, Some(user_id))),
+        );
+        self.history_repository.record(&history).await?;
+
+        let deleted: HashSet<Uuid> = batch.deleted.iter().copied().collect();
+        for (previous, task) in previous_versions.iter().zip(&batch.updated) {
+            if previous.is_done() != task.is_done() {
+                self.refresh_dependents(task.id).await?;
+            }
+            self.notify_watchers(previous, task, user_id).await?;
+        }
+        for dependency in dependents {
+            if !deleted.contains(&dependency.blocked_task_id) {
+                self.refresh_blocked_state(dependency.blocked_task_id).await?;
+            }
+        }
+        for parent_id in deleted_parents.into_iter().flatten() {
+            if !deleted.contains(&parent_id) {
+                self.rollup_hours(Some(parent_id)).await?;
+            }
+        }
+
+        Ok(BulkTaskResponse {
+            updated: batch.updated.len(),
+            deleted: batch.deleted.len(),
+            failed,
+            results,
+        })
+    }
+
+    /// Drop a task into a board column between two neighbours, optionally changing its status.
+    /// Given another project, the task moves there together with its subtasks.
+    pub async fn move_task(&self, id: Uuid, cmd: MoveTaskCommand, user_id: Uuid) -> Result<Task, DomainError> {
+        let mut task = self.get_task(id).await?;
+        if cmd.before_id == Some(id) || cmd.after_id == Some(id) {
+            return Err(DomainError::ValidationError(
+                "A task cannot be its own neighbour".into(),
+            ));
+        }
+        let previous = task.clone();
+
+        let target_project = cmd.project_id.filter(|project_id| *project_id != task.project_id);
+        let target_milestones = match target_project {
+            Some(project_id) => {
+                let milestones = self.milestone_repository.find_by_project(project_id).await?;
+                let workflow = self.get_workflow(project_id).await?;
+                // The task leaves its parent behind; subtasks keep theirs
+                task.parent_id = None;
+                self.reassign_project(&mut task, &workflow, &milestones).await?;
+                milestones
+            }
+            None => Vec::new(),
+        };
+
+        if cmd.workflow_status.is_some() || cmd.status.is_some() {
+            let workflow = self.get_workflow(task.project_id).await?;
+            let target = self
+                .resolve_status_change(&workflow, &task, cmd.workflow_status.as_deref(), cmd.status.as_ref())
+                .await?;
+            task.move_to_workflow_status(&target);
+        }
+
+        let column: Vec<Task> = self
+            .task_repository
+            .find_column(task.project_id, &task.workflow_status)
+            .await?
+            .into_iter()
+            .filter(|t| t.id != task.id)
+            .collect();
+        let position = |neighbour_id: Uuid| {
+            column.iter().position(|t| t.id == neighbour_id).ok_or_else(|| {
+                DomainError::ValidationError("Neighbour task is not in the target column".into())
+            })
+        };
+        let index = match (cmd.before_id, cmd.after_id) {
+            (Some(before_id), Some(after_id)) => {
+                let after = position(after_id)?;
+                if position(before_id)? + 1 != after {
+                    return Err(DomainError::ValidationError(
+                        "Neighbour tasks are not adjacent".into(),
+                    ));
+                }
+                after
+            }
+            (Some(before_id), None) => position(before_id)? + 1,
+            (None, Some(after_id)) => position(after_id)?,
+            (None, None) => column.len(),
+        };
+
+        let ranks: Vec<f64> = column.iter().map(|t| t.rank).collect();
+        let placement = TaskRank::place(&ranks, index);
+        task.rank = placement.rank;
+        let rebalanced: Vec<(Uuid, f64)> = placement
+            .rebalanced
+            .into_iter()
+            .map(|(i, rank)| (column[i].id, rank))
+            .collect();
+
+        let subtasks = match target_project {
+            Some(_) => self.carry_subtasks(&task, &target_milestones).await?,
+            None => Vec::new(),
+        };
+        let moved_subtasks: Vec<Task> = subtasks.iter().map(|(_, subtask)| subtask.clone()).collect();
+
+        let task = self.task_repository.move_task(&task, &rebalanced, &moved_subtasks).await?;
+        self.record_update(&previous, &task, Some(user_id)).await?;
+        if previous.is_done() != task.is_done() {
+            self.refresh_dependents(task.id).await?;
+        }
+        self.notify_watchers(&previous, &task, user_id).await?;
+
+        if target_project.is_some() {
+            let records: Vec<ChangeRecord> = subtasks
+                .iter()
+                .filter_map(|(before, after)| ChangeRecord::updated(before, after, Some(user_id)))
+                .collect();
+            self.history_repository.record(&records).await?;
+            self.rollup_hours(previous.parent_id).await?;
+            self.log_activity(
+                user_id,
+                &task,
+                "task_moved",
+                json!({
+                    "from_project_id": previous.project_id,
+                    "to_project_id": task.project_id,
+                    "subtasks": subtasks.len(),
+                    "milestone_cleared": previous.milestone_id.is_some() && task.milestone_id.is_none(),
+                }),
+            )
+            .await?;
+        }
+
+        Ok(task)
+    }
+
+    /// Copy a task with its tags, checklist and attachments, and optionally its time logs.
+    /// Subtasks are not copied.
+    pub async fn clone_task(&self, id: Uuid, cmd: CloneTaskCommand, user_id: Uuid) -> Result<Task, DomainError> {
+        let source = self.get_task(id).await?;
+        let project_id = cmd.project_id.unwrap_or(source.project_id);
+        let title = cmd.title.unwrap_or_else(|| source.title.clone());
+        if title.trim().is_empty() {
+            return Err(DomainError::ValidationError("Task title cannot be empty".into()));
+        }
+
+        let mut task = Task::new(
+            source.project_id,
+            title,
+            source.description.clone(),
+            Some(source.priority.clone()),
+            source.assignee_id,
+            source.due_date,
+            source.estimated_hours,
+        );
+        task.parent_id = source.parent_id;
+        task.start_date = source.start_date;
+        task.milestone_id = source.milestone_id;
+        task.story_points = source.story_points;
+        task.custom_fields = source.custom_fields.clone();
+        if cmd.include_time_logs {
+            task.actual_hours = source.actual_hours;
+        }
+
+        let workflow = self.get_workflow(project_id).await?;
+        if project_id != source.project_id {
+            let milestones = self.milestone_repository.find_by_project(project_id).await?;
+            task.parent_id = None;
+            self.reassign_project(&mut task, &workflow, &milestones).await?;
+        }
+        if let Some(initial) = workflow.initial_status() {
+            task.move_to_workflow_status(initial);
+        }
+        task.rank = TaskRank::after(
+            self.task_repository
+                .find_last_rank(task.project_id, &task.workflow_status)
+                .await?,
+        );
+
+        let task = self.task_repository.create(&task).await?;
+        self.history_repository
+            .record(&[ChangeRecord::created(&task, Some(user_id))])
+            .await?;
+        self.notification_service.watch_task(task.id, user_id).await?;
+
+        let tag_ids: Vec<Uuid> = self
+            .tag_repository
+            .find_tags_by_task(source.id)
+            .await?
+            .into_iter()
+            .map(|tag| tag.id)
+            .collect();
+        if !tag_ids.is_empty() {
+            self.tag_repository.set_task_tags(task.id, tag_ids).await?;
+        }
+        // Checklist items start unchecked on the copy
+        for item in self.checklist_repository.find_by_task(source.id).await? {
+            let copy = ChecklistItem::new(task.id, item.content, item.position);
+            self.checklist_repository.create(&copy).await?;
+        }
+        let attachments = self.attachment_service.copy_task_attachments(source.id, task.id).await?;
+        let mut time_logs = 0;
+        if cmd.include_time_logs {
+            for log in self.time_log_repository.find_by_task(source.id).await? {
+                let copy = TimeLog::new(task.id, log.user_id, log.hours, log.date, log.description);
+                self.time_log_repository.create(&copy).await?;
+                time_logs += 1;
+            }
+        }
+        self.rollup_hours(task.parent_id).await?;
+
+        self.log_activity(
+            user_id,
+            &task,
+            "task_cloned",
+            json!({
+                "source_task_id": source.id,
+                "source_project_id": source.project_id,
+                "attachments": attachments.len(),
+                "time_logs": time_logs,
+            }),
+        )
+        .await?;
+
+        Ok(task)
+    }
+
+    pub async fn get_watchers(&self, task_id: Uuid) -> Result<Vec<TaskWatcher>, DomainError> {
+        self.get_task(task_id).await?;
+        self.notification_service.get_task_watchers(task_id).await
+    }
+
+    pub async fn watch_task(&self, task_id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
+        self.get_task(task_id).await?;
+        self.notification_service.watch_task(task_id, user_id).await
+    }
+
+    pub async fn unwatch_task(&self, task_id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
+        self.get_task(task_id).await?;
+        self.notification_service.unwatch_task(task_id, user_id).await
+    }
+
+    pub async fn get_subtasks(&self, task_id: Uuid) -> Result<Vec<Task>, DomainError> {
+        self.get_task(task_id).await?;
+        self.task_repository.find_children(task_id).await
+    }
+
+    /// Get the task with all of its descendants nested, including completion rollups
+    pub async fn get_task_tree(&self, task_id: Uuid) -> Result<TaskTreeNode, DomainError> {
+        let tasks = self.task_repository.find_subtree(task_id).await?;
+        TaskTree::build(task_id, tasks).ok_or_else(|| DomainError::NotFound("Task not found".into()))
+    }
+
+    pub async fn get_dependencies(&self, task_id: Uuid) -> Result<TaskDependencies, DomainError> {
+        self.get_task(task_id).await?;
+
+        let blocker_ids: Vec<Uuid> = self
+            .dependency_repository
+            .find_blockers(task_id)
+            .await?
+            .into_iter()
+            .map(|d| d.blocking_task_id)
+            .collect();
+        let blocked_ids: Vec<Uuid> = self
+            .dependency_repository
+            .find_blocked(task_id)
+            .await?
+            .into_iter()
+            .map(|d| d.blocked_task_id)
+            .collect();
+
+        Ok(TaskDependencies {
+            blocked_by: self.task_repository.find_by_ids(&blocker_ids).await?,
+            blocks: self.task_repository.find_by_ids(&blocked_ids).await?,
+        })
+    }
+
+    /// Record that `blocking_task_id` blocks `blocked_task_id`
+    pub async fn add_dependency(
+        &self,
+        blocked_task_id: Uuid,
+        blocking_task_id: Uuid,
+        created_by: Uuid,
+    ) -> Result<TaskDependency, DomainError> {
+        self.get_task(blocked_task_id).await?;
+        self.task_repository
+            .find_by_id(blocking_task_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Blocking task not found".into()))?;
+
+        let downstream = self.dependency_repository.find_downstream(blocked_task_id).await?;
+        let graph = DependencyGraph::new(
+            downstream
+                .iter()
+                .map(|d| (d.blocking_task_id, d.blocked_task_id)),
+        );
+        if graph.would_create_cycle(blocking_task_id, blocked_task_id) {
+            return Err(DomainError::ValidationError(
+                "Dependency would create a cycle".into(),
+            ));
+        }
+
+        let dependency = TaskDependency::new(blocking_task_id, blocked_task_id, Some(created_by));
+        let dependency = self.dependency_repository.create(&dependency).await?;
+        self.refresh_blocked_state(blocked_task_id).await?;
+
+        Ok(dependency)
+    }
+
+    pub async fn remove_dependency(
+        &self,
+        blocked_task_id: Uuid,
+        blocking_task_id: Uuid,
+    ) -> Result<(), DomainError> {
+        self.dependency_repository
+            .delete(blocking_task_id, blocked_task_id)
+            .await?;
+        self.refresh_blocked_state(blocked_task_id).await
+    }
+
+    /// Resolve the workflow status a task moves to, checking the transition,
+    /// its blockers and, when the workflow requires it, its subtasks
+    async fn resolve_status_change(
+        &self,
+        workflow: &ProjectWorkflow,
+        task: &Task,
+        workflow_status: Option<&str>,
+        status: Option<&TaskStatus>,
+    ) -> Result<WorkflowStatus, DomainError> {
+        let target = match (workflow_status, status) {
+            (Some(key), _) => workflow.find_status(key).ok_or_else(|| {
+                DomainError::ValidationError(format!("Unknown workflow status '{}'", key))
+            })?,
+            (None, Some(status)) => workflow.status_for_category(status).ok_or_else(|| {
+                DomainError::ValidationError(format!(
+                    "Project workflow has no status for '{}'",
+                    status.as_str()
+                ))
+            })?,
+            (None, None) => {
+                return Err(DomainError::ValidationError("No status given".into()));
+            }
+        };
+
+        if !workflow.can_transition(&task.workflow_status, &target.key) {
+            return Err(DomainError::ValidationError(format!(
+                "Invalid status transition from '{}' to '{}'",
+                task.workflow_status, target.key
+            )));
+        }
+        if target.is_done()
+            && self.dependency_repository.count_unfinished_blockers(task.id).await? > 0
+        {
+            return Err(DomainError::ValidationError(
+                "Task is blocked by unfinished tasks".into(),
+            ));
+        }
+        if target.is_done()
+            && workflow.require_subtasks_done
+            && self
+                .task_repository
+                .find_children(task.id)
+                .await?
+                .iter()
+                .any(|child| !child.is_done())
+        {
+            return Err(DomainError::ValidationError(
+                "Task has unfinished subtasks".into(),
+            ));
+        }
+        Ok(target.clone())
+    }
+
+    async fn apply_bulk_changes(
+        &self,
+        task: &mut Task,
+        cmd: &BulkTaskCommand,
+        milestone: Option<&Milestone>,
+        workflows: &mut HashMap<Uuid, ProjectWorkflow>,
+        last_ranks: &mut HashMap<(Uuid, String), f64>,
+    ) -> Result<(), DomainError> {
+        if cmd.workflow_status.is_some() || cmd.status.is_some() {
+            let workflow = match workflows.entry(task.project_id) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(self.get_workflow(task.project_id).await?),
+            };
+            let target = self
+                .resolve_status_change(workflow, task, cmd.workflow_status.as_deref(), cmd.status.as_ref())
+                .await?;
+            // Tasks moved into the same column are appended one after another
+            if task.workflow_status != target.key {
+                let column = (task.project_id, target.key.clone());
+                let last = match last_ranks.get(&column) {
+                    Some(rank) => Some(*rank),
+                    None => self.task_repository.find_last_rank(task.project_id, &target.key).await?,
+                };
+                task.rank = TaskRank::after(last);
+                last_ranks.insert(column, task.rank);
+            }
+            task.move_to_workflow_status(&target);
+        }
+        if let Some(priority) = &cmd.priority {
+            task.priority = priority.clone();
+        }
+        if cmd.unassign {
+            task.assign_to(None);
+        } else if cmd.assignee_id.is_some() {
+            task.assign_to(cmd.assignee_id);
+        }
+        if let Some(milestone) = milestone {
+            if milestone.project_id != task.project_id {
+                return Err(DomainError::ValidationError(
+                    "Milestone belongs to another project".into(),
+                ));
+            }
+            task.milestone_id = Some(milestone.id);
+        } else if cmd.clear_milestone {
+            task.milestone_id = None;
+        }
+        if let Some(days) = cmd.due_date_shift_days {
+            task.due_date = task.due_date.map(|due_date| due_date + Duration::days(days));
+        }
+        Ok(())
+    }
+
+    /// Tell the task's watchers what changed; reordering alone is not worth a notification
+    async fn notify_watchers(&self, previous: &Task, task: &Task, actor_id: Uuid) -> Result<(), DomainError> {
+        let changed = task.changed_fields(previous);
+        if changed.is_empty() {
+            return Ok(());
+        }
+
+        let (notification_type, title, message) = if task.is_done() && !previous.is_done() {
+            (
+                NotificationType::TaskCompleted,
+                format!("Task \"{}\" was completed", task.title),
+                format!("Moved to {}", task.workflow_status),
+            )
+        } else {
+            (
+                NotificationType::TaskUpdated,
+                format!("Task \"{}\" was updated", task.title),
+                format!("Changed: {}", changed.join(", ")),
+            )
+        };
+        self.notification_service
+            .notify_watchers(task, actor_id, notification_type, title, message)
+            .await
+    }
+
+    /// Re-home a task in another project: its status is mapped onto the target workflow and
+    /// values that only exist in the old project are dropped. The milestone is kept when the
+    /// target project has one of the same name.
+    async fn reassign_project(
+        &self,
+        task: &mut Task,
+        workflow: &ProjectWorkflow,
+        milestones: &[Milestone],
+    ) -> Result<(), DomainError> {
+        task.milestone_id = match task.milestone_id {
+            Some(milestone_id) => match self.milestone_repository.find_by_id(milestone_id).await? {
+                Some(current) => milestones.iter().find(|m| m.name == current.name).map(|m| m.id),
+                None => None,
+            },
+            None => None,
+        };
+        if let Some(assignee_id) = task.assignee_id {
+            if !self.can_access_project(workflow.project_id, assignee_id).await? {
+                task.assign_to(None);
+            }
+        }
+        task.move_to_project(workflow.project_id);
+        if let Some(status) = workflow.equivalent_status(&task.workflow_status, &task.status) {
+            task.move_to_workflow_status(status);
+        }
+        task.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Re-home the descendants of a task already placed in its new project, appending each to
+    /// the bottom of its column. Returns every subtask before and after the move.
+    async fn carry_subtasks(&self, task: &Task, milestones: &[Milestone]) -> Result<Vec<(Task, Task)>, DomainError> {
+        let workflow = self.get_workflow(task.project_id).await?;
+        let mut last_ranks: HashMap<String, f64> = HashMap::from([(task.workflow_status.clone(), task.rank)]);
+        let mut moved = Vec::new();
+
+        for previous in self.task_repository.find_subtree(task.id).await? {
+            if previous.id == task.id {
+                continue;
+            }
+            let mut subtask = previous.clone();
+            self.reassign_project(&mut subtask, &workflow, milestones).await?;
+
+            let last = self
+                .task_repository
+                .find_last_rank(task.project_id, &subtask.workflow_status)
+                .await?;
+            let last = match (last, last_ranks.get(&subtask.workflow_status)) {
+                (Some(stored), Some(placed)) => Some(stored.max(*placed)),
+                (stored, placed) => stored.or(placed.copied()),
+            };
+            subtask.rank = TaskRank::after(last);
+            last_ranks.insert(subtask.workflow_status.clone(), subtask.rank);
+            moved.push((previous, subtask));
+        }
+        Ok(moved)
+    }
+
+    async fn log_activity(&self, user_id: Uuid, task: &Task, action: &str, details: Value) -> Result<(), DomainError> {
+        let log = ActivityLog::new(
+            Some(user_id),
+            Some(task.project_id),
+            action.to_string(),
+            "task".to_string(),
+            task.id,
+            Some(details),
+        );
+        self.activity_repository.create(&log).await?;
+        Ok(())
+    }
+
+    /// Move the task to a workflow status, appending it to the bottom of that board column
+    async fn move_to_column(&self, task: &mut Task, target: &WorkflowStatus) -> Result<(), DomainError> {
+        if task.workflow_status != target.key {
+            let last = self
+                .task_repository
+                .find_last_rank(task.project_id, &target.key)
+                .await?;
+            task.rank = TaskRank::after(last);
+        }
+        task.move_to_workflow_status(target);
+        Ok(())
+    }
+
+    async fn validate_parent(&self, project_id: Uuid, parent_id: Uuid) -> Result<(), DomainError> {
+        let parent = self
+            .task_repository
+            .find_by_id(parent_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Parent task not found".into()))?;
+        if parent.project_id != project_id {
+            return Err(DomainError::ValidationError(
+                "Parent task must belong to the same project".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn validate_milestone(&self, project_id: Uuid, milestone_id: Uuid) -> Result<(), DomainError> {
+        let milestone = self
+            .milestone_repository
+            .find_by_id(milestone_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Milestone not found".into()))?;
+        if milestone.project_id != project_id {
+            return Err(DomainError::ValidationError(
+                "Milestone belongs to another project".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn get_template(&self, project_id: Uuid, template_id: Uuid) -> Result<TaskTemplate, DomainError> {
+        let template = self
+            .template_repository
+            .find_by_id(template_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Task template not found".into()))?;
+        if template.project_id != project_id {
+            return Err(DomainError::ValidationError(
+                "Task template must belong to the same project".into(),
+            ));
+        }
+        Ok(template)
+    }
+
+    /// Attach the template's tags and checklist to a newly created task
+    async fn apply_template(&self, task: &Task, template: &TaskTemplate) -> Result<(), DomainError> {
+        // Tags deleted since the template was saved are skipped
+        let mut tag_ids = Vec::with_capacity(template.tag_ids.len());
+        for tag_id in &template.tag_ids {
+            if self.tag_repository.find_by_id(*tag_id).await?.is_some() {
+                tag_ids.push(*tag_id);
+            }
+        }
+        if !tag_ids.is_empty() {
+            self.tag_repository.set_task_tags(task.id, tag_ids).await?;
+        }
+
+        for (position, content) in template.checklist.iter().enumerate() {
+            let item = ChecklistItem::new(task.id, content.clone(), position as i32);
+            self.checklist_repository.create(&item).await?;
+        }
+        Ok(())
+    }
+
+    /// Record the fields changed by an update; `None` actor for system changes
+    async fn record_update(&self, previous: &Task, task: &Task, actor: Option<Uuid>) -> Result<(), DomainError> {
+        match ChangeRecord::updated(previous, task, actor) {
+            Some(record) => self.history_repository.record(&[record]).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Roll estimated and actual hours up from subtasks to every ancestor
+    async fn rollup_hours(&self, mut parent_id: Option<Uuid>) -> Result<(), DomainError> {
+        while let Some(id) = parent_id {
+            let Some(mut parent) = self.task_repository.find_by_id(id).await? else {
+                break;
+            };
+            let children = self.task_repository.find_children(id).await?;
+            if !children.is_empty() {
+                let previous = parent.clone();
+                TaskTree::rollup_into(&mut parent, &children);
+                if parent.estimated_hours != previous.estimated_hours
+                    || parent.actual_hours != previous.actual_hours
+                {
+                    self.task_repository.update(&parent).await?;
+                    self.record_update(&previous, &parent, None).await?;
+                }
+            }
+            parent_id = parent.parent_id;
+        }
+        Ok(())
+    }
+
+    /// Re-evaluate the blocked state of every task blocked by `task_id`
+    async fn refresh_dependents(&self, task_id: Uuid) -> Result<(), DomainError> {
+        for dependency in self.dependency_repository.find_blocked(task_id).await? {
+            self.refresh_blocked_state(dependency.blocked_task_id).await?;
+        }
+        Ok(())
+    }
+
+    /// Block the task while any blocker is unfinished and unblock it once all are done.
+    /// Workflows without a Blocked status leave the task status untouched.
+    async fn refresh_blocked_state(&self, task_id: Uuid) -> Result<(), DomainError> {
+        // Trashed tasks are refreshed when they are restored
+        let Some(mut task) = self.task_repository.find_by_id(task_id).await? else {
+            return Ok(());
+        };
+        if task.is_done() {
+            return Ok(());
+        }
+
+        let unfinished = self
+            .dependency_repository
+            .count_unfinished_blockers(task_id)
+            .await?;
+        let workflow = self.get_workflow(task.project_id).await?;
+        let target = if unfinished > 0 && !task.is_blocked() {
+            workflow.status_for_category(&TaskStatus::Blocked)
+        } else if unfinished == 0 && task.is_blocked() {
+            // Return to the status the task was blocked from, if it is still an open one
+            let history = self
+                .history_repository
+                .find_by_entity(ChangeEntityType::Task, task.id)
+                .await?;
+            ChangeRecord::workflow_status_before(&history, &task.workflow_status)
+                .and_then(|key| workflow.find_status(&key))
+                .filter(|status| !status.is_done() && status.category != TaskStatus::Blocked)
+                .or_else(|| workflow.initial_status())
+        } else {
+            None
+        };
+
+        if let Some(target) = target {
+            let previous = task.clone();
+            self.move_to_column(&mut task, target).await?;
+            self.task_repository.update(&task).await?;
+            self.record_update(&previous, &task, None).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the project's custom workflow, falling back to the built-in one
+    pub async fn get_workflow(&self, project_id: Uuid) -> Result<ProjectWorkflow, DomainError> {
+        Ok(self
+            .workflow_repository
+            .find_by_project(project_id)
+            .await?
+            .unwrap_or_else(|| ProjectWorkflow::default_for(project_id)))
     }
 
     pub async fn get_tasks_by_project(&self, project_id: Uuid) -> Result<Vec<Task>, DomainError> {
@@ -113,3 +1325,122 @@ impl TaskAppService {
         self.task_repository.find_by_assignee(user_id).await
     }
 }
+
+impl BulkTaskResult {
+    fn succeeded(task_id: Uuid, outcome: BulkTaskOutcome) -> Self {
+        Self { task_id, outcome, error: None }
+    }
+
+    fn failed(task_id: Uuid, outcome: BulkTaskOutcome, error: &str) -> Self {
+        Self { task_id, outcome, error: Some(error.to_string()) }
+    }
+}
+
+fn validate_story_points(points: i32) -> Result<i32, DomainError> {
+    if points < 0 {
+        return Err(DomainError::ValidationError("Story points cannot be negative".into()));
+    }
+    Ok(points)
+}
+
+fn build_task_filter(query: TaskQuery, accessible_by: Option<Uuid>) -> Result<TaskFilter, DomainError> {
+    let unassigned = query.unassigned.unwrap_or(false);
+    if unassigned && query.assignee_id.is_some() {
+        return Err(DomainError::ValidationError(
+            "Cannot filter by assignee and unassigned together".into(),
+        ));
+    }
+    if let (Some(from), Some(to)) = (query.due_from, query.due_to) {
+        if from > to {
+            return Err(DomainError::ValidationError("due_from must not be after due_to".into()));
+        }
+    }
+
+    let priorities = split_list(query.priority.as_deref())
+        .map(|name| {
+            Priority::ALL
+                .into_iter()
+                .find(|p| p.as_str() == name)
+                .ok_or_else(|| DomainError::ValidationError(format!("Unknown priority '{}'", name)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let tag_ids = split_list(query.tags.as_deref())
+        .map(|id| {
+            Uuid::parse_str(id).map_err(|_| DomainError::ValidationError(format!("Invalid tag id '{}'", id)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let pagination = query.pagination();
+
+    Ok(TaskFilter {
+        accessible_by,
+        project_id: query.project_id,
+        assignee_id: query.assignee_id,
+        unassigned,
+        status: query.status,
+        priorities,
+        due_from: query.due_from,
+        due_to: query.due_to,
+        overdue: query.overdue.unwrap_or(false),
+        tag_ids,
+        milestone_id: query.milestone_id,
+        sprint_id: query.sprint_id,
+        sla_breached: query.sla_breached.unwrap_or(false),
+        text: query.q.map(|q| q.trim().to_string()).filter(|q| !q.is_empty()),
+        custom_fields: HashMap::new(),
+        sort: TaskSort::parse_list(query.sort.as_deref())?,
+        limit: pagination.per_page() as i64,
+        offset: pagination.offset() as i64,
+    })
+}
+
+/// Non-empty entries of a comma separated query value
+fn split_list(value: Option<&str>) -> impl Iterator<Item = &str> {
+    value.unwrap_or_default().split(',').map(str::trim).filter(|s| !s.is_empty())
+}
+
+fn validate_bulk_command(cmd: &BulkTaskCommand) -> Result<(), DomainError> {
+    if cmd.task_ids.is_empty() {
+        return Err(DomainError::ValidationError("No tasks given".into()));
+    }
+    if cmd.task_ids.len() > MAX_BULK_TASKS {
+        return Err(DomainError::ValidationError(format!(
+            "At most {} tasks can be changed at once",
+            MAX_BULK_TASKS
+        )));
+    }
+
+    let has_changes = cmd.status.is_some()
+        || cmd.workflow_status.is_some()
+        || cmd.priority.is_some()
+        || cmd.assignee_id.is_some()
+        || cmd.unassign
+        || cmd.milestone_id.is_some()
+        || cmd.clear_milestone
+        || cmd.due_date_shift_days.is_some()
+        || !cmd.add_tag_ids.is_empty()
+        || !cmd.remove_tag_ids.is_empty();
+    if cmd.delete && has_changes {
+        return Err(DomainError::ValidationError(
+            "Delete cannot be combined with other changes".into(),
+        ));
+    }
+    if !cmd.delete && !has_changes {
+        return Err(DomainError::ValidationError("No changes given".into()));
+    }
+    if cmd.assignee_id.is_some() && cmd.unassign {
+        return Err(DomainError::ValidationError(
+            "Cannot both set and clear the assignee".into(),
+        ));
+    }
+    if cmd.milestone_id.is_some() && cmd.clear_milestone {
+        return Err(DomainError::ValidationError(
+            "Cannot both set and clear the milestone".into(),
+        ));
+    }
+    if cmd.add_tag_ids.iter().any(|id| cmd.remove_tag_ids.contains(id)) {
+        return Err(DomainError::ValidationError(
+            "A tag cannot be both added and removed".into(),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,166 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateTaskCommentCommand, UpdateTaskCommentCommand};
+use crate::application::services::mention_app_service::notification_preview;
+use crate::application::services::{MentionAppService, NotificationAppService, WithMentions};
+use crate::domain::entities::{NotificationType, TaskComment, TaskCommentEdit};
+use crate::domain::repositories::{TaskCommentRepository, TaskRepository};
+use crate::domain::services::CommentThread;
+use crate::domain::value_objects::ProjectRole;
+use crate::shared::DomainError;
+
+pub struct TaskCommentAppService {
+    comment_repository: Arc<dyn TaskCommentRepository>,
+    task_repository: Arc<dyn TaskRepository>,
+    notification_service: Arc<NotificationAppService>,
+    mention_service: Arc<MentionAppService>,
+}
+
+impl TaskCommentAppService {
+    pub fn new(
+        comment_repository: Arc<dyn TaskCommentRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+        notification_service: Arc<NotificationAppService>,
+        mention_service: Arc<MentionAppService>,
+    ) -> Self {
+        Self {
+            comment_repository,
+            task_repository,
+            notification_service,
+            mention_service,
+        }
+    }
+
+    /// Check if user can access task (via project access)
+    pub async fn can_user_access(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.task_repository.can_user_access(task_id, user_id).await
+    }
+
+    /// Check if user owns the project containing the task or is a member with at least `role`
+    pub async fn has_role(&self, task_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.task_repository.has_role(task_id, user_id, role).await
+    }
+
+    /// Get the task's comments with replies nested under the comment they answer
+    pub async fn list_comments(&self, task_id: Uuid) -> Result<Vec<CommentThread>, DomainError> {
+        self.ensure_task_exists(task_id).await?;
+        let comments = self.comment_repository.find_by_task(task_id).await?;
+        Ok(CommentThread::build(comments))
+    }
+
+    pub async fn get_comment(&self, task_id: Uuid, comment_id: Uuid) -> Result<TaskComment, DomainError> {
+        self.comment_repository
+            .find_by_id(comment_id)
+            .await?
+            .filter(|c| c.task_id == task_id)
+            .ok_or_else(|| DomainError::NotFound("Comment not found".into()))
+    }
+
+    /// Get the previous versions of a comment, newest first
+    pub async fn get_comment_history(
+        &self,
+        task_id: Uuid,
+        comment_id: Uuid,
+    ) -> Result<Vec<TaskCommentEdit>, DomainError> {
+        self.get_comment(task_id, comment_id).await?;
+        self.comment_repository.find_edits(comment_id).await
+    }
+
+    pub async fn create_comment(
+        &self,
+        task_id: Uuid,
+        user_id: Uuid,
+        cmd: CreateTaskCommentCommand,
+    ) -> Result<WithMentions<TaskComment>, DomainError> {
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Task not found".into()))?;
+        let content = validate_content(cmd.content)?;
+        if let Some(parent_id) = cmd.parent_id {
+            self.get_comment(task_id, parent_id).await.map_err(|_| {
+                DomainError::ValidationError("Parent comment must belong to the same task".into())
+            })?;
+        }
+
+        let comment = TaskComment::new(task_id, user_id, cmd.parent_id, content);
+        let comment = self.comment_repository.create(&comment).await?;
+        self.notification_service.watch_task(task_id, user_id).await?;
+
+        // Let the assignee know, unless they wrote the comment themselves
+        if let Some(assignee_id) = task.assignee_id.filter(|id| *id != user_id) {
+            self.notification_service
+                .create_notification(
+                    assignee_id,
+                    NotificationType::CommentAdded,
+                    format!("New comment on \"{}\"", task.title),
+                    notification_preview(&comment.content),
+                    Some(format!("/tasks/{}", task.id)),
+                )
+                .await?;
+        }
+        let mentions = self
+            .mention_service
+            .notify_mentions(&task, Some(user_id), Some(&comment.content), None)
+            .await?;
+
+        Ok(WithMentions { item: comment, mentions })
+    }
+
+    /// Edit a comment; only its author may do so and the previous content is kept
+    pub async fn update_comment(
+        &self,
+        task_id: Uuid,
+        comment_id: Uuid,
+        user_id: Uuid,
+        cmd: UpdateTaskCommentCommand,
+    ) -> Result<WithMentions<TaskComment>, DomainError> {
+        let mut comment = self.get_comment(task_id, comment_id).await?;
+        if comment.user_id != user_id {
+            return Err(DomainError::Forbidden("Only the author can edit this comment".into()));
+        }
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Task not found".into()))?;
+
+        let content = validate_content(cmd.content)?;
+        let previous_content = comment.content.clone();
+        if content != previous_content {
+            let edit = comment.edit(content);
+            comment = self.comment_repository.update(&comment, &edit).await?;
+        }
+
+        // Only users newly mentioned by the edit are notified
+        let mentions = self
+            .mention_service
+            .notify_mentions(&task, Some(user_id), Some(&comment.content), Some(&previous_content))
+            .await?;
+        Ok(WithMentions { item: comment, mentions })
+    }
+
+    /// Delete a comment and its replies
+    pub async fn delete_comment(&self, task_id: Uuid, comment_id: Uuid) -> Result<(), DomainError> {
+        self.get_comment(task_id, comment_id).await?;
+        self.comment_repository.delete(comment_id).await
+    }
+
+    async fn ensure_task_exists(&self, task_id: Uuid) -> Result<(), DomainError> {
+        self.task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Task not found".into()))?;
+        Ok(())
+    }
+}
+
+fn validate_content(content: String) -> Result<String, DomainError> {
+    let content = content.trim().to_string();
+    if content.is_empty() {
+        return Err(DomainError::ValidationError("Comment cannot be empty".into()));
+    }
+    Ok(content)
+}
@@ -0,0 +1,138 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateTaskTemplateCommand, UpdateTaskTemplateCommand};
+use crate::domain::entities::TaskTemplate;
+use crate::domain::repositories::{ProjectRepository, TagRepository, TaskTemplateRepository};
+use crate::domain::value_objects::ProjectRole;
+use crate::shared::DomainError;
+
+pub struct TaskTemplateAppService {
+    template_repository: Arc<dyn TaskTemplateRepository>,
+    project_repository: Arc<dyn ProjectRepository>,
+    tag_repository: Arc<dyn TagRepository>,
+}
+
+impl TaskTemplateAppService {
+    pub fn new(
+        template_repository: Arc<dyn TaskTemplateRepository>,
+        project_repository: Arc<dyn ProjectRepository>,
+        tag_repository: Arc<dyn TagRepository>,
+    ) -> Self {
+        Self {
+            template_repository,
+            project_repository,
+            tag_repository,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_access_project(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.project_repository.can_user_access(project_id, user_id).await
+    }
+
+    /// Check if user is owner of project or a member with at least `role`
+    pub async fn has_project_role(&self, project_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.project_repository.has_role(project_id, user_id, role).await
+    }
+
+    /// Check if user can access the project of the template
+    pub async fn can_user_access(&self, id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        let template = self.get_template(id).await?;
+        self.project_repository.can_user_access(template.project_id, user_id).await
+    }
+
+    /// Check if user is owner of the project of the template or a member with at least `role`
+    pub async fn has_role(&self, id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        let template = self.get_template(id).await?;
+        self.project_repository.has_role(template.project_id, user_id, role).await
+    }
+
+    pub async fn list_templates(&self, project_id: Uuid) -> Result<Vec<TaskTemplate>, DomainError> {
+        self.template_repository.find_by_project(project_id).await
+    }
+
+    pub async fn get_template(&self, id: Uuid) -> Result<TaskTemplate, DomainError> {
+        self.template_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Task template not found".into()))
+    }
+
+    pub async fn create_template(
+        &self,
+        project_id: Uuid,
+        user_id: Uuid,
+        cmd: CreateTaskTemplateCommand,
+    ) -> Result<TaskTemplate, DomainError> {
+        self.project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
+        self.validate_tags(&cmd.tag_ids).await?;
+
+        let template = TaskTemplate::new(
+            project_id,
+            cmd.name,
+            cmd.title_pattern,
+            cmd.description,
+            cmd.priority,
+            cmd.estimated_hours,
+            cmd.tag_ids,
+            cmd.checklist,
+            user_id,
+        );
+        template.validate()?;
+
+        self.template_repository.create(&template).await
+    }
+
+    pub async fn update_template(
+        &self,
+        id: Uuid,
+        cmd: UpdateTaskTemplateCommand,
+    ) -> Result<TaskTemplate, DomainError> {
+        let mut template = self.get_template(id).await?;
+
+        if let Some(name) = cmd.name {
+            template.name = name;
+        }
+        if let Some(title_pattern) = cmd.title_pattern {
+            template.title_pattern = title_pattern;
+        }
+        if let Some(description) = cmd.description {
+            template.description = Some(description);
+        }
+        if let Some(priority) = cmd.priority {
+            template.priority = priority;
+        }
+        if let Some(estimated_hours) = cmd.estimated_hours {
+            template.estimated_hours = Some(estimated_hours);
+        }
+        if let Some(tag_ids) = cmd.tag_ids {
+            self.validate_tags(&tag_ids).await?;
+            template.tag_ids = tag_ids;
+        }
+        if let Some(checklist) = cmd.checklist {
+            template.checklist = checklist;
+        }
+        template.validate()?;
+
+        self.template_repository.update(&template).await
+    }
+
+    pub async fn delete_template(&self, id: Uuid) -> Result<(), DomainError> {
+        self.get_template(id).await?;
+        self.template_repository.delete(id).await
+    }
+
+    async fn validate_tags(&self, tag_ids: &[Uuid]) -> Result<(), DomainError> {
+        for tag_id in tag_ids {
+            self.tag_repository
+                .find_by_id(*tag_id)
+                .await?
+                .ok_or_else(|| DomainError::NotFound(format!("Tag {} not found", tag_id)))?;
+        }
+        Ok(())
+    }
+}
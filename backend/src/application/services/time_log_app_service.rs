@@ -3,7 +3,8 @@ use chrono::{NaiveDate, Utc};
 use uuid::Uuid;
 
 use crate::domain::entities::TimeLog;
-use crate::domain::repositories::TimeLogRepository;
+use crate::domain::repositories::{TaskRepository, TimeLogRepository};
+use crate::domain::value_objects::ProjectRole;
 use crate::shared::DomainError;
 
 #[derive(Debug)]
@@ -24,11 +25,32 @@ pub struct UpdateTimeLogDto {
 
 pub struct TimeLogAppService {
     time_log_repository: Arc<dyn TimeLogRepository>,
+    task_repository: Arc<dyn TaskRepository>,
 }
 
 impl TimeLogAppService {
-    pub fn new(time_log_repository: Arc<dyn TimeLogRepository>) -> Self {
-        Self { time_log_repository }
+    pub fn new(
+        time_log_repository: Arc<dyn TimeLogRepository>,
+        task_repository: Arc<dyn TaskRepository>,
+    ) -> Self {
+        Self {
+            time_log_repository,
+            task_repository,
+        }
+    }
+
+    /// Check if user owns the project containing the task or is a member with at least `role`
+    pub async fn has_role(&self, task_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.task_repository.has_role(task_id, user_id, role).await
+    }
+
+    /// Check the user's role on the project of the task the time log was booked on
+    pub async fn has_time_log_role(&self, id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        let time_log = self.time_log_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("Time log with id {} not found", id)))?;
+        self.task_repository.has_role(time_log.task_id, user_id, role).await
     }
 
     pub async fn get_time_log(&self, id: Uuid) -> Result<Option<TimeLog>, DomainError> {
@@ -0,0 +1,45 @@
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+use crate::application::services::AttachmentAppService;
+use crate::domain::repositories::{ProjectRepository, TaskRepository};
+use crate::shared::DomainError;
+
+pub struct TrashAppService {
+    task_repository: Arc<dyn TaskRepository>,
+    project_repository: Arc<dyn ProjectRepository>,
+    attachment_service: Arc<AttachmentAppService>,
+    retention: Duration,
+}
+
+impl TrashAppService {
+    pub fn new(
+        task_repository: Arc<dyn TaskRepository>,
+        project_repository: Arc<dyn ProjectRepository>,
+        attachment_service: Arc<AttachmentAppService>,
+        retention_days: i64,
+    ) -> Self {
+        Self {
+            task_repository,
+            project_repository,
+            attachment_service,
+            retention: Duration::days(retention_days),
+        }
+    }
+
+    /// Permanently delete tasks and projects that stayed in the trash longer than
+    /// the retention period, along with the attachment files of the tasks.
+    /// Returns how many tasks and projects were removed.
+    pub async fn purge_expired(&self, now: DateTime<Utc>) -> Result<usize, DomainError> {
+        let cutoff = now - self.retention;
+
+        // Tasks of a trashed project share its timestamp, so they go first and leave their files behind
+        let task_ids = self.task_repository.purge(cutoff).await?;
+        for task_id in &task_ids {
+            self.attachment_service.remove_task_files(*task_id).await?;
+        }
+        let projects = self.project_repository.purge(cutoff).await?;
+
+        Ok(task_ids.len() + projects as usize)
+    }
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::SetWorkflowCommand;
+use crate::domain::entities::ProjectWorkflow;
+use crate::domain::repositories::{ProjectRepository, WorkflowRepository};
+use crate::domain::value_objects::ProjectRole;
+use crate::shared::DomainError;
+
+pub struct WorkflowAppService {
+    workflow_repository: Arc<dyn WorkflowRepository>,
+    project_repository: Arc<dyn ProjectRepository>,
+}
+
+impl WorkflowAppService {
+    pub fn new(
+        workflow_repository: Arc<dyn WorkflowRepository>,
+        project_repository: Arc<dyn ProjectRepository>,
+    ) -> Self {
+        Self {
+            workflow_repository,
+            project_repository,
+        }
+    }
+
+    /// Check if user can access project
+    pub async fn can_user_access(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+        self.project_repository.can_user_access(project_id, user_id).await
+    }
+
+    /// Check if user is owner of project or a member with at least `role`
+    pub async fn has_role(&self, project_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        self.project_repository.has_role(project_id, user_id, role).await
+    }
+
+    /// Get the project's workflow, falling back to the built-in one
+    pub async fn get_workflow(&self, project_id: Uuid) -> Result<ProjectWorkflow, DomainError> {
+        self.ensure_project_exists(project_id).await?;
+
+        Ok(self
+            .workflow_repository
+            .find_by_project(project_id)
+            .await?
+            .unwrap_or_else(|| ProjectWorkflow::default_for(project_id)))
+    }
+
+    pub async fn set_workflow(
+        &self,
+        project_id: Uuid,
+        cmd: SetWorkflowCommand,
+    ) -> Result<ProjectWorkflow, DomainError> {
+        self.ensure_project_exists(project_id).await?;
+
+        let mut workflow = ProjectWorkflow::new(project_id, cmd.statuses, cmd.transitions);
+        workflow.require_subtasks_done = cmd.require_subtasks_done;
+        workflow.validate()?;
+        self.ensure_statuses_in_use_kept(&workflow).await?;
+
+        self.workflow_repository.save(&workflow).await
+    }
+
+    /// Drop the custom workflow so the project uses the built-in one again
+    pub async fn reset_workflow(&self, project_id: Uuid) -> Result<ProjectWorkflow, DomainError> {
+        self.ensure_project_exists(project_id).await?;
+
+        let workflow = ProjectWorkflow::default_for(project_id);
+        self.ensure_statuses_in_use_kept(&workflow).await?;
+
+        self.workflow_repository.delete_by_project(project_id).await?;
+        Ok(workflow)
+    }
+
+    async fn ensure_project_exists(&self, project_id: Uuid) -> Result<(), DomainError> {
+        self.project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Project not found".into()))?;
+        Ok(())
+    }
+
+    /// Tasks must never be left in a status the workflow no longer defines
+    async fn ensure_statuses_in_use_kept(&self, workflow: &ProjectWorkflow) -> Result<(), DomainError> {
+        let missing: Vec<String> = self
+            .workflow_repository
+            .find_status_keys_in_use(workflow.project_id)
+            .await?
+            .into_iter()
+            .filter(|key| workflow.find_status(key).is_none())
+            .collect();
+
+        if !missing.is_empty() {
+            return Err(DomainError::ValidationError(format!(
+                "Workflow is missing statuses still used by tasks: {}",
+                missing.join(", ")
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::shared::DomainError;
+
+/// Hourly cost of a user from a date on, until the user's next rate takes effect
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostRate {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub hourly_rate: Decimal,
+    pub effective_from: NaiveDate,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CostRate {
+    pub fn new(user_id: Uuid, hourly_rate: Decimal, effective_from: NaiveDate) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            hourly_rate,
+            effective_from,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.hourly_rate < Decimal::ZERO {
+            return Err(DomainError::ValidationError("Hourly rate cannot be negative".into()));
+        }
+        Ok(())
+    }
+
+    /// Rate of the user in effect on `date`; `None` before the user's first rate
+    pub fn effective_on(rates: &[CostRate], user_id: Uuid, date: NaiveDate) -> Option<&CostRate> {
+        rates
+            .iter()
+            .filter(|rate| rate.user_id == user_id && rate.effective_from <= date)
+            .max_by_key(|rate| rate.effective_from)
+    }
+}
+
+/// Non-labour cost booked against a project's budget
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Expense {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub category: String,
+    pub description: Option<String>,
+    pub amount: Decimal,
+    pub incurred_on: NaiveDate,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Expense {
+    pub fn new(
+        project_id: Uuid,
+        category: String,
+        description: Option<String>,
+        amount: Decimal,
+        incurred_on: NaiveDate,
+        created_by: Uuid,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            category,
+            description,
+            amount,
+            incurred_on,
+            created_by: Some(created_by),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.category.trim().is_empty() {
+            return Err(DomainError::ValidationError("Expense category cannot be empty".into()));
+        }
+        if self.amount <= Decimal::ZERO {
+            return Err(DomainError::ValidationError("Expense amount must be positive".into()));
+        }
+        Ok(())
+    }
+}
+
+/// Hours a user logged on the project's tasks on one day, with the teams of the user
+#[derive(Debug, Clone)]
+pub struct LabourEntry {
+    pub user_id: Uuid,
+    pub user_name: String,
+    /// Id and name of every team the user belongs to
+    pub teams: Vec<(Uuid, String)>,
+    pub date: NaiveDate,
+    pub hours: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_effective_rate() {
+        let user = Uuid::new_v4();
+        let rates = vec![
+            CostRate::new(user, Decimal::from(60), date(3, 1)),
+            CostRate::new(user, Decimal::from(50), date(1, 1)),
+            CostRate::new(Uuid::new_v4(), Decimal::from(90), date(1, 1)),
+        ];
+
+        assert!(CostRate::effective_on(&rates, user, date(1, 1).pred_opt().unwrap()).is_none());
+        assert_eq!(CostRate::effective_on(&rates, user, date(2, 29)).unwrap().hourly_rate, Decimal::from(50));
+        assert_eq!(CostRate::effective_on(&rates, user, date(3, 1)).unwrap().hourly_rate, Decimal::from(60));
+    }
+
+    #[test]
+    fn test_validate_expense() {
+        let expense = |category: &str, amount: i64| {
+            Expense::new(Uuid::nil(), category.to_string(), None, Decimal::from(amount), date(3, 1), Uuid::nil())
+        };
+        assert!(expense("Travel", 120).validate().is_ok());
+        assert!(expense("Travel", 0).validate().is_err());
+        assert!(expense(" ", 120).validate().is_err());
+    }
+}
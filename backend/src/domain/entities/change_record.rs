@@ -0,0 +1,278 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use crate::domain::entities::{Project, Task};
+
+/// Fields left out of diffs: identity, timestamps and board ordering
+const IGNORED_FIELDS: [&str; 4] = ["id", "created_at", "updated_at", "rank"];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "change_entity_type", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeEntityType {
+    Task,
+    Project,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "change_action", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeAction {
+    Created,
+    Updated,
+    /// Moved to the trash
+    Deleted,
+    /// Taken back out of the trash
+    Restored,
+}
+
+/// Entity whose changes are recorded field by field
+pub trait Tracked: Serialize {
+    const ENTITY_TYPE: ChangeEntityType;
+
+    fn entity_id(&self) -> Uuid;
+    fn project_id(&self) -> Uuid;
+}
+
+impl Tracked for Task {
+    const ENTITY_TYPE: ChangeEntityType = ChangeEntityType::Task;
+
+    fn entity_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn project_id(&self) -> Uuid {
+        self.project_id
+    }
+}
+
+impl Tracked for Project {
+    const ENTITY_TYPE: ChangeEntityType = ChangeEntityType::Project;
+
+    fn entity_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn project_id(&self) -> Uuid {
+        self.id
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub old_value: Value,
+    pub new_value: Value,
+}
+
+/// One mutation of a task or project with its per-field before/after values.
+/// Records do not reference the entity, so they outlive it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeRecord {
+    pub id: Uuid,
+    pub entity_type: ChangeEntityType,
+    pub entity_id: Uuid,
+    /// Project the entity belonged to at the time of the change
+    pub project_id: Uuid,
+    pub action: ChangeAction,
+    pub changes: Vec<FieldChange>,
+    /// `None` for changes made by the system, e.g. rolled up hours
+    pub changed_by: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changed_by_name: Option<String>,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl ChangeRecord {
+    fn new<T: Tracked>(entity: &T, action: ChangeAction, changes: Vec<FieldChange>, actor: Option<Uuid>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            entity_type: T::ENTITY_TYPE,
+            entity_id: entity.entity_id(),
+            project_id: entity.project_id(),
+            action,
+            changes,
+            changed_by: actor,
+            changed_by_name: None,
+            changed_at: Utc::now(),
+        }
+    }
+
+    /// Initial values of every field that is set
+    pub fn created<T: Tracked>(entity: &T, actor: Option<Uuid>) -> Self {
+        let changes = diff(&Value::Null, &to_fields(entity));
+        Self::new(entity, ChangeAction::Created, changes, actor)
+    }
+
+    /// Fields that differ between the two versions; `None` when nothing changed
+    pub fn updated<T: Tracked>(previous: &T, current: &T, actor: Option<Uuid>) -> Option<Self> {
+        let changes = diff(&to_fields(previous), &to_fields(current));
+        (!changes.is_empty()).then(|| Self::new(current, ChangeAction::Updated, changes, actor))
+    }
+
+    /// Last values of every field that was set
+    pub fn deleted<T: Tracked>(entity: &T, actor: Option<Uuid>) -> Self {
+        let changes = diff(&to_fields(entity), &Value::Null);
+        Self::new(entity, ChangeAction::Deleted, changes, actor)
+    }
+
+    /// Values of every field that is set again after leaving the trash
+    pub fn restored<T: Tracked>(entity: &T, actor: Option<Uuid>) -> Self {
+        let changes = diff(&Value::Null, &to_fields(entity));
+        Self::new(entity, ChangeAction::Restored, changes, actor)
+    }
+
+    /// Workflow status a task was in before it last moved to `status_key`, from its
+    /// records newest first
+    pub fn workflow_status_before(records: &[ChangeRecord], status_key: &str) -> Option<String> {
+        records
+            .iter()
+            .flat_map(|record| &record.changes)
+            .find(|change| change.field == "workflow_status" && change.new_value == status_key)
+            .and_then(|change| change.old_value.as_str().map(str::to_string))
+    }
+}
+
+fn to_fields<T: Serialize>(entity: &T) -> Value {
+    serde_json::to_value(entity).unwrap_or(Value::Null)
+}
+
+/// Compare the top-level fields of two serialized entities, in field name order
+fn diff(before: &Value, after: &Value) -> Vec<FieldChange> {
+    let empty = Map::new();
+    let before = before.as_object().unwrap_or(&empty);
+    let after = after.as_object().unwrap_or(&empty);
+
+    let mut fields: Vec<&String> = before.keys().chain(after.keys()).collect();
+    fields.sort();
+    fields.dedup();
+
+    fields
+        .into_iter()
+        .filter(|field| !IGNORED_FIELDS.contains(&field.as_str()))
+        .filter_map(|field| {
+            let old_value = field_value(before, field);
+            let new_value = field_value(after, field);
+            (old_value != new_value).then(|| FieldChange {
+                field: field.clone(),
+                old_value,
+                new_value,
+            })
+        })
+        .collect()
+}
+
+/// Value of a field, with empty collections treated as unset
+fn field_value(fields: &Map<String, Value>, field: &str) -> Value {
+    match fields.get(field) {
+        Some(Value::Object(map)) if map.is_empty() => Value::Null,
+        Some(Value::Array(items)) if items.is_empty() => Value::Null,
+        Some(value) => value.clone(),
+        None => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::RecurringTask;
+    use crate::domain::value_objects::Priority;
+    use serde_json::json;
+
+    fn task() -> Task {
+        Task::new(Uuid::nil(), "Write docs".to_string(), None, None, None, None, Some(3.0))
+    }
+
+    #[test]
+    fn test_updated_records_changed_fields_only() {
+        let previous = task();
+        let mut current = previous.clone();
+        current.priority = Priority::High;
+        current.rank = 4096.0;
+        current.updated_at = Utc::now();
+        let actor = Uuid::new_v4();
+
+        let record = ChangeRecord::updated(&previous, &current, Some(actor)).unwrap();
+        assert_eq!(record.action, ChangeAction::Updated);
+        assert_eq!(record.changed_by, Some(actor));
+        assert_eq!(
+            record.changes,
+            vec![FieldChange {
+                field: "priority".to_string(),
+                old_value: json!("Medium"),
+                new_value: json!("High"),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_updated_without_changes_is_none() {
+        let previous = task();
+        let mut current = previous.clone();
+        current.rank = 1.0;
+        assert!(ChangeRecord::updated(&previous, &current, None).is_none());
+    }
+
+    #[test]
+    fn test_created_and_deleted_skip_unset_fields() {
+        let task = task();
+        let created = ChangeRecord::created(&task, None);
+        assert!(created.changes.iter().all(|c| c.old_value.is_null() && !c.new_value.is_null()));
+        assert!(created.changes.iter().any(|c| c.field == "estimated_hours"));
+        assert!(!created
+            .changes
+            .iter()
+            .any(|c| c.field == "due_date" || c.field == "custom_fields" || c.field == "id"));
+
+        let deleted = ChangeRecord::deleted(&task, None);
+        assert_eq!(deleted.changes.len(), created.changes.len());
+        assert!(deleted.changes.iter().all(|c| c.new_value.is_null()));
+
+        let restored = ChangeRecord::restored(&task, None);
+        assert_eq!(restored.action, ChangeAction::Restored);
+        assert_eq!(restored.changes, created.changes);
+    }
+
+    #[test]
+    fn test_workflow_status_before_uses_latest_move() {
+        let mut task = task();
+        let mut records = Vec::new();
+        for key in ["in_progress", "blocked", "review", "blocked"] {
+            let previous = task.clone();
+            task.workflow_status = key.to_string();
+            records.push(ChangeRecord::updated(&previous, &task, None).unwrap());
+        }
+        records.reverse();
+
+        assert_eq!(
+            ChangeRecord::workflow_status_before(&records, "blocked").as_deref(),
+            Some("review")
+        );
+        assert_eq!(ChangeRecord::workflow_status_before(&records, "done"), None);
+    }
+
+    #[test]
+    fn test_created_by_deleted_series_creator_has_no_actor() {
+        // A recurring series loses its creator when the user is deleted; the tasks it
+        // still generates must be recorded without an author rather than a nil user
+        let mut series = RecurringTask::new(
+            Uuid::new_v4(),
+            "Weekly report".to_string(),
+            None,
+            None,
+            None,
+            None,
+            "FREQ=WEEKLY".to_string(),
+            Utc::now(),
+            0,
+            Uuid::new_v4(),
+        );
+        series.created_by = None;
+
+        let record = ChangeRecord::created(&task(), series.created_by);
+        assert_eq!(record.changed_by, None);
+        assert_eq!(record.action, ChangeAction::Created);
+    }
+}
@@ -0,0 +1,135 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::shared::DomainError;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub content: String,
+    pub is_checked: bool,
+    /// Zero-based order within the task's checklist
+    pub position: i32,
+    pub checked_by: Option<Uuid>,
+    pub checked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ChecklistItem {
+    pub fn new(task_id: Uuid, content: String, position: i32) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            task_id,
+            content,
+            is_checked: false,
+            position,
+            checked_by: None,
+            checked_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn set_checked(&mut self, checked: bool, user_id: Uuid) {
+        if self.is_checked == checked {
+            return;
+        }
+        self.is_checked = checked;
+        self.checked_by = checked.then_some(user_id);
+        self.checked_at = checked.then(Utc::now);
+        self.updated_at = Utc::now();
+    }
+}
+
+/// A task's checklist items in order, with completion
+#[derive(Debug, Clone, Serialize)]
+pub struct Checklist {
+    pub items: Vec<ChecklistItem>,
+    pub total: usize,
+    pub checked: usize,
+    pub percent_complete: f32,
+}
+
+impl Checklist {
+    pub fn new(mut items: Vec<ChecklistItem>) -> Self {
+        items.sort_by_key(|item| item.position);
+        let total = items.len();
+        let checked = items.iter().filter(|item| item.is_checked).count();
+        let percent_complete = if total == 0 {
+            0.0
+        } else {
+            checked as f32 / total as f32 * 100.0
+        };
+
+        Self {
+            items,
+            total,
+            checked,
+            percent_complete,
+        }
+    }
+
+    /// A new order must list every item of the checklist exactly once
+    pub fn validate_order(items: &[ChecklistItem], item_ids: &[Uuid]) -> Result<(), DomainError> {
+        let current: HashSet<Uuid> = items.iter().map(|item| item.id).collect();
+        let requested: HashSet<Uuid> = item_ids.iter().copied().collect();
+
+        if requested.len() != item_ids.len() || requested != current {
+            return Err(DomainError::ValidationError(
+                "Order must list every checklist item exactly once".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(position: i32, checked: bool) -> ChecklistItem {
+        let mut item = ChecklistItem::new(Uuid::nil(), format!("Item {}", position), position);
+        item.set_checked(checked, Uuid::nil());
+        item
+    }
+
+    #[test]
+    fn test_checklist_orders_items_and_computes_percent() {
+        let checklist = Checklist::new(vec![item(2, false), item(0, true), item(1, true), item(3, false)]);
+        let positions: Vec<i32> = checklist.items.iter().map(|i| i.position).collect();
+        assert_eq!(positions, vec![0, 1, 2, 3]);
+        assert_eq!(checklist.checked, 2);
+        assert_eq!(checklist.percent_complete, 50.0);
+    }
+
+    #[test]
+    fn test_empty_checklist_is_zero_percent() {
+        assert_eq!(Checklist::new(Vec::new()).percent_complete, 0.0);
+    }
+
+    #[test]
+    fn test_set_checked_tracks_who_and_when() {
+        let user = Uuid::new_v4();
+        let mut item = item(0, false);
+        item.set_checked(true, user);
+        assert_eq!(item.checked_by, Some(user));
+        assert!(item.checked_at.is_some());
+        item.set_checked(false, user);
+        assert!(item.checked_by.is_none() && item.checked_at.is_none());
+    }
+
+    #[test]
+    fn test_validate_order() {
+        let items = vec![item(0, false), item(1, false)];
+        let (a, b) = (items[0].id, items[1].id);
+        assert!(Checklist::validate_order(&items, &[b, a]).is_ok());
+        assert!(Checklist::validate_order(&items, &[a]).is_err());
+        assert!(Checklist::validate_order(&items, &[a, a]).is_err());
+        assert!(Checklist::validate_order(&items, &[a, Uuid::new_v4()]).is_err());
+    }
+}
@@ -0,0 +1,277 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::shared::DomainError;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "custom_field_type", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum CustomFieldType {
+    Text,
+    Number,
+    /// Calendar date stored as `YYYY-MM-DD`
+    Date,
+    SingleSelect,
+    MultiSelect,
+    /// User id
+    User,
+}
+
+impl CustomFieldType {
+    pub fn has_options(&self) -> bool {
+        matches!(self, CustomFieldType::SingleSelect | CustomFieldType::MultiSelect)
+    }
+}
+
+/// Project-defined field whose values are stored on each task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomField {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub name: String,
+    pub field_type: CustomFieldType,
+    /// Allowed values of select fields
+    pub options: Vec<String>,
+    pub required: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CustomField {
+    pub fn new(
+        project_id: Uuid,
+        name: String,
+        field_type: CustomFieldType,
+        options: Vec<String>,
+        required: bool,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            name,
+            field_type,
+            options,
+            required,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.name.trim().is_empty() {
+            return Err(DomainError::ValidationError("Field name cannot be empty".into()));
+        }
+        if !self.field_type.has_options() {
+            if !self.options.is_empty() {
+                return Err(DomainError::ValidationError(
+                    "Only select fields can have options".into(),
+                ));
+            }
+            return Ok(());
+        }
+
+        if self.options.is_empty() {
+            return Err(DomainError::ValidationError("Select fields need at least one option".into()));
+        }
+        let mut seen = HashSet::new();
+        for option in &self.options {
+            if option.trim().is_empty() {
+                return Err(DomainError::ValidationError("Options cannot be empty".into()));
+            }
+            if !seen.insert(option.as_str()) {
+                return Err(DomainError::ValidationError(format!("Duplicate option '{}'", option)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check a value against the field definition and return it in stored form
+    pub fn normalize_value(&self, value: &Value) -> Result<Value, DomainError> {
+        let invalid = |expected: &str| {
+            DomainError::ValidationError(format!("Field '{}' expects {}", self.name, expected))
+        };
+
+        match self.field_type {
+            CustomFieldType::Text => match value {
+                Value::String(_) => Ok(value.clone()),
+                _ => Err(invalid("text")),
+            },
+            CustomFieldType::Number => match value {
+                Value::Number(_) => Ok(value.clone()),
+                _ => Err(invalid("a number")),
+            },
+            CustomFieldType::Date => value
+                .as_str()
+                .and_then(|s| self.parse_scalar(s).ok())
+                .ok_or_else(|| invalid("a date (YYYY-MM-DD)")),
+            CustomFieldType::SingleSelect => value
+                .as_str()
+                .and_then(|s| self.parse_scalar(s).ok())
+                .ok_or_else(|| invalid(&format!("one of: {}", self.options.join(", ")))),
+            CustomFieldType::MultiSelect => {
+                let items = value
+                    .as_array()
+                    .ok_or_else(|| invalid("a list of options"))?;
+                let mut selected: Vec<Value> = Vec::new();
+                for item in items {
+                    let option = item
+                        .as_str()
+                        .and_then(|s| self.parse_scalar(s).ok())
+                        .ok_or_else(|| invalid(&format!("options from: {}", self.options.join(", "))))?;
+                    if !selected.contains(&option) {
+                        selected.push(option);
+                    }
+                }
+                Ok(Value::Array(selected))
+            }
+            CustomFieldType::User => value
+                .as_str()
+                .and_then(|s| self.parse_scalar(s).ok())
+                .ok_or_else(|| invalid("a user id")),
+        }
+    }
+
+    /// Value a task must contain to match a `?cf.<field_id>=` filter;
+    /// multi-select fields match tasks having the option among their values
+    pub fn filter_value(&self, value: &str) -> Result<Value, DomainError> {
+        let scalar = self.parse_scalar(value.trim())?;
+        Ok(match self.field_type {
+            CustomFieldType::MultiSelect => Value::Array(vec![scalar]),
+            _ => scalar,
+        })
+    }
+
+    /// Parse a single textual value, e.g. one option of a multi-select field
+    fn parse_scalar(&self, value: &str) -> Result<Value, DomainError> {
+        let invalid = || {
+            DomainError::ValidationError(format!("Invalid value '{}' for field '{}'", value, self.name))
+        };
+
+        match self.field_type {
+            CustomFieldType::Text => Ok(Value::String(value.to_string())),
+            CustomFieldType::Number => value
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number)
+                .ok_or_else(invalid),
+            CustomFieldType::Date => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map(|date| Value::String(date.format("%Y-%m-%d").to_string()))
+                .map_err(|_| invalid()),
+            CustomFieldType::SingleSelect | CustomFieldType::MultiSelect => self
+                .options
+                .iter()
+                .find(|option| option.as_str() == value)
+                .map(|option| Value::String(option.clone()))
+                .ok_or_else(invalid),
+            CustomFieldType::User => Uuid::parse_str(value)
+                .map(|id| Value::String(id.to_string()))
+                .map_err(|_| invalid()),
+        }
+    }
+
+    /// Apply `changes` to a task's values; `null` clears a value
+    pub fn merge_values(
+        fields: &[CustomField],
+        current: &HashMap<Uuid, Value>,
+        changes: &HashMap<Uuid, Value>,
+    ) -> Result<HashMap<Uuid, Value>, DomainError> {
+        let mut values = current.clone();
+        for (field_id, value) in changes {
+            let field = fields
+                .iter()
+                .find(|f| f.id == *field_id)
+                .ok_or_else(|| DomainError::ValidationError(format!("Unknown custom field {}", field_id)))?;
+
+            if value.is_null() {
+                if field.required {
+                    return Err(DomainError::ValidationError(format!(
+                        "Field '{}' is required",
+                        field.name
+                    )));
+                }
+                values.remove(field_id);
+            } else {
+                values.insert(*field_id, field.normalize_value(value)?);
+            }
+        }
+        Ok(values)
+    }
+
+    /// Every required field must have a value
+    pub fn check_required(fields: &[CustomField], values: &HashMap<Uuid, Value>) -> Result<(), DomainError> {
+        match fields.iter().find(|f| f.required && !values.contains_key(&f.id)) {
+            Some(field) => Err(DomainError::ValidationError(format!(
+                "Field '{}' is required",
+                field.name
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn field(field_type: CustomFieldType, options: &[&str]) -> CustomField {
+        CustomField::new(
+            Uuid::nil(),
+            "Field".to_string(),
+            field_type,
+            options.iter().map(|o| o.to_string()).collect(),
+            false,
+        )
+    }
+
+    #[test]
+    fn test_validate_options() {
+        assert!(field(CustomFieldType::SingleSelect, &["a", "b"]).validate().is_ok());
+        assert!(field(CustomFieldType::SingleSelect, &[]).validate().is_err());
+        assert!(field(CustomFieldType::MultiSelect, &["a", "a"]).validate().is_err());
+        assert!(field(CustomFieldType::Text, &["a"]).validate().is_err());
+    }
+
+    #[test]
+    fn test_normalize_value_by_type() {
+        assert_eq!(field(CustomFieldType::Number, &[]).normalize_value(&json!(3.5)).unwrap(), json!(3.5));
+        assert!(field(CustomFieldType::Number, &[]).normalize_value(&json!("3")).is_err());
+        assert!(field(CustomFieldType::Date, &[]).normalize_value(&json!("2024-02-30")).is_err());
+        assert!(field(CustomFieldType::User, &[]).normalize_value(&json!("not-a-uuid")).is_err());
+
+        let select = field(CustomFieldType::MultiSelect, &["web", "api"]);
+        assert_eq!(
+            select.normalize_value(&json!(["api", "web", "api"])).unwrap(),
+            json!(["api", "web"])
+        );
+        assert!(select.normalize_value(&json!(["mobile"])).is_err());
+    }
+
+    #[test]
+    fn test_merge_values_sets_and_clears() {
+        let severity = field(CustomFieldType::SingleSelect, &["low", "high"]);
+        let mut customer = field(CustomFieldType::Text, &[]);
+        customer.required = true;
+        let fields = vec![severity.clone(), customer.clone()];
+        let current = HashMap::from([(severity.id, json!("low")), (customer.id, json!("Acme"))]);
+
+        let merged = CustomField::merge_values(&fields, &current, &HashMap::from([(severity.id, Value::Null)])).unwrap();
+        assert!(!merged.contains_key(&severity.id));
+
+        assert!(CustomField::merge_values(&fields, &current, &HashMap::from([(customer.id, Value::Null)])).is_err());
+        assert!(CustomField::merge_values(&fields, &current, &HashMap::from([(Uuid::new_v4(), json!("x"))])).is_err());
+        assert!(CustomField::check_required(&fields, &HashMap::new()).is_err());
+    }
+
+    #[test]
+    fn test_filter_value() {
+        assert_eq!(field(CustomFieldType::MultiSelect, &["web"]).filter_value("web").unwrap(), json!(["web"]));
+        assert_eq!(field(CustomFieldType::Number, &[]).filter_value("2").unwrap(), json!(2.0));
+        assert!(field(CustomFieldType::SingleSelect, &["web"]).filter_value("api").is_err());
+    }
+}
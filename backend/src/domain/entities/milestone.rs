@@ -2,6 +2,10 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::domain::entities::Task;
+use crate::shared::DomainError;
+
+/// Target point of a project; tasks are assigned to it by `Task::milestone_id`
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Milestone {
     pub id: Uuid,
@@ -34,6 +38,13 @@ impl Milestone {
         }
     }
 
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.name.trim().is_empty() {
+            return Err(DomainError::ValidationError("Milestone name cannot be empty".into()));
+        }
+        Ok(())
+    }
+
     pub fn mark_completed(&mut self) {
         self.completed = true;
         self.updated_at = Utc::now();
@@ -47,3 +58,58 @@ impl Milestone {
         }
     }
 }
+
+/// Done versus total tasks and estimated hours of a milestone
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MilestoneProgress {
+    pub total_tasks: i32,
+    pub done_tasks: i32,
+    pub total_hours: f32,
+    pub done_hours: f32,
+}
+
+impl MilestoneProgress {
+    /// Totals of the milestone's tasks; tasks without an estimate count as zero hours
+    pub fn of(tasks: &[Task]) -> Self {
+        let hours = |task: &Task| task.estimated_hours.unwrap_or(0.0);
+        let done: Vec<&Task> = tasks.iter().filter(|task| task.is_done()).collect();
+        Self {
+            total_tasks: tasks.len() as i32,
+            done_tasks: done.len() as i32,
+            total_hours: tasks.iter().map(hours).sum(),
+            done_hours: done.iter().map(|task| hours(task)).sum(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::TaskStatus;
+
+    fn task(hours: Option<f32>, status: TaskStatus) -> Task {
+        let mut task = Task::new(Uuid::nil(), "Task".to_string(), None, None, None, None, hours);
+        task.update_status(status);
+        task
+    }
+
+    #[test]
+    fn test_validate_name() {
+        let milestone = |name: &str| Milestone::new(Uuid::nil(), name.to_string(), None, None);
+        assert!(milestone("Beta").validate().is_ok());
+        assert!(milestone("  ").validate().is_err());
+    }
+
+    #[test]
+    fn test_progress() {
+        let tasks = vec![
+            task(Some(4.0), TaskStatus::Done),
+            task(Some(6.0), TaskStatus::InProgress),
+            task(None, TaskStatus::Done),
+        ];
+        let progress = MilestoneProgress::of(&tasks);
+        assert_eq!((progress.total_tasks, progress.done_tasks), (3, 2));
+        assert_eq!((progress.total_hours, progress.done_hours), (10.0, 4.0));
+        assert_eq!(MilestoneProgress::of(&[]), MilestoneProgress::default());
+    }
+}
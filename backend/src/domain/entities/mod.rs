@@ -1,21 +1,49 @@
 mod activity_log;
 mod attachment;
+mod budget;
+mod change_record;
+mod checklist;
+mod custom_field;
 mod milestone;
 mod notification;
 mod project;
+mod project_template;
+mod recurring_task;
+mod search_hit;
+mod sla_policy;
+mod sprint;
 mod tag;
 mod task;
+mod task_dependency;
+mod task_template;
+mod task_watcher;
 mod team;
 mod time_log;
+mod trash;
 mod user;
+mod workflow;
 
 pub use activity_log::{ActivityLog, ActivityLogWithDetails};
 pub use attachment::Attachment;
-pub use milestone::Milestone;
+pub use budget::{CostRate, Expense, LabourEntry};
+pub use change_record::{ChangeAction, ChangeEntityType, ChangeRecord, FieldChange, Tracked};
+pub use checklist::{Checklist, ChecklistItem};
+pub use custom_field::{CustomField, CustomFieldType};
+pub use milestone::{Milestone, MilestoneProgress};
 pub use notification::{Notification, NotificationType};
 pub use project::{Project, ProjectMember};
+pub use project_template::{ProjectSkeleton, ProjectTemplate, TemplateMember, TemplateMilestone, TemplateTask};
+pub use recurring_task::{RecurringTask, RecurringTaskOccurrence};
+pub use search_hit::{SearchEntityType, SearchHit};
+pub use sla_policy::{SlaBreach, SlaBreachCount, SlaPolicy, SlaTarget};
+pub use sprint::{Sprint, SprintSnapshot, SprintStatus};
 pub use tag::{Tag, TaskTag};
-pub use task::{Task, TaskComment};
+pub use task::{Task, TaskComment, TaskCommentEdit};
+pub use task_dependency::TaskDependency;
+pub use task_template::TaskTemplate;
+pub use task_watcher::TaskWatcher;
 pub use team::{Team, TeamMember};
 pub use time_log::TimeLog;
+pub use trash::Trashed;
 pub use user::User;
+pub use workflow::{ProjectWorkflow, WorkflowStatus, WorkflowTransition};
@@ -9,6 +9,10 @@ pub enum NotificationType {
     TaskUpdated,
     TaskCompleted,
     TaskDueSoon,
+    TaskOverdue,
+    MilestoneDueSoon,
+    MilestoneOverdue,
+    SlaBreached,
     ProjectUpdated,
     CommentAdded,
     Mention,
@@ -22,6 +26,10 @@ impl std::fmt::Display for NotificationType {
             NotificationType::TaskUpdated => write!(f, "task_updated"),
             NotificationType::TaskCompleted => write!(f, "task_completed"),
             NotificationType::TaskDueSoon => write!(f, "task_due_soon"),
+            NotificationType::TaskOverdue => write!(f, "task_overdue"),
+            NotificationType::MilestoneDueSoon => write!(f, "milestone_due_soon"),
+            NotificationType::MilestoneOverdue => write!(f, "milestone_overdue"),
+            NotificationType::SlaBreached => write!(f, "sla_breached"),
             NotificationType::ProjectUpdated => write!(f, "project_updated"),
             NotificationType::CommentAdded => write!(f, "comment_added"),
             NotificationType::Mention => write!(f, "mention"),
@@ -39,6 +47,10 @@ impl std::str::FromStr for NotificationType {
             "task_updated" => Ok(NotificationType::TaskUpdated),
             "task_completed" => Ok(NotificationType::TaskCompleted),
             "task_due_soon" => Ok(NotificationType::TaskDueSoon),
+            "task_overdue" => Ok(NotificationType::TaskOverdue),
+            "milestone_due_soon" => Ok(NotificationType::MilestoneDueSoon),
+            "milestone_overdue" => Ok(NotificationType::MilestoneOverdue),
+            "sla_breached" => Ok(NotificationType::SlaBreached),
             "project_updated" => Ok(NotificationType::ProjectUpdated),
             "comment_added" => Ok(NotificationType::CommentAdded),
             "mention" => Ok(NotificationType::Mention),
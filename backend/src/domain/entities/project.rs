@@ -3,7 +3,7 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::value_objects::{Priority, ProjectStatus};
+use crate::domain::value_objects::{Priority, ProjectRole, ProjectStatus};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
@@ -21,6 +21,7 @@ pub struct Project {
 }
 
 impl Project {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         name: String,
         description: Option<String>,
@@ -70,17 +71,17 @@ pub struct ProjectMember {
     pub id: Uuid,
     pub project_id: Uuid,
     pub user_id: Uuid,
-    pub role: Option<String>,
+    pub role: ProjectRole,
     pub joined_at: DateTime<Utc>,
 }
 
 impl ProjectMember {
-    pub fn new(project_id: Uuid, user_id: Uuid, role: Option<String>) -> Self {
+    pub fn new(project_id: Uuid, user_id: Uuid, role: Option<ProjectRole>) -> Self {
         Self {
             id: Uuid::new_v4(),
             project_id,
             user_id,
-            role,
+            role: role.unwrap_or_default(),
             joined_at: Utc::now(),
         }
     }
@@ -0,0 +1,371 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::domain::entities::{
+    ChecklistItem, Milestone, Project, ProjectMember, ProjectWorkflow, Task, TaskDependency, TaskTag,
+};
+use crate::domain::value_objects::{Priority, ProjectRole, TaskStatus};
+use crate::shared::DomainError;
+
+/// Skeleton of a project for starting new ones. Dates are kept as offsets from the
+/// start of the source project and shifted onto the start of each created project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectTemplate {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub priority: Priority,
+    pub end_offset_secs: Option<i64>,
+    pub milestones: Vec<TemplateMilestone>,
+    /// Parents come before their subtasks
+    pub tasks: Vec<TemplateTask>,
+    pub members: Vec<TemplateMember>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateMilestone {
+    /// Id of the milestone in the source project, referenced by its tasks
+    pub key: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub due_offset_secs: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateTask {
+    /// Id of the task in the source project, referenced by its subtasks and dependents
+    pub key: Uuid,
+    pub parent_key: Option<Uuid>,
+    pub milestone_key: Option<Uuid>,
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Priority,
+    pub estimated_hours: Option<f32>,
+    pub story_points: Option<i32>,
+    pub start_offset_secs: Option<i64>,
+    pub due_offset_secs: Option<i64>,
+    pub tag_ids: Vec<Uuid>,
+    /// Checklist item contents, in order
+    pub checklist: Vec<String>,
+    /// Keys of the tasks blocking this one
+    pub blocked_by: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateMember {
+    pub user_id: Uuid,
+    pub role: ProjectRole,
+}
+
+/// A project with everything saved in and created from a template
+#[derive(Debug, Clone)]
+pub struct ProjectSkeleton {
+    pub project: Project,
+    pub members: Vec<ProjectMember>,
+    pub milestones: Vec<Milestone>,
+    /// Parents come before their subtasks
+    pub tasks: Vec<Task>,
+    pub checklist_items: Vec<ChecklistItem>,
+    pub task_tags: Vec<TaskTag>,
+    pub dependencies: Vec<TaskDependency>,
+}
+
+impl ProjectTemplate {
+    /// Capture the source project; work progress, assignees and time are left behind
+    pub fn capture(name: String, description: Option<String>, source: &ProjectSkeleton, created_by: Uuid) -> Self {
+        let anchor = source.project.start_date.unwrap_or(source.project.created_at);
+        let offset = |date: DateTime<Utc>| (date - anchor).num_seconds();
+
+        let mut checklists: HashMap<Uuid, Vec<&ChecklistItem>> = HashMap::new();
+        for item in &source.checklist_items {
+            checklists.entry(item.task_id).or_default().push(item);
+        }
+        let mut tag_ids: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for task_tag in &source.task_tags {
+            tag_ids.entry(task_tag.task_id).or_default().push(task_tag.tag_id);
+        }
+        let mut blocked_by: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for dependency in &source.dependencies {
+            blocked_by
+                .entry(dependency.blocked_task_id)
+                .or_default()
+                .push(dependency.blocking_task_id);
+        }
+
+        let tasks = Self::parents_first(&source.tasks)
+            .into_iter()
+            .map(|task| {
+                let mut checklist = checklists.remove(&task.id).unwrap_or_default();
+                checklist.sort_by_key(|item| item.position);
+                TemplateTask {
+                    key: task.id,
+                    parent_key: task.parent_id,
+                    milestone_key: task.milestone_id,
+                    title: task.title.clone(),
+                    description: task.description.clone(),
+                    priority: task.priority.clone(),
+                    estimated_hours: task.estimated_hours,
+                    story_points: task.story_points,
+                    start_offset_secs: task.start_date.map(offset),
+                    due_offset_secs: task.due_date.map(offset),
+                    tag_ids: tag_ids.remove(&task.id).unwrap_or_default(),
+                    checklist: checklist.into_iter().map(|item| item.content.clone()).collect(),
+                    blocked_by: blocked_by.remove(&task.id).unwrap_or_default(),
+                }
+            })
+            .collect();
+
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            description,
+            priority: source.project.priority.clone(),
+            end_offset_secs: source.project.end_date.map(offset),
+            milestones: source
+                .milestones
+                .iter()
+                .map(|milestone| TemplateMilestone {
+                    key: milestone.id,
+                    name: milestone.name.clone(),
+                    description: milestone.description.clone(),
+                    due_offset_secs: milestone.due_date.map(offset),
+                })
+                .collect(),
+            tasks,
+            members: source
+                .members
+                .iter()
+                .map(|member| TemplateMember {
+                    user_id: member.user_id,
+                    role: member.role,
+                })
+                .collect(),
+            created_by: Some(created_by),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.name.trim().is_empty() {
+            return Err(DomainError::ValidationError("Template name cannot be empty".into()));
+        }
+        Ok(())
+    }
+
+    /// Fill the new project with fresh copies, dates shifted onto its start date (now when unset).
+    /// The end date is only taken from the template when the project has none.
+    pub fn instantiate(&self, mut project: Project) -> ProjectSkeleton {
+        let anchor = *project.start_date.get_or_insert_with(Utc::now);
+        let shift = |offset: i64| anchor + Duration::seconds(offset);
+        if project.end_date.is_none() {
+            project.end_date = self.end_offset_secs.map(shift);
+        }
+
+        let milestones: Vec<Milestone> = self
+            .milestones
+            .iter()
+            .map(|milestone| {
+                Milestone::new(
+                    project.id,
+                    milestone.name.clone(),
+                    milestone.description.clone(),
+                    milestone.due_offset_secs.map(shift),
+                )
+            })
+            .collect();
+        let milestone_ids: HashMap<Uuid, Uuid> = self
+            .milestones
+            .iter()
+            .zip(&milestones)
+            .map(|(template, milestone)| (template.key, milestone.id))
+            .collect();
+
+        // New projects start on the built-in workflow
+        let workflow = ProjectWorkflow::default_for(project.id);
+        let task_ids: HashMap<Uuid, Uuid> = self.tasks.iter().map(|task| (task.key, Uuid::new_v4())).collect();
+        let mut tasks = Vec::with_capacity(self.tasks.len());
+        let mut checklist_items = Vec::new();
+        let mut task_tags = Vec::new();
+        let mut dependencies = Vec::new();
+        for template in &self.tasks {
+            let mut task = Task::new(
+                project.id,
+                template.title.clone(),
+                template.description.clone(),
+                Some(template.priority.clone()),
+                None,
+                template.due_offset_secs.map(shift),
+                template.estimated_hours,
+            );
+            task.id = task_ids[&template.key];
+            task.parent_id = template.parent_key.and_then(|key| task_ids.get(&key).copied());
+            task.milestone_id = template.milestone_key.and_then(|key| milestone_ids.get(&key).copied());
+            task.start_date = template.start_offset_secs.map(shift);
+            task.story_points = template.story_points;
+
+            checklist_items.extend(
+                template
+                    .checklist
+                    .iter()
+                    .enumerate()
+                    .map(|(position, content)| ChecklistItem::new(task.id, content.clone(), position as i32)),
+            );
+            task_tags.extend(template.tag_ids.iter().map(|&tag_id| TaskTag::new(task.id, tag_id)));
+            let blocked_by: Vec<TaskDependency> = template
+                .blocked_by
+                .iter()
+                .filter_map(|key| task_ids.get(key))
+                .map(|&blocking| TaskDependency::new(blocking, task.id, Some(project.owner_id)))
+                .collect();
+            // Created tasks are never done, so any blocker is unfinished
+            if !blocked_by.is_empty() {
+                if let Some(blocked) = workflow.status_for_category(&TaskStatus::Blocked) {
+                    task.move_to_workflow_status(blocked);
+                }
+            }
+            dependencies.extend(blocked_by);
+            tasks.push(task);
+        }
+
+        let members = self
+            .members
+            .iter()
+            .filter(|member| member.user_id != project.owner_id)
+            .map(|member| ProjectMember::new(project.id, member.user_id, Some(member.role)))
+            .collect();
+
+        ProjectSkeleton {
+            project,
+            members,
+            milestones,
+            tasks,
+            checklist_items,
+            task_tags,
+            dependencies,
+        }
+    }
+
+    /// Order tasks so every parent precedes its subtasks; subtasks of missing parents become top-level
+    fn parents_first(tasks: &[Task]) -> Vec<&Task> {
+        let ids: HashSet<Uuid> = tasks.iter().map(|task| task.id).collect();
+        let mut children: HashMap<Option<Uuid>, Vec<&Task>> = HashMap::new();
+        for task in tasks {
+            let parent = task.parent_id.filter(|parent_id| ids.contains(parent_id));
+            children.entry(parent).or_default().push(task);
+        }
+
+        let mut ordered = Vec::with_capacity(tasks.len());
+        let mut stack: Vec<&Task> = children.remove(&None).unwrap_or_default().into_iter().rev().collect();
+        while let Some(task) = stack.pop() {
+            ordered.push(task);
+            if let Some(subtasks) = children.remove(&Some(task.id)) {
+                stack.extend(subtasks.into_iter().rev());
+            }
+        }
+        ordered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2030, 3, day, 9, 0, 0).unwrap()
+    }
+
+    fn source() -> ProjectSkeleton {
+        let owner_id = Uuid::new_v4();
+        let project = Project::new("Acme onboarding".to_string(), None, owner_id, None, None, Some(at(1)), Some(at(31)), None);
+        let milestone = Milestone::new(project.id, "Kickoff".to_string(), None, Some(at(8)));
+
+        let mut child = Task::new(project.id, "Collect access".to_string(), None, None, Some(owner_id), Some(at(5)), Some(4.0));
+        let parent = Task::new(project.id, "Discovery".to_string(), None, None, None, Some(at(10)), None);
+        child.parent_id = Some(parent.id);
+        child.milestone_id = Some(milestone.id);
+        child.start_date = Some(at(2));
+        let report = Task::new(project.id, "Report".to_string(), None, None, None, None, None);
+
+        ProjectSkeleton {
+            members: vec![ProjectMember::new(project.id, Uuid::new_v4(), Some(ProjectRole::Maintainer))],
+            milestones: vec![milestone],
+            checklist_items: vec![
+                ChecklistItem::new(child.id, "VPN".to_string(), 1),
+                ChecklistItem::new(child.id, "Repository".to_string(), 0),
+            ],
+            task_tags: vec![TaskTag::new(child.id, Uuid::nil())],
+            dependencies: vec![TaskDependency::new(parent.id, report.id, None)],
+            tasks: vec![child, parent, report],
+            project,
+        }
+    }
+
+    #[test]
+    fn test_capture_keeps_offsets_and_orders_parents_first() {
+        let source = source();
+        let template = ProjectTemplate::capture("Onboarding".to_string(), None, &source, Uuid::new_v4());
+
+        assert_eq!(template.end_offset_secs, Some(30 * 86_400));
+        assert_eq!(template.milestones[0].due_offset_secs, Some(7 * 86_400));
+        let titles: Vec<&str> = template.tasks.iter().map(|task| task.title.as_str()).collect();
+        assert_eq!(titles, vec!["Discovery", "Collect access", "Report"]);
+
+        let child = &template.tasks[1];
+        assert_eq!(child.start_offset_secs, Some(86_400));
+        assert_eq!(child.checklist, vec!["Repository", "VPN"]);
+        assert_eq!(child.tag_ids, vec![Uuid::nil()]);
+        assert_eq!(template.tasks[2].blocked_by, vec![template.tasks[0].key]);
+        assert_eq!(template.members[0].role, ProjectRole::Maintainer);
+    }
+
+    #[test]
+    fn test_instantiate_shifts_dates_to_anchor_and_links_copies() {
+        let template = ProjectTemplate::capture("Onboarding".to_string(), None, &source(), Uuid::new_v4());
+        let anchor = Utc.with_ymd_and_hms(2031, 1, 10, 9, 0, 0).unwrap();
+        let project = Project::new("Globex onboarding".to_string(), None, Uuid::new_v4(), None, None, Some(anchor), None, None);
+
+        let skeleton = template.instantiate(project);
+
+        assert_eq!(skeleton.project.end_date, Some(anchor + Duration::days(30)));
+        assert_eq!(skeleton.milestones[0].due_date, Some(anchor + Duration::days(7)));
+        let (parent, child, report) = (&skeleton.tasks[0], &skeleton.tasks[1], &skeleton.tasks[2]);
+        assert_eq!(child.parent_id, Some(parent.id));
+        assert_eq!(child.milestone_id, Some(skeleton.milestones[0].id));
+        assert_eq!(child.start_date, Some(anchor + Duration::days(1)));
+        assert_eq!(child.due_date, Some(anchor + Duration::days(4)));
+        assert_eq!(child.assignee_id, None);
+        assert_eq!(skeleton.checklist_items.len(), 2);
+        assert!(skeleton.checklist_items.iter().all(|item| item.task_id == child.id));
+        assert_eq!(skeleton.task_tags[0].task_id, child.id);
+        assert_eq!(skeleton.dependencies[0].blocking_task_id, parent.id);
+        assert_eq!(skeleton.dependencies[0].blocked_task_id, report.id);
+        assert!(report.is_blocked());
+        assert!(!parent.is_blocked() && !child.is_blocked());
+        assert_eq!(skeleton.members.len(), 1);
+    }
+
+    #[test]
+    fn test_instantiate_leaves_out_owner_membership() {
+        let source = source();
+        let mut template = ProjectTemplate::capture("Onboarding".to_string(), None, &source, Uuid::new_v4());
+        let owner_id = Uuid::new_v4();
+        template.members.push(TemplateMember {
+            user_id: owner_id,
+            role: ProjectRole::Viewer,
+        });
+        let project = Project::new("Initech".to_string(), None, owner_id, None, None, None, None, None);
+
+        let skeleton = template.instantiate(project);
+
+        assert!(skeleton.project.start_date.is_some());
+        assert!(skeleton.members.iter().all(|member| member.user_id != owner_id));
+    }
+}
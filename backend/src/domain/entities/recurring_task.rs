@@ -0,0 +1,121 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::{Priority, RecurrenceRule};
+use crate::shared::DomainError;
+
+/// Template for a series of tasks generated from an RRULE
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurringTask {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Priority,
+    pub assignee_id: Option<Uuid>,
+    pub estimated_hours: Option<f32>,
+    /// RFC 5545 recurrence rule, e.g. `FREQ=WEEKLY;BYDAY=MO`
+    pub rrule: String,
+    /// First occurrence of the series; its time of day is used for every due date
+    pub dtstart: DateTime<Utc>,
+    /// How many days before its due date an occurrence is created
+    pub lead_days: i32,
+    pub is_active: bool,
+    /// Last date the generator has processed occurrences up to
+    pub generated_through: Option<NaiveDate>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RecurringTask {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        project_id: Uuid,
+        title: String,
+        description: Option<String>,
+        priority: Option<Priority>,
+        assignee_id: Option<Uuid>,
+        estimated_hours: Option<f32>,
+        rrule: String,
+        dtstart: DateTime<Utc>,
+        lead_days: i32,
+        created_by: Uuid,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            title,
+            description,
+            priority: priority.unwrap_or_default(),
+            assignee_id,
+            estimated_hours,
+            rrule,
+            dtstart,
+            lead_days,
+            is_active: true,
+            generated_through: None,
+            created_by: Some(created_by),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn rule(&self) -> Result<RecurrenceRule, DomainError> {
+        RecurrenceRule::parse(&self.rrule)
+    }
+
+    /// Due date of the occurrence on `date`, at the series' time of day
+    pub fn due_at(&self, date: NaiveDate) -> DateTime<Utc> {
+        date.and_time(self.dtstart.time()).and_utc()
+    }
+}
+
+/// A single occurrence of a series that was edited, skipped or generated
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurringTaskOccurrence {
+    pub id: Uuid,
+    pub recurring_task_id: Uuid,
+    pub occurrence_date: NaiveDate,
+    pub skipped: bool,
+    // Overrides applied to this occurrence only
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub priority: Option<Priority>,
+    pub assignee_id: Option<Uuid>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub estimated_hours: Option<f32>,
+    /// Set once the task was created; deleting the task does not regenerate it
+    pub generated_at: Option<DateTime<Utc>>,
+    pub task_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RecurringTaskOccurrence {
+    pub fn new(recurring_task_id: Uuid, occurrence_date: NaiveDate) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            recurring_task_id,
+            occurrence_date,
+            skipped: false,
+            title: None,
+            description: None,
+            priority: None,
+            assignee_id: None,
+            due_date: None,
+            estimated_hours: None,
+            generated_at: None,
+            task_id: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_generated(&self) -> bool {
+        self.generated_at.is_some()
+    }
+}
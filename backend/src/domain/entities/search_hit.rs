@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::shared::DomainError;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchEntityType {
+    Project,
+    Task,
+    Comment,
+}
+
+impl SearchEntityType {
+    pub const ALL: [SearchEntityType; 3] = [
+        SearchEntityType::Project,
+        SearchEntityType::Task,
+        SearchEntityType::Comment,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchEntityType::Project => "project",
+            SearchEntityType::Task => "task",
+            SearchEntityType::Comment => "comment",
+        }
+    }
+
+    /// Parse a comma separated list such as `task,comment`; empty means every type
+    pub fn parse_list(types: Option<&str>) -> Result<Vec<SearchEntityType>, DomainError> {
+        let mut parsed = Vec::new();
+        for name in types
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+        {
+            let entity_type = Self::ALL
+                .into_iter()
+                .find(|t| t.as_str().eq_ignore_ascii_case(name))
+                .ok_or_else(|| {
+                    DomainError::ValidationError(format!("Unknown search type '{}'", name))
+                })?;
+            if !parsed.contains(&entity_type) {
+                parsed.push(entity_type);
+            }
+        }
+
+        if parsed.is_empty() {
+            parsed = Self::ALL.to_vec();
+        }
+        Ok(parsed)
+    }
+}
+
+/// A ranked full-text search match
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    #[serde(rename = "type")]
+    pub entity_type: SearchEntityType,
+    pub id: Uuid,
+    pub project_id: Uuid,
+    /// Task the match belongs to, for tasks and comments
+    pub task_id: Option<Uuid>,
+    /// Project name, task title, or the title of the commented task
+    pub title: String,
+    /// Matching excerpt with terms wrapped in `<mark>` tags
+    pub snippet: String,
+    pub rank: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(
+            SearchEntityType::parse_list(None).unwrap(),
+            SearchEntityType::ALL.to_vec()
+        );
+        assert_eq!(
+            SearchEntityType::parse_list(Some("Task, comment,task")).unwrap(),
+            vec![SearchEntityType::Task, SearchEntityType::Comment]
+        );
+        assert!(SearchEntityType::parse_list(Some("task,milestone")).is_err());
+    }
+}
@@ -0,0 +1,156 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::Priority;
+use crate::shared::DomainError;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "sla_target", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum SlaTarget {
+    /// The task has to leave Todo
+    Response,
+    /// The task has to be done
+    Resolution,
+}
+
+impl SlaTarget {
+    pub const ALL: [SlaTarget; 2] = [SlaTarget::Response, SlaTarget::Resolution];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SlaTarget::Response => "response",
+            SlaTarget::Resolution => "resolution",
+        }
+    }
+}
+
+/// Time limits for tasks of one priority in a project, counted from task creation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlaPolicy {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub priority: Priority,
+    /// Minutes until the task must leave Todo
+    pub response_minutes: Option<i32>,
+    /// Minutes until the task must be done
+    pub resolution_minutes: Option<i32>,
+    /// Count only working hours instead of calendar time
+    pub business_hours: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SlaPolicy {
+    pub fn new(
+        project_id: Uuid,
+        priority: Priority,
+        response_minutes: Option<i32>,
+        resolution_minutes: Option<i32>,
+        business_hours: bool,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            priority,
+            response_minutes,
+            resolution_minutes,
+            business_hours,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.response_minutes.is_none() && self.resolution_minutes.is_none() {
+            return Err(DomainError::ValidationError(
+                "SLA policy needs a response or resolution target".into(),
+            ));
+        }
+        if self.response_minutes.is_some_and(|m| m <= 0) || self.resolution_minutes.is_some_and(|m| m <= 0) {
+            return Err(DomainError::ValidationError("SLA targets must be positive".into()));
+        }
+        if let (Some(response), Some(resolution)) = (self.response_minutes, self.resolution_minutes) {
+            if response > resolution {
+                return Err(DomainError::ValidationError(
+                    "Response target cannot be longer than resolution target".into(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn target_minutes(&self, target: SlaTarget) -> Option<i32> {
+        match target {
+            SlaTarget::Response => self.response_minutes,
+            SlaTarget::Resolution => self.resolution_minutes,
+        }
+    }
+}
+
+/// A task that missed one SLA target; recorded once per task and target
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlaBreach {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub project_id: Uuid,
+    /// `None` once the policy is deleted
+    pub policy_id: Option<Uuid>,
+    pub priority: Priority,
+    pub target: SlaTarget,
+    pub target_minutes: i32,
+    /// When the target ran out
+    pub breached_at: DateTime<Utc>,
+    /// Users notified about the breach
+    pub escalated_to: Vec<Uuid>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl SlaBreach {
+    pub fn new(
+        task_id: Uuid,
+        policy: &SlaPolicy,
+        target: SlaTarget,
+        breached_at: DateTime<Utc>,
+        escalated_to: Vec<Uuid>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            task_id,
+            project_id: policy.project_id,
+            policy_id: Some(policy.id),
+            priority: policy.priority.clone(),
+            target,
+            target_minutes: policy.target_minutes(target).unwrap_or_default(),
+            breached_at,
+            escalated_to,
+            recorded_at: Utc::now(),
+        }
+    }
+}
+
+/// Number of breaches per priority and target, for reporting
+#[derive(Debug, Clone, Serialize)]
+pub struct SlaBreachCount {
+    pub priority: Priority,
+    pub target: SlaTarget,
+    pub count: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_targets() {
+        let policy = |response, resolution| SlaPolicy::new(Uuid::nil(), Priority::Critical, response, resolution, true);
+
+        assert!(policy(Some(240), Some(2880)).validate().is_ok());
+        assert!(policy(None, Some(2880)).validate().is_ok());
+        assert!(policy(None, None).validate().is_err());
+        assert!(policy(Some(0), None).validate().is_err());
+        assert!(policy(Some(300), Some(240)).validate().is_err());
+    }
+}
@@ -0,0 +1,188 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::entities::Task;
+use crate::shared::DomainError;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "sprint_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum SprintStatus {
+    Planned,
+    Active,
+    Closed,
+}
+
+/// Time-boxed iteration of a project; tasks are planned into it by `Task::sprint_id`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sprint {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub name: String,
+    pub goal: Option<String>,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub status: SprintStatus,
+    /// Story points planned when the sprint started
+    pub committed_points: Option<i32>,
+    /// Story points finished when the sprint closed
+    pub completed_points: Option<i32>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Sprint {
+    pub fn new(
+        project_id: Uuid,
+        name: String,
+        goal: Option<String>,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            name,
+            goal,
+            start_date,
+            end_date,
+            status: SprintStatus::Planned,
+            committed_points: None,
+            completed_points: None,
+            started_at: None,
+            closed_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.name.trim().is_empty() {
+            return Err(DomainError::ValidationError("Sprint name cannot be empty".into()));
+        }
+        if self.end_date < self.start_date {
+            return Err(DomainError::ValidationError(
+                "Sprint cannot end before it starts".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.status == SprintStatus::Closed
+    }
+
+    pub fn start(&mut self, committed_points: i32) -> Result<(), DomainError> {
+        if self.status != SprintStatus::Planned {
+            return Err(DomainError::ValidationError("Only planned sprints can be started".into()));
+        }
+        let now = Utc::now();
+        self.status = SprintStatus::Active;
+        self.committed_points = Some(committed_points);
+        self.started_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    pub fn close(&mut self, completed_points: i32) -> Result<(), DomainError> {
+        if self.status != SprintStatus::Active {
+            return Err(DomainError::ValidationError("Only active sprints can be closed".into()));
+        }
+        let now = Utc::now();
+        self.status = SprintStatus::Closed;
+        self.completed_points = Some(completed_points);
+        self.closed_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+/// Story point totals of a sprint on one day; later captures of the same day replace earlier ones
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SprintSnapshot {
+    pub sprint_id: Uuid,
+    pub snapshot_date: NaiveDate,
+    /// Points of every task in the sprint, i.e. its scope
+    pub total_points: i32,
+    pub completed_points: i32,
+    pub total_tasks: i32,
+    pub completed_tasks: i32,
+    pub captured_at: DateTime<Utc>,
+}
+
+impl SprintSnapshot {
+    /// Totals of the sprint's tasks; tasks without an estimate count as zero points
+    pub fn capture(sprint_id: Uuid, snapshot_date: NaiveDate, tasks: &[Task]) -> Self {
+        let points = |task: &Task| task.story_points.unwrap_or(0);
+        let done: Vec<&Task> = tasks.iter().filter(|task| task.is_done()).collect();
+        Self {
+            sprint_id,
+            snapshot_date,
+            total_points: tasks.iter().map(points).sum(),
+            completed_points: done.iter().map(|task| points(task)).sum(),
+            total_tasks: tasks.len() as i32,
+            completed_tasks: done.len() as i32,
+            captured_at: Utc::now(),
+        }
+    }
+
+    pub fn remaining_points(&self) -> i32 {
+        self.total_points - self.completed_points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::TaskStatus;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, day).unwrap()
+    }
+
+    fn task(points: Option<i32>, status: TaskStatus) -> Task {
+        let mut task = Task::new(Uuid::nil(), "Task".to_string(), None, None, None, None, None);
+        task.story_points = points;
+        task.update_status(status);
+        task
+    }
+
+    #[test]
+    fn test_lifecycle() {
+        let mut sprint = Sprint::new(Uuid::nil(), "Sprint 1".to_string(), None, date(1), date(14));
+        assert!(sprint.validate().is_ok());
+        assert!(sprint.close(0).is_err());
+
+        sprint.start(21).unwrap();
+        assert_eq!(sprint.status, SprintStatus::Active);
+        assert!(sprint.start(21).is_err());
+
+        sprint.close(13).unwrap();
+        assert!(sprint.is_closed());
+        assert_eq!(sprint.completed_points, Some(13));
+    }
+
+    #[test]
+    fn test_validate_dates() {
+        let sprint = Sprint::new(Uuid::nil(), "Sprint 1".to_string(), None, date(14), date(1));
+        assert!(sprint.validate().is_err());
+    }
+
+    #[test]
+    fn test_capture_snapshot() {
+        let tasks = vec![
+            task(Some(5), TaskStatus::Done),
+            task(Some(3), TaskStatus::InProgress),
+            task(None, TaskStatus::Done),
+        ];
+        let snapshot = SprintSnapshot::capture(Uuid::nil(), date(3), &tasks);
+        assert_eq!(snapshot.total_points, 8);
+        assert_eq!(snapshot.completed_points, 5);
+        assert_eq!(snapshot.remaining_points(), 3);
+        assert_eq!((snapshot.total_tasks, snapshot.completed_tasks), (3, 2));
+    }
+}
@@ -1,22 +1,37 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 use uuid::Uuid;
 
+use crate::domain::entities::WorkflowStatus;
 use crate::domain::value_objects::{Priority, TaskStatus};
+use crate::shared::DomainError;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
     pub id: Uuid,
     pub project_id: Uuid,
+    pub parent_id: Option<Uuid>,
     pub milestone_id: Option<Uuid>,
+    pub sprint_id: Option<Uuid>,
     pub title: String,
     pub description: Option<String>,
     pub status: TaskStatus,
+    /// Key of the project workflow status; `status` holds its category
+    pub workflow_status: String,
     pub priority: Priority,
     pub assignee_id: Option<Uuid>,
+    /// Planned start; the schedule never starts the task earlier
+    pub start_date: Option<DateTime<Utc>>,
     pub due_date: Option<DateTime<Utc>>,
     pub estimated_hours: Option<f32>,
     pub actual_hours: Option<f32>,
+    pub story_points: Option<i32>,
+    /// Order within the (project, workflow status) board column, ascending
+    pub rank: f64,
+    /// Values of the project's custom fields, keyed by field id
+    pub custom_fields: HashMap<Uuid, Value>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -35,15 +50,22 @@ impl Task {
         Self {
             id: Uuid::new_v4(),
             project_id,
+            parent_id: None,
             milestone_id: None,
+            sprint_id: None,
             title,
             description,
             status: TaskStatus::Todo,
+            workflow_status: TaskStatus::Todo.as_str().to_string(),
             priority: priority.unwrap_or(Priority::Medium),
             assignee_id,
+            start_date: None,
             due_date,
             estimated_hours,
             actual_hours: None,
+            story_points: None,
+            rank: 0.0,
+            custom_fields: HashMap::new(),
             created_at: now,
             updated_at: now,
         }
@@ -58,22 +80,30 @@ impl Task {
     }
 
     pub fn can_transition_to(&self, new_status: &TaskStatus) -> bool {
-        use TaskStatus::*;
-        match (&self.status, new_status) {
-            (Todo, InProgress) => true,
-            (InProgress, Review | Blocked | Todo) => true,
-            (Review, Done | InProgress) => true,
-            (Blocked, InProgress | Todo) => true,
-            (Done, InProgress) => true, // Reopen
-            _ => false,
-        }
+        self.status.can_transition_to(new_status)
     }
 
     pub fn update_status(&mut self, status: TaskStatus) {
+        self.workflow_status = status.as_str().to_string();
         self.status = status;
         self.updated_at = Utc::now();
     }
 
+    pub fn move_to_workflow_status(&mut self, status: &WorkflowStatus) {
+        self.workflow_status = status.key.clone();
+        self.status = status.category.clone();
+        self.updated_at = Utc::now();
+    }
+
+    /// Move the task into another project, dropping the sprint and custom field values
+    /// that belong to the old one
+    pub fn move_to_project(&mut self, project_id: Uuid) {
+        self.project_id = project_id;
+        self.sprint_id = None;
+        self.custom_fields.clear();
+        self.updated_at = Utc::now();
+    }
+
     pub fn assign_to(&mut self, user_id: Option<Uuid>) {
         self.assignee_id = user_id;
         self.updated_at = Utc::now();
@@ -83,6 +113,40 @@ impl Task {
         self.actual_hours = Some(self.actual_hours.unwrap_or(0.0) + hours);
         self.updated_at = Utc::now();
     }
+
+    pub fn validate_dates(&self) -> Result<(), DomainError> {
+        if let (Some(start_date), Some(due_date)) = (self.start_date, self.due_date) {
+            if start_date > due_date {
+                return Err(DomainError::ValidationError("Task cannot start after its due date".into()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Names of the user-facing fields that differ from an earlier version of the task
+    pub fn changed_fields(&self, previous: &Task) -> Vec<&'static str> {
+        let checks = [
+            ("title", self.title != previous.title),
+            ("description", self.description != previous.description),
+            ("status", self.workflow_status != previous.workflow_status),
+            ("priority", self.priority != previous.priority),
+            ("assignee", self.assignee_id != previous.assignee_id),
+            ("start date", self.start_date != previous.start_date),
+            ("due date", self.due_date != previous.due_date),
+            ("estimated hours", self.estimated_hours != previous.estimated_hours),
+            ("actual hours", self.actual_hours != previous.actual_hours),
+            ("story points", self.story_points != previous.story_points),
+            ("milestone", self.milestone_id != previous.milestone_id),
+            ("sprint", self.sprint_id != previous.sprint_id),
+            ("parent task", self.parent_id != previous.parent_id),
+            ("custom fields", self.custom_fields != previous.custom_fields),
+        ];
+        checks
+            .into_iter()
+            .filter(|(_, changed)| *changed)
+            .map(|(field, _)| field)
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -90,21 +154,102 @@ pub struct TaskComment {
     pub id: Uuid,
     pub task_id: Uuid,
     pub user_id: Uuid,
+    /// Comment this one replies to
+    pub parent_id: Option<Uuid>,
     pub content: String,
+    pub edited_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    // Joined fields (populated from queries)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
 }
 
 impl TaskComment {
-    pub fn new(task_id: Uuid, user_id: Uuid, content: String) -> Self {
+    pub fn new(task_id: Uuid, user_id: Uuid, parent_id: Option<Uuid>, content: String) -> Self {
         let now = Utc::now();
         Self {
             id: Uuid::new_v4(),
             task_id,
             user_id,
+            parent_id,
             content,
+            edited_at: None,
             created_at: now,
             updated_at: now,
+            user_name: None,
+        }
+    }
+
+    /// Replace the content, returning the edit record holding the previous version
+    pub fn edit(&mut self, content: String) -> TaskCommentEdit {
+        let now = Utc::now();
+        let previous_content = std::mem::replace(&mut self.content, content);
+        self.edited_at = Some(now);
+        self.updated_at = now;
+
+        TaskCommentEdit {
+            id: Uuid::new_v4(),
+            comment_id: self.id,
+            previous_content,
+            edited_at: now,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskCommentEdit {
+    pub id: Uuid,
+    pub comment_id: Uuid,
+    pub previous_content: String,
+    pub edited_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_dates() {
+        let mut task = Task::new(Uuid::nil(), "Write docs".to_string(), None, None, None, None, None);
+        task.start_date = Some(Utc::now());
+        assert!(task.validate_dates().is_ok());
+
+        task.due_date = task.start_date.map(|start| start - chrono::Duration::days(1));
+        assert!(task.validate_dates().is_err());
+    }
+
+    #[test]
+    fn test_changed_fields_ignores_ordering_and_timestamps() {
+        let previous = Task::new(Uuid::nil(), "Write docs".to_string(), None, None, None, None, None);
+        let mut task = previous.clone();
+        task.rank = 2048.0;
+        task.updated_at = Utc::now();
+        assert!(task.changed_fields(&previous).is_empty());
+
+        task.title = "Write API docs".to_string();
+        task.priority = Priority::High;
+        task.assign_to(Some(Uuid::new_v4()));
+        assert_eq!(task.changed_fields(&previous), vec!["title", "priority", "assignee"]);
+    }
+
+    #[test]
+    fn test_move_to_project_leaves_source_sprint() {
+        let (source, target, sprint) = (Uuid::new_v4(), Uuid::new_v4(), Some(Uuid::new_v4()));
+        let mut task = Task::new(source, "Ship release".to_string(), None, None, None, None, None);
+        task.sprint_id = sprint;
+        task.custom_fields.insert(Uuid::new_v4(), serde_json::json!("high"));
+        let mut subtask = Task::new(source, "Tag build".to_string(), None, None, None, None, None);
+        subtask.parent_id = Some(task.id);
+        subtask.sprint_id = sprint;
+        // A clone starts as a copy of the source task before it is re-homed
+        let mut copy = task.clone();
+
+        for moved in [&mut task, &mut subtask, &mut copy] {
+            moved.move_to_project(target);
+            assert_eq!(moved.project_id, target);
+            assert_eq!(moved.sprint_id, None);
+            assert!(moved.custom_fields.is_empty());
         }
     }
 }
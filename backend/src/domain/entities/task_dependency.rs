@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// `blocking_task_id` blocks `blocked_task_id` until it is done
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskDependency {
+    pub id: Uuid,
+    pub blocking_task_id: Uuid,
+    pub blocked_task_id: Uuid,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TaskDependency {
+    pub fn new(blocking_task_id: Uuid, blocked_task_id: Uuid, created_by: Option<Uuid>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            blocking_task_id,
+            blocked_task_id,
+            created_by,
+            created_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::value_objects::Priority;
+use crate::shared::DomainError;
+
+/// Reusable blueprint for creating tasks in a project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskTemplate {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub name: String,
+    /// Title of created tasks; `{title}` is replaced by the title given on
+    /// creation and `{date}` by today's date (YYYY-MM-DD)
+    pub title_pattern: String,
+    pub description: Option<String>,
+    pub priority: Priority,
+    pub estimated_hours: Option<f32>,
+    pub tag_ids: Vec<Uuid>,
+    /// Checklist item contents, in order
+    pub checklist: Vec<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TaskTemplate {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        project_id: Uuid,
+        name: String,
+        title_pattern: String,
+        description: Option<String>,
+        priority: Option<Priority>,
+        estimated_hours: Option<f32>,
+        tag_ids: Vec<Uuid>,
+        checklist: Vec<String>,
+        created_by: Uuid,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            name,
+            title_pattern,
+            description,
+            priority: priority.unwrap_or_default(),
+            estimated_hours,
+            tag_ids,
+            checklist,
+            created_by: Some(created_by),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.name.trim().is_empty() {
+            return Err(DomainError::ValidationError("Template name cannot be empty".into()));
+        }
+        if self.title_pattern.trim().is_empty() {
+            return Err(DomainError::ValidationError("Title pattern cannot be empty".into()));
+        }
+        if self.checklist.iter().any(|item| item.trim().is_empty()) {
+            return Err(DomainError::ValidationError("Checklist items cannot be empty".into()));
+        }
+        Ok(())
+    }
+
+    /// Title for a task created from this template
+    pub fn render_title(&self, title: &str, today: NaiveDate) -> Result<String, DomainError> {
+        let title = title.trim();
+        if self.title_pattern.contains("{title}") && title.is_empty() {
+            return Err(DomainError::ValidationError(
+                "This template requires a title to fill in {title}".into(),
+            ));
+        }
+
+        Ok(self
+            .title_pattern
+            .replace("{title}", title)
+            .replace("{date}", &today.format("%Y-%m-%d").to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(pattern: &str) -> TaskTemplate {
+        TaskTemplate::new(
+            Uuid::nil(),
+            "Template".to_string(),
+            pattern.to_string(),
+            None,
+            None,
+            None,
+            Vec::new(),
+            vec!["Check".to_string()],
+            Uuid::nil(),
+        )
+    }
+
+    #[test]
+    fn test_render_title_placeholders() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let title = template("Release {title} ({date})").render_title("v1.2", today).unwrap();
+        assert_eq!(title, "Release v1.2 (2024-03-01)");
+    }
+
+    #[test]
+    fn test_render_title_requires_title_for_placeholder() {
+        let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        assert!(template("Release {title}").render_title("  ", today).is_err());
+        assert_eq!(template("Weekly report").render_title("", today).unwrap(), "Weekly report");
+    }
+
+    #[test]
+    fn test_validate_rejects_blank_checklist_item() {
+        let mut template = template("Report");
+        assert!(template.validate().is_ok());
+        template.checklist.push(" ".to_string());
+        assert!(template.validate().is_err());
+    }
+}
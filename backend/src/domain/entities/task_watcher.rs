@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A user following a task's updates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskWatcher {
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    /// Name of the watching user, filled in when listing watchers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+}
+
+impl TaskWatcher {
+    pub fn new(task_id: Uuid, user_id: Uuid) -> Self {
+        Self {
+            task_id,
+            user_id,
+            created_at: Utc::now(),
+            user_name: None,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// An item in the trash, with the moment it was deleted
+#[derive(Debug, Clone, Serialize)]
+pub struct Trashed<T> {
+    #[serde(flatten)]
+    pub item: T,
+    pub deleted_at: DateTime<Utc>,
+}
@@ -0,0 +1,301 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::domain::value_objects::TaskStatus;
+use crate::shared::DomainError;
+
+const MAX_STATUS_KEY_LENGTH: usize = 50;
+
+/// A status in a project workflow. `category` maps it onto the built-in
+/// `TaskStatus`, so a "QA" status can count as Review and "Deployed" as Done.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorkflowStatus {
+    pub key: String,
+    pub name: String,
+    pub category: TaskStatus,
+}
+
+impl WorkflowStatus {
+    pub fn is_done(&self) -> bool {
+        matches!(self.category, TaskStatus::Done)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorkflowTransition {
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectWorkflow {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    /// Ordered statuses; the first one is assigned to new tasks
+    pub statuses: Vec<WorkflowStatus>,
+    pub transitions: Vec<WorkflowTransition>,
+    /// Prevent a task from reaching a done status while subtasks are open
+    #[serde(default)]
+    pub require_subtasks_done: bool,
+    /// True when the project has no custom workflow stored
+    pub is_default: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ProjectWorkflow {
+    pub fn new(
+        project_id: Uuid,
+        statuses: Vec<WorkflowStatus>,
+        transitions: Vec<WorkflowTransition>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            statuses,
+            transitions,
+            require_subtasks_done: false,
+            is_default: false,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Built-in Todo -> InProgress -> Review -> Done workflow
+    pub fn default_for(project_id: Uuid) -> Self {
+        let statuses = TaskStatus::ALL
+            .iter()
+            .map(|status| WorkflowStatus {
+                key: status.as_str().to_string(),
+                name: default_status_name(status).to_string(),
+                category: status.clone(),
+            })
+            .collect();
+
+        let transitions = TaskStatus::ALL
+            .iter()
+            .flat_map(|from| {
+                TaskStatus::ALL
+                    .iter()
+                    .filter(move |to| from.can_transition_to(to))
+                    .map(move |to| WorkflowTransition {
+                        from: from.as_str().to_string(),
+                        to: to.as_str().to_string(),
+                    })
+            })
+            .collect();
+
+        let mut workflow = Self::new(project_id, statuses, transitions);
+        workflow.is_default = true;
+        workflow
+    }
+
+    pub fn initial_status(&self) -> Option<&WorkflowStatus> {
+        self.statuses.first()
+    }
+
+    pub fn find_status(&self, key: &str) -> Option<&WorkflowStatus> {
+        self.statuses.iter().find(|s| s.key == key)
+    }
+
+    /// First status of the workflow mapped to the given category
+    pub fn status_for_category(&self, category: &TaskStatus) -> Option<&WorkflowStatus> {
+        self.statuses.iter().find(|s| &s.category == category)
+    }
+
+    /// Status matching one from another workflow: same key, else same category, else the initial status
+    pub fn equivalent_status(&self, key: &str, category: &TaskStatus) -> Option<&WorkflowStatus> {
+        self.find_status(key)
+            .or_else(|| self.status_for_category(category))
+            .or_else(|| self.initial_status())
+    }
+
+    pub fn done_status_keys(&self) -> Vec<&str> {
+        self.statuses
+            .iter()
+            .filter(|s| s.is_done())
+            .map(|s| s.key.as_str())
+            .collect()
+    }
+
+    pub fn can_transition(&self, from: &str, to: &str) -> bool {
+        from == to
+            || self
+                .transitions
+                .iter()
+                .any(|t| t.from == from && t.to == to)
+    }
+
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.statuses.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Workflow must define at least one status".into(),
+            ));
+        }
+
+        let mut keys = HashSet::new();
+        for status in &self.statuses {
+            if status.key.is_empty()
+                || status.key.len() > MAX_STATUS_KEY_LENGTH
+                || !status
+                    .key
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+            {
+                return Err(DomainError::ValidationError(format!(
+                    "Invalid status key '{}': use 1-{} lowercase letters, digits, '_' or '-'",
+                    status.key, MAX_STATUS_KEY_LENGTH
+                )));
+            }
+            if status.name.trim().is_empty() {
+                return Err(DomainError::ValidationError(format!(
+                    "Status '{}' must have a name",
+                    status.key
+                )));
+            }
+            if !keys.insert(status.key.as_str()) {
+                return Err(DomainError::ValidationError(format!(
+                    "Duplicate status key '{}'",
+                    status.key
+                )));
+            }
+        }
+
+        if self.done_status_keys().is_empty() {
+            return Err(DomainError::ValidationError(
+                "Workflow must have at least one status in the done category".into(),
+            ));
+        }
+
+        for transition in &self.transitions {
+            for key in [&transition.from, &transition.to] {
+                if !keys.contains(key.as_str()) {
+                    return Err(DomainError::ValidationError(format!(
+                        "Transition references unknown status '{}'",
+                        key
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn default_status_name(status: &TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Todo => "To Do",
+        TaskStatus::InProgress => "In Progress",
+        TaskStatus::Review => "Review",
+        TaskStatus::Done => "Done",
+        TaskStatus::Blocked => "Blocked",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(key: &str, category: TaskStatus) -> WorkflowStatus {
+        WorkflowStatus {
+            key: key.to_string(),
+            name: key.to_uppercase(),
+            category,
+        }
+    }
+
+    fn transition(from: &str, to: &str) -> WorkflowTransition {
+        WorkflowTransition {
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+
+    fn custom_workflow() -> ProjectWorkflow {
+        ProjectWorkflow::new(
+            Uuid::new_v4(),
+            vec![
+                status("todo", TaskStatus::Todo),
+                status("inprogress", TaskStatus::InProgress),
+                status("qa", TaskStatus::Review),
+                status("deployed", TaskStatus::Done),
+            ],
+            vec![
+                transition("todo", "inprogress"),
+                transition("inprogress", "qa"),
+                transition("qa", "deployed"),
+                transition("qa", "inprogress"),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_default_workflow_matches_builtin_transitions() {
+        let workflow = ProjectWorkflow::default_for(Uuid::new_v4());
+        assert!(workflow.is_default);
+        assert!(workflow.validate().is_ok());
+        assert!(workflow.can_transition("todo", "inprogress"));
+        assert!(workflow.can_transition("review", "done"));
+        assert!(!workflow.can_transition("todo", "done"));
+        assert_eq!(workflow.initial_status().unwrap().key, "todo");
+    }
+
+    #[test]
+    fn test_custom_workflow_transitions() {
+        let workflow = custom_workflow();
+        assert!(workflow.validate().is_ok());
+        assert!(workflow.can_transition("qa", "deployed"));
+        assert!(!workflow.can_transition("inprogress", "deployed"));
+        assert!(workflow.can_transition("qa", "qa"));
+        assert_eq!(workflow.done_status_keys(), vec!["deployed"]);
+    }
+
+    #[test]
+    fn test_status_for_category() {
+        let workflow = custom_workflow();
+        assert_eq!(workflow.status_for_category(&TaskStatus::Review).unwrap().key, "qa");
+        assert!(workflow.status_for_category(&TaskStatus::Blocked).is_none());
+    }
+
+    #[test]
+    fn test_equivalent_status() {
+        let workflow = custom_workflow();
+        assert_eq!(workflow.equivalent_status("qa", &TaskStatus::Done).unwrap().key, "qa");
+        assert_eq!(workflow.equivalent_status("review", &TaskStatus::Review).unwrap().key, "qa");
+        assert_eq!(workflow.equivalent_status("blocked", &TaskStatus::Blocked).unwrap().key, "todo");
+    }
+
+    #[test]
+    fn test_validate_rejects_workflow_without_done_status() {
+        let workflow = ProjectWorkflow::new(
+            Uuid::new_v4(),
+            vec![status("todo", TaskStatus::Todo)],
+            vec![],
+        );
+        assert!(workflow.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_duplicate_keys() {
+        let mut workflow = custom_workflow();
+        workflow.statuses.push(status("qa", TaskStatus::Review));
+        assert!(workflow.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_transition_status() {
+        let mut workflow = custom_workflow();
+        workflow.transitions.push(transition("qa", "released"));
+        assert!(workflow.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_key() {
+        let mut workflow = custom_workflow();
+        workflow.statuses[0].key = "To Do".to_string();
+        assert!(workflow.validate().is_err());
+    }
+}
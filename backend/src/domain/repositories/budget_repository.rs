@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{CostRate, Expense, LabourEntry};
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait BudgetRepository: Send + Sync {
+    async fn find_rate(&self, id: Uuid) -> Result<Option<CostRate>, DomainError>;
+    /// Find the user's rates, latest effective date first
+    async fn find_rates(&self, user_id: Uuid) -> Result<Vec<CostRate>, DomainError>;
+    /// Find the rates of every user who logged time on the project
+    async fn find_project_rates(&self, project_id: Uuid) -> Result<Vec<CostRate>, DomainError>;
+    async fn create_rate(&self, rate: &CostRate) -> Result<CostRate, DomainError>;
+    async fn delete_rate(&self, id: Uuid) -> Result<(), DomainError>;
+    async fn find_expense(&self, id: Uuid) -> Result<Option<Expense>, DomainError>;
+    /// Find the project's expenses, most recent first
+    async fn find_expenses(&self, project_id: Uuid) -> Result<Vec<Expense>, DomainError>;
+    async fn create_expense(&self, expense: &Expense) -> Result<Expense, DomainError>;
+    async fn update_expense(&self, expense: &Expense) -> Result<Expense, DomainError>;
+    async fn delete_expense(&self, id: Uuid) -> Result<(), DomainError>;
+    /// Hours logged on the project's tasks outside the trash, per user and day
+    async fn find_labour(&self, project_id: Uuid) -> Result<Vec<LabourEntry>, DomainError>;
+}
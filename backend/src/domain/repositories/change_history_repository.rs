@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{ChangeEntityType, ChangeRecord};
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait ChangeHistoryRepository: Send + Sync {
+    async fn record(&self, records: &[ChangeRecord]) -> Result<(), DomainError>;
+    /// Find the entity's changes, newest first
+    async fn find_by_entity(
+        &self,
+        entity_type: ChangeEntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<ChangeRecord>, DomainError>;
+}
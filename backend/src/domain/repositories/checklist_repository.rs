@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::ChecklistItem;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait ChecklistRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ChecklistItem>, DomainError>;
+    /// Find the task's checklist items ordered by position
+    async fn find_by_task(&self, task_id: Uuid) -> Result<Vec<ChecklistItem>, DomainError>;
+    /// Insert the item at its position, shifting later items down
+    async fn create(&self, item: &ChecklistItem) -> Result<ChecklistItem, DomainError>;
+    async fn update(&self, item: &ChecklistItem) -> Result<ChecklistItem, DomainError>;
+    /// Delete the item and close the gap in positions
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    /// Assign positions following the order of `item_ids`
+    async fn reorder(&self, task_id: Uuid, item_ids: &[Uuid]) -> Result<(), DomainError>;
+}
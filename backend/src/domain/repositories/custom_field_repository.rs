@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::CustomField;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait CustomFieldRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<CustomField>, DomainError>;
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<CustomField>, DomainError>;
+    async fn create(&self, field: &CustomField) -> Result<CustomField, DomainError>;
+    async fn update(&self, field: &CustomField) -> Result<CustomField, DomainError>;
+    /// Delete the field and remove its values from every task
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    /// Check if any task has the select option as its value
+    async fn is_option_used(&self, id: Uuid, option: &str) -> Result<bool, DomainError>;
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::shared::DomainError;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "due_item_type", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum DueItemType {
+    Task,
+    Milestone,
+}
+
+/// Unfinished task or milestone with a due date, and who to remind about it
+#[derive(Debug, Clone)]
+pub struct DueItem {
+    pub item_type: DueItemType,
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub title: String,
+    pub due_date: DateTime<Utc>,
+    /// Assignees and the project owner, without duplicates
+    pub recipients: Vec<Uuid>,
+    /// Most urgent reminder already sent for this due date; `0` is the overdue reminder
+    pub last_lead_minutes: Option<i32>,
+}
+
+#[async_trait]
+pub trait DueReminderRepository: Send + Sync {
+    /// Find unfinished items due before `until` whose overdue reminder has not been sent
+    async fn find_due_items(&self, until: DateTime<Utc>) -> Result<Vec<DueItem>, DomainError>;
+    /// Record a sent reminder; `false` when it had already been recorded
+    async fn record(&self, item: &DueItem, lead_minutes: i32) -> Result<bool, DomainError>;
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::Milestone;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait MilestoneRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Milestone>, DomainError>;
+    /// Find the project's milestones, earliest due date first
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<Milestone>, DomainError>;
+    async fn create(&self, milestone: &Milestone) -> Result<Milestone, DomainError>;
+    async fn update(&self, milestone: &Milestone) -> Result<Milestone, DomainError>;
+    /// Delete the milestone; its tasks keep existing without a milestone
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
@@ -1,19 +1,51 @@
 mod activity_log_repository;
 mod attachment_repository;
+mod budget_repository;
+mod change_history_repository;
+mod checklist_repository;
+mod custom_field_repository;
+mod due_reminder_repository;
+mod milestone_repository;
 mod notification_repository;
 mod project_repository;
+mod project_template_repository;
+mod recurring_task_repository;
+mod search_repository;
+mod sla_repository;
+mod sprint_repository;
 mod tag_repository;
+mod task_comment_repository;
+mod task_dependency_repository;
 mod task_repository;
+mod task_template_repository;
+mod task_watcher_repository;
 mod team_repository;
 mod time_log_repository;
 mod user_repository;
+mod workflow_repository;
 
 pub use activity_log_repository::ActivityLogRepository;
 pub use attachment_repository::AttachmentRepository;
+pub use budget_repository::BudgetRepository;
+pub use change_history_repository::ChangeHistoryRepository;
+pub use checklist_repository::ChecklistRepository;
+pub use custom_field_repository::CustomFieldRepository;
+pub use due_reminder_repository::{DueItem, DueItemType, DueReminderRepository};
+pub use milestone_repository::MilestoneRepository;
 pub use notification_repository::NotificationRepository;
 pub use project_repository::ProjectRepository;
+pub use project_template_repository::ProjectTemplateRepository;
+pub use recurring_task_repository::RecurringTaskRepository;
+pub use search_repository::{SearchFilter, SearchRepository};
+pub use sla_repository::{SlaRepository, SlaTrackedTask};
+pub use sprint_repository::SprintRepository;
 pub use tag_repository::TagRepository;
-pub use task_repository::TaskRepository;
+pub use task_comment_repository::TaskCommentRepository;
+pub use task_dependency_repository::TaskDependencyRepository;
+pub use task_repository::{TaskBatch, TaskFilter, TaskRepository};
+pub use task_template_repository::TaskTemplateRepository;
+pub use task_watcher_repository::TaskWatcherRepository;
 pub use team_repository::TeamRepository;
 pub use time_log_repository::TimeLogRepository;
 pub use user_repository::UserRepository;
+pub use workflow_repository::WorkflowRepository;
@@ -1,9 +1,12 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::domain::entities::{Milestone, Project, Task};
+use crate::domain::entities::{Project, ProjectMember, ProjectSkeleton, Task, Trashed};
+use crate::domain::value_objects::ProjectRole;
 use crate::shared::DomainError;
 
+/// Finders skip projects in the trash unless stated otherwise
 #[async_trait]
 pub trait ProjectRepository: Send + Sync {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Project>, DomainError>;
@@ -13,11 +16,28 @@ pub trait ProjectRepository: Send + Sync {
     async fn find_accessible_by_user(&self, user_id: Uuid) -> Result<Vec<Project>, DomainError>;
     /// Check if user can access project (is owner OR member)
     async fn can_user_access(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError>;
-    /// Check if user is owner of project
+    /// Check if user is owner of project; also for trashed projects
     async fn is_owner(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError>;
+    /// Check if user is owner of project or a member with at least `role`
+    async fn has_role(&self, project_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError>;
     async fn create(&self, project: &Project) -> Result<Project, DomainError>;
+    /// Create the project with its members, milestones, tasks, checklists, tags and dependencies
+    /// in one transaction. Members and tags that no longer exist are skipped.
+    async fn create_skeleton(&self, skeleton: &ProjectSkeleton) -> Result<Project, DomainError>;
     async fn update(&self, project: &Project) -> Result<Project, DomainError>;
+    /// Move the project and its tasks to the trash
     async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    /// Find trashed projects, of one owner or of everyone
+    async fn find_trashed(&self, owner_id: Option<Uuid>) -> Result<Vec<Trashed<Project>>, DomainError>;
+    async fn find_trashed_by_id(&self, id: Uuid) -> Result<Option<Trashed<Project>>, DomainError>;
+    /// Take the project and the tasks trashed along with it out of the trash
+    async fn restore(&self, id: Uuid) -> Result<Project, DomainError>;
+    /// Permanently delete projects trashed before the cutoff, returning how many were removed
+    async fn purge(&self, trashed_before: DateTime<Utc>) -> Result<u64, DomainError>;
     async fn find_tasks(&self, project_id: Uuid) -> Result<Vec<Task>, DomainError>;
-    async fn find_milestones(&self, project_id: Uuid) -> Result<Vec<Milestone>, DomainError>;
+    async fn find_members(&self, project_id: Uuid) -> Result<Vec<ProjectMember>, DomainError>;
+    async fn find_member(&self, project_id: Uuid, user_id: Uuid) -> Result<Option<ProjectMember>, DomainError>;
+    async fn add_member(&self, member: &ProjectMember) -> Result<ProjectMember, DomainError>;
+    async fn update_member(&self, member: &ProjectMember) -> Result<ProjectMember, DomainError>;
+    async fn remove_member(&self, project_id: Uuid, user_id: Uuid) -> Result<(), DomainError>;
 }
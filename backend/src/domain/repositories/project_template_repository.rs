@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::ProjectTemplate;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait ProjectTemplateRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ProjectTemplate>, DomainError>;
+    async fn find_all(&self) -> Result<Vec<ProjectTemplate>, DomainError>;
+    async fn create(&self, template: &ProjectTemplate) -> Result<ProjectTemplate, DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::domain::entities::{RecurringTask, RecurringTaskOccurrence};
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait RecurringTaskRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<RecurringTask>, DomainError>;
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<RecurringTask>, DomainError>;
+    async fn find_active(&self) -> Result<Vec<RecurringTask>, DomainError>;
+    async fn create(&self, recurring_task: &RecurringTask) -> Result<RecurringTask, DomainError>;
+    async fn update(&self, recurring_task: &RecurringTask) -> Result<RecurringTask, DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    async fn set_generated_through(&self, id: Uuid, date: NaiveDate) -> Result<(), DomainError>;
+
+    async fn find_occurrence(
+        &self,
+        recurring_task_id: Uuid,
+        occurrence_date: NaiveDate,
+    ) -> Result<Option<RecurringTaskOccurrence>, DomainError>;
+    /// Find stored occurrences between `from` and `to` inclusive
+    async fn find_occurrences(
+        &self,
+        recurring_task_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<RecurringTaskOccurrence>, DomainError>;
+    /// Insert or replace the overrides of an occurrence
+    async fn save_occurrence(&self, occurrence: &RecurringTaskOccurrence) -> Result<RecurringTaskOccurrence, DomainError>;
+    async fn delete_occurrence(&self, recurring_task_id: Uuid, occurrence_date: NaiveDate) -> Result<(), DomainError>;
+    /// Atomically mark an occurrence as generated. Returns None when it was
+    /// skipped or already generated, so concurrent generators never duplicate it.
+    async fn claim_occurrence(
+        &self,
+        recurring_task_id: Uuid,
+        occurrence_date: NaiveDate,
+    ) -> Result<Option<RecurringTaskOccurrence>, DomainError>;
+    /// Undo a claim after the task could not be created
+    async fn release_occurrence(&self, occurrence_id: Uuid) -> Result<(), DomainError>;
+    async fn set_occurrence_task(&self, occurrence_id: Uuid, task_id: Uuid) -> Result<(), DomainError>;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{SearchEntityType, SearchHit};
+use crate::shared::DomainError;
+
+#[derive(Debug)]
+pub struct SearchFilter {
+    pub query: String,
+    pub types: Vec<SearchEntityType>,
+    pub project_id: Option<Uuid>,
+    /// Only search projects this user can access; `None` searches everything
+    pub user_id: Option<Uuid>,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+#[async_trait]
+pub trait SearchRepository: Send + Sync {
+    /// Find matches ordered by relevance, best first
+    async fn search(&self, filter: &SearchFilter) -> Result<Vec<SearchHit>, DomainError>;
+}
@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::entities::{SlaBreach, SlaBreachCount, SlaPolicy, SlaTarget};
+use crate::shared::DomainError;
+
+/// Task under the SLA policy of its project and priority, with the moments its clocks stopped
+#[derive(Debug, Clone)]
+pub struct SlaTrackedTask {
+    pub task_id: Uuid,
+    pub project_id: Uuid,
+    pub title: String,
+    pub created_at: DateTime<Utc>,
+    /// When the task first left Todo
+    pub responded_at: Option<DateTime<Utc>>,
+    /// When the task was last marked done, if it still is
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub policy: SlaPolicy,
+    /// Targets already recorded as breached
+    pub breached: Vec<SlaTarget>,
+    /// The project owner and the leads of the assignee's teams, without duplicates
+    pub escalate_to: Vec<Uuid>,
+}
+
+#[async_trait]
+pub trait SlaRepository: Send + Sync {
+    async fn find_policy(&self, id: Uuid) -> Result<Option<SlaPolicy>, DomainError>;
+    async fn find_policies(&self, project_id: Uuid) -> Result<Vec<SlaPolicy>, DomainError>;
+    async fn create_policy(&self, policy: &SlaPolicy) -> Result<SlaPolicy, DomainError>;
+    async fn update_policy(&self, policy: &SlaPolicy) -> Result<SlaPolicy, DomainError>;
+    async fn delete_policy(&self, id: Uuid) -> Result<(), DomainError>;
+    /// Find tasks under a policy that are unfinished or were finished since `done_since`
+    async fn find_tracked(&self, done_since: DateTime<Utc>) -> Result<Vec<SlaTrackedTask>, DomainError>;
+    /// Find the task if a policy applies to it
+    async fn find_tracked_task(&self, task_id: Uuid) -> Result<Option<SlaTrackedTask>, DomainError>;
+    /// Record a breach; `false` when the target was already recorded as breached
+    async fn record_breach(&self, breach: &SlaBreach) -> Result<bool, DomainError>;
+    async fn find_breaches(&self, task_id: Uuid) -> Result<Vec<SlaBreach>, DomainError>;
+    /// Count breaches of a project that happened within the period, per priority and target
+    async fn count_breaches(
+        &self,
+        project_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<SlaBreachCount>, DomainError>;
+}
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{Sprint, SprintSnapshot};
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait SprintRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Sprint>, DomainError>;
+    /// Find the project's sprints, most recent start date first
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<Sprint>, DomainError>;
+    async fn find_active(&self, project_id: Uuid) -> Result<Option<Sprint>, DomainError>;
+    /// Find the active sprint of every project
+    async fn find_all_active(&self) -> Result<Vec<Sprint>, DomainError>;
+    async fn create(&self, sprint: &Sprint) -> Result<Sprint, DomainError>;
+    async fn update(&self, sprint: &Sprint) -> Result<Sprint, DomainError>;
+    /// Delete the sprint; its tasks return to the backlog
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    /// Close the sprint, record its final snapshot and move its unfinished
+    /// tasks to `carry_over_to`, or to the backlog when `None`
+    async fn close(
+        &self,
+        sprint: &Sprint,
+        snapshot: &SprintSnapshot,
+        unfinished_task_ids: &[Uuid],
+        carry_over_to: Option<Uuid>,
+    ) -> Result<Sprint, DomainError>;
+    /// Insert the snapshot, replacing one taken earlier on the same day
+    async fn save_snapshot(&self, snapshot: &SprintSnapshot) -> Result<(), DomainError>;
+    async fn find_snapshots(&self, sprint_id: Uuid) -> Result<Vec<SprintSnapshot>, DomainError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::{TaskComment, TaskCommentEdit};
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait TaskCommentRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<TaskComment>, DomainError>;
+    /// Find all comments on a task, oldest first
+    async fn find_by_task(&self, task_id: Uuid) -> Result<Vec<TaskComment>, DomainError>;
+    async fn find_edits(&self, comment_id: Uuid) -> Result<Vec<TaskCommentEdit>, DomainError>;
+    async fn create(&self, comment: &TaskComment) -> Result<TaskComment, DomainError>;
+    /// Save the edited comment together with the record of its previous content
+    async fn update(&self, comment: &TaskComment, edit: &TaskCommentEdit) -> Result<TaskComment, DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
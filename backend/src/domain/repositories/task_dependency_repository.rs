@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::TaskDependency;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait TaskDependencyRepository: Send + Sync {
+    /// Dependencies where the task is blocked by another task
+    async fn find_blockers(&self, task_id: Uuid) -> Result<Vec<TaskDependency>, DomainError>;
+    /// Dependencies where the task blocks another task
+    async fn find_blocked(&self, task_id: Uuid) -> Result<Vec<TaskDependency>, DomainError>;
+    /// All dependencies reachable from the task by following "blocks" edges
+    async fn find_downstream(&self, task_id: Uuid) -> Result<Vec<TaskDependency>, DomainError>;
+    /// Dependencies between live tasks of the project
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<TaskDependency>, DomainError>;
+    /// Count blockers of the task that are not in a done status
+    async fn count_unfinished_blockers(&self, task_id: Uuid) -> Result<i64, DomainError>;
+    async fn create(&self, dependency: &TaskDependency) -> Result<TaskDependency, DomainError>;
+    async fn delete(&self, blocking_task_id: Uuid, blocked_task_id: Uuid) -> Result<(), DomainError>;
+}
@@ -1,26 +1,99 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::domain::entities::Task;
-use crate::domain::value_objects::TaskStatus;
+use crate::domain::entities::{Task, Trashed};
+use crate::domain::value_objects::{Priority, ProjectRole, TaskSort, TaskStatus};
 use crate::shared::DomainError;
 
+/// Changes written together by a bulk task operation
+#[derive(Debug, Default)]
+pub struct TaskBatch {
+    pub updated: Vec<Task>,
+    pub deleted: Vec<Uuid>,
+    /// Tags added to every updated task
+    pub add_tag_ids: Vec<Uuid>,
+    /// Tags removed from every updated task
+    pub remove_tag_ids: Vec<Uuid>,
+}
+
+/// Criteria for listing tasks; empty collections and `None` do not filter
+#[derive(Debug, Default)]
+pub struct TaskFilter {
+    /// Only tasks of projects this user can access; `None` lists every project
+    pub accessible_by: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    pub assignee_id: Option<Uuid>,
+    pub unassigned: bool,
+    pub status: Option<TaskStatus>,
+    pub priorities: Vec<Priority>,
+    pub due_from: Option<DateTime<Utc>>,
+    pub due_to: Option<DateTime<Utc>>,
+    /// Only unfinished tasks past their due date
+    pub overdue: bool,
+    /// Tasks carrying any of these tags
+    pub tag_ids: Vec<Uuid>,
+    pub milestone_id: Option<Uuid>,
+    pub sprint_id: Option<Uuid>,
+    /// Only tasks that breached an SLA target
+    pub sla_breached: bool,
+    pub text: Option<String>,
+    /// Custom field values the task must contain; multi-select values as arrays
+    pub custom_fields: HashMap<Uuid, Value>,
+    pub sort: Vec<TaskSort>,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+/// Finders skip tasks in the trash unless stated otherwise
 #[async_trait]
 pub trait TaskRepository: Send + Sync {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Task>, DomainError>;
     async fn find_all(&self) -> Result<Vec<Task>, DomainError>;
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Task>, DomainError>;
     async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<Task>, DomainError>;
+    async fn find_children(&self, parent_id: Uuid) -> Result<Vec<Task>, DomainError>;
+    /// Find the task and all of its descendants
+    async fn find_subtree(&self, root_id: Uuid) -> Result<Vec<Task>, DomainError>;
+    async fn find_by_sprint(&self, sprint_id: Uuid) -> Result<Vec<Task>, DomainError>;
+    async fn find_by_milestone(&self, milestone_id: Uuid) -> Result<Vec<Task>, DomainError>;
+    /// Find the tasks of one board column ordered by rank
+    async fn find_column(&self, project_id: Uuid, workflow_status: &str) -> Result<Vec<Task>, DomainError>;
+    /// Highest rank in a board column
+    async fn find_last_rank(&self, project_id: Uuid, workflow_status: &str) -> Result<Option<f64>, DomainError>;
     async fn find_by_assignee(&self, user_id: Uuid) -> Result<Vec<Task>, DomainError>;
     async fn find_by_status(&self, status: TaskStatus) -> Result<Vec<Task>, DomainError>;
+    /// Find one page of tasks matching the filter, with the total number of matches
+    async fn find_filtered(&self, filter: &TaskFilter) -> Result<(Vec<Task>, i64), DomainError>;
     /// Find tasks from projects user can access (owner OR member)
     async fn find_accessible_by_user(&self, user_id: Uuid) -> Result<Vec<Task>, DomainError>;
     /// Check if user can access task (via project access)
     async fn can_user_access(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError>;
-    /// Check if user is owner of the project containing the task
-    async fn is_project_owner(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError>;
+    /// Check if user owns the project containing the task or is a member with at least `role`
+    async fn has_role(&self, task_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError>;
+    /// Same as `has_role`, for a task in the trash of a live project
+    async fn has_trashed_role(&self, task_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError>;
     /// Check if user can access project (for create task)
     async fn can_access_project(&self, project_id: Uuid, user_id: Uuid) -> Result<bool, DomainError>;
+    /// Check if user owns the project or is a member with at least `role`
+    async fn has_project_role(&self, project_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError>;
     async fn create(&self, task: &Task) -> Result<Task, DomainError>;
     async fn update(&self, task: &Task) -> Result<Task, DomainError>;
+    /// Move the task and its subtasks to the trash
     async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    /// Find the trashed tasks of a project, without subtasks trashed along with their parent
+    async fn find_trashed(&self, project_id: Uuid) -> Result<Vec<Trashed<Task>>, DomainError>;
+    async fn find_trashed_by_id(&self, id: Uuid) -> Result<Option<Trashed<Task>>, DomainError>;
+    /// Take the task and the subtasks trashed along with it out of the trash;
+    /// nothing is restored while the project is in the trash
+    async fn restore(&self, id: Uuid) -> Result<Vec<Task>, DomainError>;
+    /// Permanently delete tasks trashed before the cutoff, returning the ids of every removed task
+    async fn purge(&self, trashed_before: DateTime<Utc>) -> Result<Vec<Uuid>, DomainError>;
+    /// Update the moved task together with the respaced ranks of its neighbours
+    /// and the subtasks carried along to another project
+    async fn move_task(&self, task: &Task, ranks: &[(Uuid, f64)], subtasks: &[Task]) -> Result<Task, DomainError>;
+    /// Apply all changes of the batch in a single transaction
+    async fn apply_batch(&self, batch: &TaskBatch) -> Result<(), DomainError>;
 }
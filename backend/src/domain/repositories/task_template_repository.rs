@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::TaskTemplate;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait TaskTemplateRepository: Send + Sync {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<TaskTemplate>, DomainError>;
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<TaskTemplate>, DomainError>;
+    async fn create(&self, template: &TaskTemplate) -> Result<TaskTemplate, DomainError>;
+    async fn update(&self, template: &TaskTemplate) -> Result<TaskTemplate, DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
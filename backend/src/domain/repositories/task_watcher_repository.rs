@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::TaskWatcher;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait TaskWatcherRepository: Send + Sync {
+    async fn find_by_task(&self, task_id: Uuid) -> Result<Vec<TaskWatcher>, DomainError>;
+    /// Add the watcher; watching a task twice is a no-op
+    async fn watch(&self, watcher: &TaskWatcher) -> Result<(), DomainError>;
+    async fn unwatch(&self, task_id: Uuid, user_id: Uuid) -> Result<(), DomainError>;
+}
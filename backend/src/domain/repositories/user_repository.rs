@@ -9,6 +9,8 @@ pub trait UserRepository: Send + Sync {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, DomainError>;
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, DomainError>;
     async fn find_all(&self) -> Result<Vec<User>, DomainError>;
+    /// Find users who can access the project (owner, members and admins)
+    async fn find_with_project_access(&self, project_id: Uuid) -> Result<Vec<User>, DomainError>;
     async fn create(&self, user: &User) -> Result<User, DomainError>;
     async fn update(&self, user: &User) -> Result<User, DomainError>;
     async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
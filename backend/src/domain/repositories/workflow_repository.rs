@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::ProjectWorkflow;
+use crate::shared::DomainError;
+
+#[async_trait]
+pub trait WorkflowRepository: Send + Sync {
+    /// Find the custom workflow of a project (None if the project uses the default)
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Option<ProjectWorkflow>, DomainError>;
+    /// Create or replace the custom workflow of a project
+    async fn save(&self, workflow: &ProjectWorkflow) -> Result<ProjectWorkflow, DomainError>;
+    async fn delete_by_project(&self, project_id: Uuid) -> Result<(), DomainError>;
+    /// Distinct workflow status keys currently used by the project's tasks
+    async fn find_status_keys_in_use(&self, project_id: Uuid) -> Result<Vec<String>, DomainError>;
+}
@@ -0,0 +1,249 @@
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use crate::domain::entities::{CostRate, Expense, LabourEntry, Project};
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UserCost {
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub hours: f32,
+    pub cost: Decimal,
+}
+
+/// Labour cost of a team; users in several teams count toward each of them
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TeamCost {
+    /// `None` for users outside any team
+    pub team_id: Option<Uuid>,
+    pub team_name: Option<String>,
+    pub hours: f32,
+    pub cost: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MonthlyCost {
+    /// `YYYY-MM`
+    pub month: String,
+    pub labour_cost: Decimal,
+    pub expense_cost: Decimal,
+    pub total: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BudgetReport {
+    pub project_id: Uuid,
+    pub budget: Option<Decimal>,
+    pub labour_cost: Decimal,
+    pub expense_cost: Decimal,
+    pub spent: Decimal,
+    pub remaining: Option<Decimal>,
+    /// Average spend per day since the project started
+    pub daily_burn_rate: Decimal,
+    /// Spend so far plus the burn rate until the end date; `None` without an end date
+    pub forecast_at_completion: Option<Decimal>,
+    /// Hours logged while the user had no cost rate; they add nothing to the spend
+    pub unrated_hours: f32,
+    pub by_user: Vec<UserCost>,
+    pub by_team: Vec<TeamCost>,
+    pub by_month: Vec<MonthlyCost>,
+}
+
+pub struct Budget;
+
+impl Budget {
+    /// Cost of each labour entry at the rate in effect on its date
+    fn labour_costs<'a>(
+        labour: &'a [LabourEntry],
+        rates: &'a [CostRate],
+    ) -> impl Iterator<Item = (&'a LabourEntry, Option<Decimal>)> {
+        labour.iter().map(|entry| {
+            let cost = CostRate::effective_on(rates, entry.user_id, entry.date)
+                .map(|rate| (rate.hourly_rate * Self::hours(entry.hours)).round_dp(2));
+            (entry, cost)
+        })
+    }
+
+    /// Labour and expenses charged so far
+    pub fn spent(labour: &[LabourEntry], rates: &[CostRate], expenses: &[Expense]) -> Decimal {
+        let labour_cost: Decimal = Self::labour_costs(labour, rates).filter_map(|(_, cost)| cost).sum();
+        labour_cost + expenses.iter().map(|expense| expense.amount).sum::<Decimal>()
+    }
+
+    pub fn report(
+        project: &Project,
+        labour: &[LabourEntry],
+        rates: &[CostRate],
+        expenses: &[Expense],
+        today: NaiveDate,
+    ) -> BudgetReport {
+        let mut labour_cost = Decimal::ZERO;
+        let mut unrated_hours = 0.0;
+        let mut by_user: BTreeMap<Uuid, UserCost> = BTreeMap::new();
+        let mut by_team: BTreeMap<Option<Uuid>, TeamCost> = BTreeMap::new();
+        let mut by_month: BTreeMap<String, MonthlyCost> = BTreeMap::new();
+
+        for (entry, cost) in Self::labour_costs(labour, rates) {
+            let Some(cost) = cost else {
+                unrated_hours += entry.hours;
+                continue;
+            };
+            labour_cost += cost;
+
+            let user = by_user.entry(entry.user_id).or_insert_with(|| UserCost {
+                user_id: entry.user_id,
+                user_name: entry.user_name.clone(),
+                hours: 0.0,
+                cost: Decimal::ZERO,
+            });
+            user.hours += entry.hours;
+            user.cost += cost;
+
+            let teams: Vec<(Option<Uuid>, Option<String>)> = if entry.teams.is_empty() {
+                vec![(None, None)]
+            } else {
+                entry.teams.iter().map(|(id, name)| (Some(*id), Some(name.clone()))).collect()
+            };
+            for (team_id, team_name) in teams {
+                let team = by_team.entry(team_id).or_insert_with(|| TeamCost {
+                    team_id,
+                    team_name,
+                    hours: 0.0,
+                    cost: Decimal::ZERO,
+                });
+                team.hours += entry.hours;
+                team.cost += cost;
+            }
+
+            Self::month(&mut by_month, entry.date).labour_cost += cost;
+        }
+
+        let expense_cost: Decimal = expenses.iter().map(|expense| expense.amount).sum();
+        for expense in expenses {
+            Self::month(&mut by_month, expense.incurred_on).expense_cost += expense.amount;
+        }
+        for month in by_month.values_mut() {
+            month.total = month.labour_cost + month.expense_cost;
+        }
+
+        let spent = labour_cost + expense_cost;
+        let started = project
+            .start_date
+            .map(|start| start.date_naive())
+            .or_else(|| labour.iter().map(|e| e.date).chain(expenses.iter().map(|e| e.incurred_on)).min())
+            .unwrap_or(today);
+        let elapsed_days = ((today - started).num_days() + 1).max(1);
+        let daily_burn_rate = (spent / Decimal::from(elapsed_days)).round_dp(2);
+        let forecast_at_completion = project.end_date.map(|end| {
+            if project.is_completed() {
+                return spent;
+            }
+            let days_left = (end.date_naive() - today).num_days().max(0);
+            spent + daily_burn_rate * Decimal::from(days_left)
+        });
+
+        let mut by_user: Vec<UserCost> = by_user.into_values().collect();
+        by_user.sort_by(|a, b| b.cost.cmp(&a.cost).then_with(|| a.user_name.cmp(&b.user_name)));
+        let mut by_team: Vec<TeamCost> = by_team.into_values().collect();
+        by_team.sort_by(|a, b| b.cost.cmp(&a.cost).then_with(|| a.team_name.cmp(&b.team_name)));
+
+        BudgetReport {
+            project_id: project.id,
+            budget: project.budget,
+            labour_cost,
+            expense_cost,
+            spent,
+            remaining: project.budget.map(|budget| budget - spent),
+            daily_burn_rate,
+            forecast_at_completion,
+            unrated_hours,
+            by_user,
+            by_team,
+            by_month: by_month.into_values().collect(),
+        }
+    }
+
+    fn month(months: &mut BTreeMap<String, MonthlyCost>, date: NaiveDate) -> &mut MonthlyCost {
+        let month = format!("{:04}-{:02}", date.year(), date.month());
+        months.entry(month.clone()).or_insert_with(|| MonthlyCost {
+            month,
+            labour_cost: Decimal::ZERO,
+            expense_cost: Decimal::ZERO,
+            total: Decimal::ZERO,
+        })
+    }
+
+    fn hours(hours: f32) -> Decimal {
+        Decimal::from_f32(hours).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    fn project() -> Project {
+        let at = |month, day| Some(Utc.with_ymd_and_hms(2024, month, day, 0, 0, 0).unwrap());
+        Project::new("Portal".to_string(), None, Uuid::nil(), None, None, at(3, 1), at(4, 30), Some(Decimal::from(10_000)))
+    }
+
+    fn entry(user_id: Uuid, teams: Vec<(Uuid, String)>, date: NaiveDate, hours: f32) -> LabourEntry {
+        LabourEntry {
+            user_id,
+            user_name: "Dev".to_string(),
+            teams,
+            date,
+            hours,
+        }
+    }
+
+    #[test]
+    fn test_report() {
+        let (alice, bob, team) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let rates = vec![
+            CostRate::new(alice, Decimal::from(50), date(3, 1)),
+            CostRate::new(alice, Decimal::from(60), date(4, 1)),
+        ];
+        let labour = vec![
+            entry(alice, vec![(team, "Web".to_string())], date(3, 10), 10.0),
+            entry(alice, vec![(team, "Web".to_string())], date(4, 2), 5.0),
+            entry(bob, vec![], date(4, 3), 8.0),
+        ];
+        let expenses = vec![Expense::new(Uuid::nil(), "Licenses".to_string(), None, Decimal::from(200), date(4, 5), Uuid::nil())];
+
+        let report = Budget::report(&project(), &labour, &rates, &expenses, date(4, 9));
+
+        assert_eq!(report.labour_cost, Decimal::from(800));
+        assert_eq!(report.spent, Decimal::from(1000));
+        assert_eq!(report.remaining, Some(Decimal::from(9000)));
+        assert_eq!(report.unrated_hours, 8.0);
+        // 1000 over 40 days since March 1st, for 21 more days
+        assert_eq!(report.daily_burn_rate, Decimal::from(25));
+        assert_eq!(report.forecast_at_completion, Some(Decimal::from(1525)));
+        assert_eq!(report.by_user.len(), 1);
+        assert_eq!(report.by_team[0].team_id, Some(team));
+        let months: Vec<(&str, Decimal)> = report.by_month.iter().map(|m| (m.month.as_str(), m.total)).collect();
+        assert_eq!(months, vec![("2024-03", Decimal::from(500)), ("2024-04", Decimal::from(500))]);
+        assert_eq!(Budget::spent(&labour, &rates, &expenses), report.spent);
+    }
+
+    #[test]
+    fn test_users_without_team() {
+        let user = Uuid::new_v4();
+        let rates = vec![CostRate::new(user, Decimal::from(40), date(1, 1))];
+        let labour = vec![entry(user, vec![], date(3, 4), 2.5)];
+
+        let report = Budget::report(&project(), &labour, &rates, &[], date(3, 4));
+        assert_eq!(report.by_team[0].team_id, None);
+        assert_eq!(report.by_team[0].cost, Decimal::from(100));
+    }
+}
@@ -0,0 +1,131 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::entities::{Sprint, SprintSnapshot};
+
+/// Story points of a sprint at the end of one day
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BurndownPoint {
+    pub date: NaiveDate,
+    /// Scope of the sprint, for burnup charts
+    pub total_points: i32,
+    pub completed_points: i32,
+    pub remaining_points: i32,
+    /// Remaining points of a steady burn from the committed points to zero on the end date
+    pub ideal_remaining: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SprintBurndown {
+    pub sprint_id: Uuid,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub committed_points: Option<i32>,
+    pub points: Vec<BurndownPoint>,
+}
+
+pub struct Burndown;
+
+impl Burndown {
+    /// Daily series from the sprint start up to its close, or `today` while it runs.
+    /// Days without a snapshot repeat the previous day; days before the first snapshot are left out.
+    pub fn build(sprint: &Sprint, snapshots: &[SprintSnapshot], today: NaiveDate) -> SprintBurndown {
+        let mut snapshots: Vec<&SprintSnapshot> = snapshots.iter().collect();
+        snapshots.sort_by_key(|s| s.snapshot_date);
+
+        let last_day = sprint.closed_at.map_or(today, |closed_at| closed_at.date_naive());
+        let baseline = sprint
+            .committed_points
+            .or_else(|| snapshots.first().map(|s| s.total_points))
+            .unwrap_or(0) as f64;
+        let duration = (sprint.end_date - sprint.start_date).num_days();
+
+        let mut points = Vec::new();
+        let mut next = 0;
+        let mut current: Option<&SprintSnapshot> = None;
+        for date in sprint.start_date.iter_days().take_while(|date| *date <= last_day) {
+            while next < snapshots.len() && snapshots[next].snapshot_date <= date {
+                current = Some(snapshots[next]);
+                next += 1;
+            }
+            let Some(snapshot) = current else {
+                continue;
+            };
+
+            let left = (sprint.end_date - date).num_days().max(0);
+            let ideal_remaining = if duration > 0 {
+                baseline * left as f64 / duration as f64
+            } else {
+                0.0
+            };
+            points.push(BurndownPoint {
+                date,
+                total_points: snapshot.total_points,
+                completed_points: snapshot.completed_points,
+                remaining_points: snapshot.remaining_points(),
+                ideal_remaining,
+            });
+        }
+
+        SprintBurndown {
+            sprint_id: sprint.id,
+            start_date: sprint.start_date,
+            end_date: sprint.end_date,
+            committed_points: sprint.committed_points,
+            points,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, day).unwrap()
+    }
+
+    fn snapshot(day: u32, total: i32, completed: i32) -> SprintSnapshot {
+        SprintSnapshot {
+            sprint_id: Uuid::nil(),
+            snapshot_date: date(day),
+            total_points: total,
+            completed_points: completed,
+            total_tasks: 0,
+            completed_tasks: 0,
+            captured_at: Utc::now(),
+        }
+    }
+
+    fn sprint() -> Sprint {
+        let mut sprint = Sprint::new(Uuid::nil(), "Sprint".to_string(), None, date(1), date(5));
+        sprint.start(20).unwrap();
+        sprint
+    }
+
+    #[test]
+    fn test_gaps_repeat_previous_day() {
+        let burndown = Burndown::build(&sprint(), &[snapshot(3, 22, 5), snapshot(1, 20, 0)], date(4));
+        let remaining: Vec<i32> = burndown.points.iter().map(|p| p.remaining_points).collect();
+        assert_eq!(remaining, vec![20, 20, 17, 17]);
+        assert_eq!(burndown.points[2].total_points, 22);
+        assert_eq!(burndown.points.last().unwrap().date, date(4));
+    }
+
+    #[test]
+    fn test_ideal_line_burns_committed_points() {
+        let burndown = Burndown::build(&sprint(), &[snapshot(1, 20, 0)], date(10));
+        let ideal: Vec<f64> = burndown.points.iter().map(|p| p.ideal_remaining).collect();
+        assert_eq!(ideal[..5], [20.0, 15.0, 10.0, 5.0, 0.0]);
+        assert_eq!(burndown.points.len(), 10);
+    }
+
+    #[test]
+    fn test_days_before_first_snapshot_are_skipped() {
+        let burndown = Burndown::build(&sprint(), &[snapshot(2, 20, 0)], date(2));
+        assert_eq!(burndown.points.len(), 1);
+        assert!(Burndown::build(&sprint(), &[], date(3)).points.is_empty());
+    }
+}
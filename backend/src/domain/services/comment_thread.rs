@@ -0,0 +1,91 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::domain::entities::TaskComment;
+
+/// A comment with its replies nested beneath it
+#[derive(Debug, Clone, Serialize)]
+pub struct CommentThread {
+    #[serde(flatten)]
+    pub comment: TaskComment,
+    pub replies: Vec<CommentThread>,
+}
+
+impl CommentThread {
+    /// Nest a flat list of comments into threads, keeping the input order at every level.
+    /// Replies whose parent is missing are treated as top-level comments.
+    pub fn build(comments: Vec<TaskComment>) -> Vec<CommentThread> {
+        let ids: Vec<Uuid> = comments.iter().map(|c| c.id).collect();
+        let mut roots = Vec::new();
+        let mut replies_by_parent: HashMap<Uuid, Vec<TaskComment>> = HashMap::new();
+        for comment in comments {
+            match comment.parent_id {
+                Some(parent_id) if ids.contains(&parent_id) => {
+                    replies_by_parent.entry(parent_id).or_default().push(comment)
+                }
+                _ => roots.push(comment),
+            }
+        }
+
+        roots
+            .into_iter()
+            .map(|comment| Self::build_node(comment, &mut replies_by_parent))
+            .collect()
+    }
+
+    fn build_node(comment: TaskComment, replies_by_parent: &mut HashMap<Uuid, Vec<TaskComment>>) -> CommentThread {
+        let replies = replies_by_parent
+            .remove(&comment.id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|reply| Self::build_node(reply, replies_by_parent))
+            .collect();
+
+        CommentThread { comment, replies }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comment(parent_id: Option<Uuid>) -> TaskComment {
+        TaskComment::new(Uuid::nil(), Uuid::nil(), parent_id, "Comment".to_string())
+    }
+
+    #[test]
+    fn test_build_nests_replies() {
+        let first = comment(None);
+        let reply = comment(Some(first.id));
+        let nested_reply = comment(Some(reply.id));
+        let second = comment(None);
+        let (first_id, reply_id, second_id) = (first.id, reply.id, second.id);
+
+        let threads = CommentThread::build(vec![first, reply, nested_reply, second]);
+
+        assert_eq!(threads.len(), 2);
+        assert_eq!(threads[0].comment.id, first_id);
+        assert_eq!(threads[0].replies[0].comment.id, reply_id);
+        assert_eq!(threads[0].replies[0].replies.len(), 1);
+        assert_eq!(threads[1].comment.id, second_id);
+    }
+
+    #[test]
+    fn test_orphan_reply_becomes_top_level() {
+        let orphan = comment(Some(Uuid::new_v4()));
+        let threads = CommentThread::build(vec![orphan]);
+        assert_eq!(threads.len(), 1);
+        assert!(threads[0].replies.is_empty());
+    }
+
+    #[test]
+    fn test_edit_keeps_previous_content() {
+        let mut c = comment(None);
+        let edit = c.edit("Updated".to_string());
+        assert_eq!(edit.previous_content, "Comment");
+        assert_eq!(edit.comment_id, c.id);
+        assert_eq!(c.content, "Updated");
+        assert!(c.edited_at.is_some());
+    }
+}
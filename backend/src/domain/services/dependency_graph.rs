@@ -0,0 +1,77 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use uuid::Uuid;
+
+/// Directed graph of "blocks" edges between tasks (blocking -> blocked)
+pub struct DependencyGraph {
+    adjacency: HashMap<Uuid, Vec<Uuid>>,
+}
+
+impl DependencyGraph {
+    pub fn new(edges: impl IntoIterator<Item = (Uuid, Uuid)>) -> Self {
+        let mut adjacency: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for (blocking, blocked) in edges {
+            adjacency.entry(blocking).or_default().push(blocked);
+        }
+        Self { adjacency }
+    }
+
+    /// Check if `to` can be reached from `from` by following edges
+    pub fn has_path(&self, from: Uuid, to: Uuid) -> bool {
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(node) = queue.pop_front() {
+            if node == to {
+                return true;
+            }
+            if !visited.insert(node) {
+                continue;
+            }
+            if let Some(next) = self.adjacency.get(&node) {
+                queue.extend(next.iter().copied());
+            }
+        }
+
+        false
+    }
+
+    /// Adding `blocking -> blocked` creates a cycle if `blocked` already reaches `blocking`
+    pub fn would_create_cycle(&self, blocking: Uuid, blocked: Uuid) -> bool {
+        blocking == blocked || self.has_path(blocked, blocking)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_dependency_is_a_cycle() {
+        let a = Uuid::new_v4();
+        let graph = DependencyGraph::new(vec![]);
+        assert!(graph.would_create_cycle(a, a));
+    }
+
+    #[test]
+    fn test_direct_cycle_detected() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let graph = DependencyGraph::new(vec![(a, b)]);
+        assert!(graph.would_create_cycle(b, a));
+    }
+
+    #[test]
+    fn test_transitive_cycle_detected() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let graph = DependencyGraph::new(vec![(a, b), (b, c)]);
+        assert!(graph.would_create_cycle(c, a));
+    }
+
+    #[test]
+    fn test_parallel_edges_are_not_a_cycle() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let graph = DependencyGraph::new(vec![(a, b), (b, c)]);
+        assert!(!graph.would_create_cycle(a, c));
+        assert!(graph.has_path(a, c));
+        assert!(!graph.has_path(c, a));
+    }
+}
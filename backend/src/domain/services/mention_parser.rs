@@ -0,0 +1,120 @@
+use crate::domain::entities::User;
+
+/// Parses `@name` / `@email` mentions out of free text
+pub struct MentionParser;
+
+impl MentionParser {
+    /// Extract mention tokens, lowercased and deduplicated, in order of appearance.
+    /// An `@` only starts a mention at the beginning of the text or after a
+    /// non-token character, so plain email addresses are not picked up.
+    pub fn extract(text: &str) -> Vec<String> {
+        let mut tokens: Vec<String> = Vec::new();
+        let mut chars = text.char_indices().peekable();
+        let mut previous: Option<char> = None;
+
+        while let Some((_, c)) = chars.next() {
+            if c == '@' && !previous.is_some_and(is_token_char) {
+                let mut token = String::new();
+                while let Some(&(_, next)) = chars.peek() {
+                    if !is_token_char(next) {
+                        break;
+                    }
+                    token.push(next);
+                    chars.next();
+                }
+                previous = token.chars().last().or(Some(c));
+
+                let token = token
+                    .trim_end_matches(|ch: char| !ch.is_alphanumeric())
+                    .to_lowercase();
+                if !token.is_empty() && !tokens.contains(&token) {
+                    tokens.push(token);
+                }
+                continue;
+            }
+            previous = Some(c);
+        }
+
+        tokens
+    }
+
+    /// Users referenced by the mentions in `text`. A token matches a user's email,
+    /// or their name ignoring case, spaces and punctuation (`@janedoe`, `@jane.doe`).
+    /// Names shared by several candidates are ambiguous and resolve to nobody.
+    pub fn resolve<'a>(text: &str, candidates: &'a [User]) -> Vec<&'a User> {
+        let mut resolved: Vec<&User> = Vec::new();
+
+        for token in Self::extract(text) {
+            let by_email = candidates.iter().find(|u| u.email.as_str() == token);
+            let user = by_email.or_else(|| {
+                let key = normalize_name(&token);
+                let mut matches = candidates.iter().filter(|u| normalize_name(&u.name) == key);
+                match (matches.next(), matches.next()) {
+                    (Some(user), None) if !key.is_empty() => Some(user),
+                    _ => None,
+                }
+            });
+
+            if let Some(user) = user {
+                if !resolved.iter().any(|r| r.id == user.id) {
+                    resolved.push(user);
+                }
+            }
+        }
+
+        resolved
+    }
+}
+
+fn is_token_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '.' | '_' | '-' | '+' | '@')
+}
+
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::Email;
+
+    fn user(name: &str, email: &str) -> User {
+        User::new(Email::new(email).unwrap(), String::new(), name.to_string(), None)
+    }
+
+    #[test]
+    fn test_extract_names_and_emails() {
+        let tokens = MentionParser::extract("Hi @jane, please sync with @Bob@Example.com.");
+        assert_eq!(tokens, vec!["jane", "bob@example.com"]);
+    }
+
+    #[test]
+    fn test_extract_ignores_plain_emails_and_duplicates() {
+        let tokens = MentionParser::extract("Mail john@example.com or ping @john and @john again");
+        assert_eq!(tokens, vec!["john"]);
+    }
+
+    #[test]
+    fn test_extract_ignores_lone_at_sign() {
+        assert!(MentionParser::extract("meet @ 10am").is_empty());
+    }
+
+    #[test]
+    fn test_resolve_by_email_and_name() {
+        let users = vec![user("Jane Doe", "jane@example.com"), user("Bob", "bob@example.com")];
+        let resolved = MentionParser::resolve("@jane.doe and @bob@example.com, not @alice", &users);
+        let names: Vec<&str> = resolved.iter().map(|u| u.name.as_str()).collect();
+        assert_eq!(names, vec!["Jane Doe", "Bob"]);
+    }
+
+    #[test]
+    fn test_resolve_skips_ambiguous_names() {
+        let users = vec![user("Sam", "sam1@example.com"), user("Sam", "sam2@example.com")];
+        assert!(MentionParser::resolve("@sam", &users).is_empty());
+        assert_eq!(MentionParser::resolve("@sam2@example.com", &users).len(), 1);
+    }
+}
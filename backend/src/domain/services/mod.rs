@@ -1,3 +1,25 @@
 mod auth_service;
+mod budget_report;
+mod burndown;
+mod comment_thread;
+mod dependency_graph;
+mod mention_parser;
+mod project_health;
+mod reminder_schedule;
+mod schedule;
+mod sla_clock;
+mod task_rank;
+mod task_tree;
 
 pub use auth_service::AuthService;
+pub use budget_report::{Budget, BudgetReport, MonthlyCost, TeamCost, UserCost};
+pub use burndown::{Burndown, BurndownPoint, SprintBurndown};
+pub use comment_thread::CommentThread;
+pub use dependency_graph::DependencyGraph;
+pub use mention_parser::MentionParser;
+pub use project_health::{HealthFactor, HealthFactorKind, HealthPolicy, HealthStatus, HealthThreshold, ProjectHealth};
+pub use reminder_schedule::{DueReminder, ReminderSchedule};
+pub use schedule::{MilestoneForecast, ProjectSchedule, Schedule, ScheduledTask};
+pub use sla_clock::{SlaClock, SlaTimer};
+pub use task_rank::{RankPlacement, TaskRank};
+pub use task_tree::{TaskTree, TaskTreeNode};
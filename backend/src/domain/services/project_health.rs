@@ -0,0 +1,307 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::entities::{Milestone, Project, Task};
+use crate::shared::DomainError;
+
+/// Red/amber/green rating, ordered from best to worst
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Green,
+    Amber,
+    Red,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthFactorKind {
+    /// Share of open tasks past their due date
+    OverdueTasks,
+    /// Number of incomplete milestones past their due date
+    OverdueMilestones,
+    /// Share of open tasks that are blocked
+    BlockedTasks,
+    /// Days the open work runs past the project end date
+    ScheduleSlip,
+    /// Share of the budget spent
+    BudgetConsumption,
+}
+
+/// Values from which a factor turns amber and red
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct HealthThreshold {
+    pub amber: f64,
+    pub red: f64,
+}
+
+impl HealthThreshold {
+    pub fn new(amber: f64, red: f64) -> Self {
+        Self { amber, red }
+    }
+
+    /// Parse an `amber,red` pair such as `0.1,0.25`
+    pub fn parse(spec: &str) -> Result<Self, DomainError> {
+        let invalid = || DomainError::ValidationError(format!("Invalid health threshold '{}'", spec));
+        let (amber, red) = spec.split_once(',').ok_or_else(invalid)?;
+        let amber: f64 = amber.trim().parse().map_err(|_| invalid())?;
+        let red: f64 = red.trim().parse().map_err(|_| invalid())?;
+        if !(0.0..=red).contains(&amber) {
+            return Err(invalid());
+        }
+        Ok(Self::new(amber, red))
+    }
+
+    pub fn rate(&self, value: f64) -> HealthStatus {
+        if value >= self.red {
+            HealthStatus::Red
+        } else if value >= self.amber {
+            HealthStatus::Amber
+        } else {
+            HealthStatus::Green
+        }
+    }
+}
+
+/// One factor of a project's health with the thresholds it was rated against
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HealthFactor {
+    pub factor: HealthFactorKind,
+    pub status: HealthStatus,
+    pub value: f64,
+    pub threshold: HealthThreshold,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectHealth {
+    pub project_id: Uuid,
+    pub project_name: String,
+    /// Worst status of the factors
+    pub status: HealthStatus,
+    /// Factors that could be evaluated; schedule and budget need an end date and a budget
+    pub factors: Vec<HealthFactor>,
+    pub evaluated_at: DateTime<Utc>,
+}
+
+/// Thresholds of every health factor
+#[derive(Debug, Clone)]
+pub struct HealthPolicy {
+    pub overdue_tasks: HealthThreshold,
+    pub overdue_milestones: HealthThreshold,
+    pub blocked_tasks: HealthThreshold,
+    pub schedule_slip_days: HealthThreshold,
+    pub budget_consumption: HealthThreshold,
+}
+
+impl Default for HealthPolicy {
+    fn default() -> Self {
+        Self {
+            overdue_tasks: HealthThreshold::new(0.1, 0.25),
+            overdue_milestones: HealthThreshold::new(1.0, 2.0),
+            blocked_tasks: HealthThreshold::new(0.15, 0.3),
+            schedule_slip_days: HealthThreshold::new(3.0, 14.0),
+            budget_consumption: HealthThreshold::new(0.9, 1.0),
+        }
+    }
+}
+
+impl HealthPolicy {
+    /// Rate the project from its live tasks and milestones; `spent` is the
+    /// amount charged against the budget, when known
+    pub fn evaluate(
+        &self,
+        project: &Project,
+        tasks: &[Task],
+        milestones: &[Milestone],
+        spent: Option<Decimal>,
+        now: DateTime<Utc>,
+    ) -> ProjectHealth {
+        let open: Vec<&Task> = tasks.iter().filter(|task| !task.is_done()).collect();
+        let share = |count: usize| if open.is_empty() { 0.0 } else { count as f64 / open.len() as f64 };
+        let mut factors = Vec::new();
+
+        let overdue = open.iter().filter(|task| task.due_date.is_some_and(|due| due < now)).count();
+        factors.push(Self::factor(
+            HealthFactorKind::OverdueTasks,
+            self.overdue_tasks,
+            share(overdue),
+            format!("{} of {} open tasks are overdue", overdue, open.len()),
+        ));
+
+        let late_milestones: Vec<&str> = milestones
+            .iter()
+            .filter(|milestone| milestone.is_overdue())
+            .map(|milestone| milestone.name.as_str())
+            .collect();
+        factors.push(Self::factor(
+            HealthFactorKind::OverdueMilestones,
+            self.overdue_milestones,
+            late_milestones.len() as f64,
+            if late_milestones.is_empty() {
+                "No overdue milestones".to_string()
+            } else {
+                format!("Overdue milestones: {}", late_milestones.join(", "))
+            },
+        ));
+
+        let blocked = open.iter().filter(|task| task.is_blocked()).count();
+        factors.push(Self::factor(
+            HealthFactorKind::BlockedTasks,
+            self.blocked_tasks,
+            share(blocked),
+            format!("{} of {} open tasks are blocked", blocked, open.len()),
+        ));
+
+        if let Some(end_date) = project.end_date.filter(|_| !project.is_completed()) {
+            let slip_days = Self::projected_finish(&open, milestones, now)
+                .map_or(0, |finish| (finish - end_date).num_days().max(0));
+            factors.push(Self::factor(
+                HealthFactorKind::ScheduleSlip,
+                self.schedule_slip_days,
+                slip_days as f64,
+                format!("Open work runs {} days past the end date", slip_days),
+            ));
+        }
+
+        if let (Some(budget), Some(spent)) = (project.budget.filter(|b| *b > Decimal::ZERO), spent) {
+            let consumed = (spent / budget).to_f64().unwrap_or_default();
+            factors.push(Self::factor(
+                HealthFactorKind::BudgetConsumption,
+                self.budget_consumption,
+                consumed,
+                format!("{} of {} budget spent", spent.round_dp(2), budget.round_dp(2)),
+            ));
+        }
+
+        ProjectHealth {
+            project_id: project.id,
+            project_name: project.name.clone(),
+            status: factors.iter().map(|f| f.status).max().unwrap_or(HealthStatus::Green),
+            factors,
+            evaluated_at: now,
+        }
+    }
+
+    fn factor(factor: HealthFactorKind, threshold: HealthThreshold, value: f64, detail: String) -> HealthFactor {
+        HealthFactor {
+            factor,
+            status: threshold.rate(value),
+            value,
+            threshold,
+            detail,
+        }
+    }
+
+    /// Latest due date of the open work, but no earlier than `now` while work is open
+    fn projected_finish(open: &[&Task], milestones: &[Milestone], now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let due_dates = open
+            .iter()
+            .filter_map(|task| task.due_date)
+            .chain(milestones.iter().filter(|m| !m.completed).filter_map(|m| m.due_date));
+        let has_open_work = !open.is_empty() || milestones.iter().any(|m| !m.completed);
+        due_dates.chain(has_open_work.then_some(now)).max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::TaskStatus;
+    use chrono::Duration;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2030-03-04T12:00:00Z").unwrap().with_timezone(&Utc)
+    }
+
+    fn project(end_in_days: Option<i64>, budget: Option<i64>) -> Project {
+        Project::new(
+            "Portal".to_string(),
+            None,
+            Uuid::nil(),
+            None,
+            None,
+            None,
+            end_in_days.map(|days| now() + Duration::days(days)),
+            budget.map(Decimal::from),
+        )
+    }
+
+    fn task(status: TaskStatus, due_in_days: Option<i64>) -> Task {
+        let due_date = due_in_days.map(|days| now() + Duration::days(days));
+        let mut task = Task::new(Uuid::nil(), "Task".to_string(), None, None, None, due_date, None);
+        task.update_status(status);
+        task
+    }
+
+    fn factor(health: &ProjectHealth, kind: HealthFactorKind) -> Option<&HealthFactor> {
+        health.factors.iter().find(|f| f.factor == kind)
+    }
+
+    #[test]
+    fn test_parse_threshold() {
+        assert_eq!(HealthThreshold::parse("0.1, 0.25").unwrap(), HealthThreshold::new(0.1, 0.25));
+        assert!(HealthThreshold::parse("0.3,0.2").is_err());
+        assert!(HealthThreshold::parse("0.3").is_err());
+        assert!(HealthThreshold::parse("a,b").is_err());
+    }
+
+    #[test]
+    fn test_rate() {
+        let threshold = HealthThreshold::new(0.1, 0.25);
+        assert_eq!(threshold.rate(0.05), HealthStatus::Green);
+        assert_eq!(threshold.rate(0.1), HealthStatus::Amber);
+        assert_eq!(threshold.rate(0.3), HealthStatus::Red);
+    }
+
+    #[test]
+    fn test_healthy_project_is_green() {
+        let tasks = vec![task(TaskStatus::InProgress, Some(2)), task(TaskStatus::Done, Some(-5))];
+        let health = HealthPolicy::default().evaluate(&project(Some(10), None), &tasks, &[], None, now());
+
+        assert_eq!(health.status, HealthStatus::Green);
+        assert!(factor(&health, HealthFactorKind::ScheduleSlip).is_some());
+        assert!(factor(&health, HealthFactorKind::BudgetConsumption).is_none());
+    }
+
+    #[test]
+    fn test_worst_factor_sets_status() {
+        let tasks = vec![
+            task(TaskStatus::Blocked, Some(3)),
+            task(TaskStatus::Todo, Some(1)),
+            task(TaskStatus::Todo, Some(1)),
+            task(TaskStatus::Todo, Some(1)),
+            task(TaskStatus::Todo, Some(-1)),
+        ];
+        let health = HealthPolicy::default().evaluate(&project(None, None), &tasks, &[], None, now());
+
+        assert_eq!(factor(&health, HealthFactorKind::OverdueTasks).unwrap().status, HealthStatus::Amber);
+        assert_eq!(factor(&health, HealthFactorKind::BlockedTasks).unwrap().status, HealthStatus::Amber);
+        assert_eq!(health.status, HealthStatus::Amber);
+    }
+
+    #[test]
+    fn test_schedule_slip_and_milestones() {
+        let tasks = vec![task(TaskStatus::Todo, Some(30))];
+        let late = Milestone::new(Uuid::nil(), "Beta".to_string(), None, Some(Utc::now() - Duration::days(1)));
+        let health = HealthPolicy::default().evaluate(&project(Some(10), None), &tasks, &[late], None, now());
+
+        let slip = factor(&health, HealthFactorKind::ScheduleSlip).unwrap();
+        assert_eq!((slip.value, slip.status), (20.0, HealthStatus::Red));
+        let milestones = factor(&health, HealthFactorKind::OverdueMilestones).unwrap();
+        assert_eq!((milestones.value, milestones.status), (1.0, HealthStatus::Amber));
+        assert_eq!(health.status, HealthStatus::Red);
+    }
+
+    #[test]
+    fn test_budget_consumption() {
+        let health =
+            HealthPolicy::default().evaluate(&project(None, Some(1000)), &[], &[], Some(Decimal::from(950)), now());
+
+        let budget = factor(&health, HealthFactorKind::BudgetConsumption).unwrap();
+        assert_eq!((budget.value, budget.status), (0.95, HealthStatus::Amber));
+    }
+}
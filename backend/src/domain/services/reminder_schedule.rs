@@ -0,0 +1,154 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::shared::DomainError;
+
+/// Reminder about a due date
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DueReminder {
+    /// The item is due within this lead time
+    DueSoon(Duration),
+    Overdue,
+}
+
+impl DueReminder {
+    /// Stored form; smaller is more urgent and `0` is the overdue reminder
+    pub fn lead_minutes(&self) -> i32 {
+        match self {
+            DueReminder::DueSoon(lead) => lead.num_minutes() as i32,
+            DueReminder::Overdue => 0,
+        }
+    }
+
+    /// Lead time in words, e.g. "1 hour" or "30 minutes"
+    pub fn describe_lead(lead: Duration) -> String {
+        let plural = |n: i64, unit: &str| format!("{} {}{}", n, unit, if n == 1 { "" } else { "s" });
+        let minutes = lead.num_minutes();
+        if minutes % (24 * 60) == 0 && minutes > 24 * 60 {
+            plural(minutes / (24 * 60), "day")
+        } else if minutes % 60 == 0 {
+            plural(minutes / 60, "hour")
+        } else {
+            plural(minutes, "minute")
+        }
+    }
+}
+
+/// Lead times at which due dates are announced, plus the overdue reminder
+#[derive(Debug, Clone)]
+pub struct ReminderSchedule {
+    /// Ascending, without duplicates
+    lead_times: Vec<Duration>,
+}
+
+impl ReminderSchedule {
+    pub fn new(mut lead_times: Vec<Duration>) -> Self {
+        lead_times.retain(|lead| *lead > Duration::zero());
+        lead_times.sort();
+        lead_times.dedup();
+        Self { lead_times }
+    }
+
+    /// Parse a comma separated list of lead times such as `24h,1h`; units are `d`, `h` and `m`
+    pub fn parse(spec: &str) -> Result<Self, DomainError> {
+        let mut lead_times = Vec::new();
+        for part in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let invalid = || DomainError::ValidationError(format!("Invalid reminder lead time '{}'", part));
+            let (amount, unit) = part.split_at(part.len() - 1);
+            let amount: i64 = amount.parse().map_err(|_| invalid())?;
+            let lead = match unit {
+                "d" => Duration::days(amount),
+                "h" => Duration::hours(amount),
+                "m" => Duration::minutes(amount),
+                _ => return Err(invalid()),
+            };
+            if lead <= Duration::zero() {
+                return Err(invalid());
+            }
+            lead_times.push(lead);
+        }
+        Ok(Self::new(lead_times))
+    }
+
+    /// Longest lead time; items due further out need no reminder yet
+    pub fn horizon(&self) -> Duration {
+        self.lead_times.last().copied().unwrap_or_else(Duration::zero)
+    }
+
+    /// Most urgent reminder that applies at `now`, unless one at least as
+    /// urgent was already sent. Reminders skipped while the scheduler was
+    /// down are not sent late.
+    pub fn next_reminder(
+        &self,
+        due_date: DateTime<Utc>,
+        now: DateTime<Utc>,
+        last_sent_minutes: Option<i32>,
+    ) -> Option<DueReminder> {
+        let reminder = if now >= due_date {
+            DueReminder::Overdue
+        } else {
+            let lead = self.lead_times.iter().find(|lead| due_date - **lead <= now)?;
+            DueReminder::DueSoon(*lead)
+        };
+
+        match last_sent_minutes {
+            Some(last) if last <= reminder.lead_minutes() => None,
+            _ => Some(reminder),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let schedule = ReminderSchedule::parse("1h, 24h,1h,30m").unwrap();
+        assert_eq!(schedule.lead_times, vec![Duration::minutes(30), Duration::hours(1), Duration::hours(24)]);
+        assert_eq!(schedule.horizon(), Duration::hours(24));
+        assert!(ReminderSchedule::parse("24x").is_err());
+        assert!(ReminderSchedule::parse("0h").is_err());
+        assert_eq!(ReminderSchedule::parse("").unwrap().horizon(), Duration::zero());
+    }
+
+    #[test]
+    fn test_next_reminder_picks_most_urgent_lead() {
+        let schedule = ReminderSchedule::parse("24h,1h").unwrap();
+        let due = Utc::now() + Duration::days(3);
+
+        assert_eq!(schedule.next_reminder(due, due - Duration::hours(30), None), None);
+        assert_eq!(
+            schedule.next_reminder(due, due - Duration::hours(20), None),
+            Some(DueReminder::DueSoon(Duration::hours(24)))
+        );
+        // Only the 1h reminder is sent when the 24h one was missed
+        assert_eq!(
+            schedule.next_reminder(due, due - Duration::minutes(30), None),
+            Some(DueReminder::DueSoon(Duration::hours(1)))
+        );
+        assert_eq!(schedule.next_reminder(due, due, None), Some(DueReminder::Overdue));
+    }
+
+    #[test]
+    fn test_next_reminder_is_sent_once() {
+        let schedule = ReminderSchedule::parse("24h,1h").unwrap();
+        let due = Utc::now();
+
+        assert_eq!(schedule.next_reminder(due, due - Duration::hours(20), Some(24 * 60)), None);
+        assert_eq!(
+            schedule.next_reminder(due, due - Duration::minutes(10), Some(24 * 60)),
+            Some(DueReminder::DueSoon(Duration::hours(1)))
+        );
+        assert_eq!(schedule.next_reminder(due, due - Duration::minutes(10), Some(60)), None);
+        assert_eq!(schedule.next_reminder(due, due + Duration::hours(1), Some(60)), Some(DueReminder::Overdue));
+        assert_eq!(schedule.next_reminder(due, due + Duration::hours(1), Some(0)), None);
+    }
+
+    #[test]
+    fn test_describe_lead() {
+        assert_eq!(DueReminder::describe_lead(Duration::hours(1)), "1 hour");
+        assert_eq!(DueReminder::describe_lead(Duration::hours(24)), "24 hours");
+        assert_eq!(DueReminder::describe_lead(Duration::days(3)), "3 days");
+        assert_eq!(DueReminder::describe_lead(Duration::minutes(90)), "90 minutes");
+    }
+}
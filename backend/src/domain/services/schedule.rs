@@ -0,0 +1,390 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+use uuid::Uuid;
+
+use crate::domain::entities::{Milestone, Project, Task, TaskDependency};
+use crate::shared::DomainError;
+
+/// Earliest and latest dates of an open task, first and last day inclusive
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScheduledTask {
+    pub task_id: Uuid,
+    pub title: String,
+    pub milestone_id: Option<Uuid>,
+    pub duration_days: i64,
+    pub earliest_start: NaiveDate,
+    pub earliest_finish: NaiveDate,
+    pub latest_start: NaiveDate,
+    pub latest_finish: NaiveDate,
+    /// Days the task can slip without delaying the project or a due date; negative when already late
+    pub float_days: i64,
+    pub critical: bool,
+    pub due_date: Option<NaiveDate>,
+    /// Days the earliest finish lies past the due date
+    pub slip_days: i64,
+}
+
+/// Projected completion of a milestone from the finish of its open tasks
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MilestoneForecast {
+    pub milestone_id: Uuid,
+    pub name: String,
+    pub due_date: Option<NaiveDate>,
+    /// `None` when the milestone has no open tasks
+    pub forecast_date: Option<NaiveDate>,
+    /// Days the forecast lies past the due date
+    pub slip_days: i64,
+    /// Task finishing last, which sets the forecast
+    pub driving_task_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectSchedule {
+    pub project_id: Uuid,
+    pub start_date: NaiveDate,
+    /// Earliest finish of the last task, `None` when no task is open
+    pub finish_date: Option<NaiveDate>,
+    /// Critical tasks in the order they are worked on
+    pub critical_path: Vec<Uuid>,
+    pub tasks: Vec<ScheduledTask>,
+    pub milestones: Vec<MilestoneForecast>,
+}
+
+/// Critical path method over the open tasks of a project, in whole calendar days.
+pub struct Schedule;
+
+struct Node<'a> {
+    task: &'a Task,
+    duration: i64,
+    /// Day before which the task cannot start
+    not_before: i64,
+    /// Exclusive day by which the task is due
+    due: Option<i64>,
+}
+
+impl Schedule {
+    /// Done tasks are finished and satisfy their dependents. A parent task is scheduled through its open
+    /// subtasks, so dependencies on it apply to each of them. Nothing starts before `today` or the project start.
+    pub fn build(
+        project: &Project,
+        tasks: &[Task],
+        dependencies: &[TaskDependency],
+        milestones: &[Milestone],
+        hours_per_day: f32,
+        today: NaiveDate,
+    ) -> Result<ProjectSchedule, DomainError> {
+        let start = project.start_date.map_or(today, |date| date.date_naive().max(today));
+        let offset = |date: DateTime<Utc>| (date.date_naive() - start).num_days();
+
+        let mut open_children: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for task in tasks.iter().filter(|task| !task.is_done()) {
+            if let Some(parent_id) = task.parent_id {
+                open_children.entry(parent_id).or_default().push(task.id);
+            }
+        }
+
+        let nodes: Vec<Node> = tasks
+            .iter()
+            .filter(|task| !task.is_done() && !open_children.contains_key(&task.id))
+            .map(|task| Node {
+                task,
+                duration: Self::duration_days(task, hours_per_day),
+                not_before: task.start_date.map_or(0, |date| offset(date).max(0)),
+                due: task.due_date.map(|date| offset(date) + 1),
+            })
+            .collect();
+        let index: HashMap<Uuid, usize> = nodes.iter().enumerate().map(|(i, node)| (node.task.id, i)).collect();
+        let leaves = |id: Uuid| Self::leaves(id, &index, &open_children);
+
+        let mut successors: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+        let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+        let mut edges = HashSet::new();
+        for dependency in dependencies {
+            for blocking in leaves(dependency.blocking_task_id) {
+                for blocked in leaves(dependency.blocked_task_id) {
+                    if blocking != blocked && edges.insert((blocking, blocked)) {
+                        successors[blocking].push(blocked);
+                        predecessors[blocked].push(blocking);
+                    }
+                }
+            }
+        }
+
+        let order = Self::topological_order(&predecessors, &successors)?;
+
+        let mut earliest_start = vec![0; nodes.len()];
+        let mut earliest_finish = vec![0; nodes.len()];
+        for &i in &order {
+            let ready = predecessors[i].iter().map(|&p| earliest_finish[p]).max().unwrap_or(0);
+            earliest_start[i] = ready.max(nodes[i].not_before);
+            earliest_finish[i] = earliest_start[i] + nodes[i].duration;
+        }
+        let finish = earliest_finish.iter().copied().max();
+
+        let mut latest_start = vec![0; nodes.len()];
+        let mut latest_finish = vec![0; nodes.len()];
+        for &i in order.iter().rev() {
+            let mut lf = finish.unwrap_or(0);
+            if let Some(due) = nodes[i].due {
+                lf = lf.min(due);
+            }
+            if let Some(successor) = successors[i].iter().map(|&s| latest_start[s]).min() {
+                lf = lf.min(successor);
+            }
+            latest_finish[i] = lf;
+            latest_start[i] = lf - nodes[i].duration;
+        }
+
+        let day = |offset: i64| start + Duration::days(offset);
+        let mut scheduled: Vec<ScheduledTask> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| {
+                let float_days = latest_start[i] - earliest_start[i];
+                ScheduledTask {
+                    task_id: node.task.id,
+                    title: node.task.title.clone(),
+                    milestone_id: node.task.milestone_id,
+                    duration_days: node.duration,
+                    earliest_start: day(earliest_start[i]),
+                    earliest_finish: day(earliest_finish[i] - 1),
+                    latest_start: day(latest_start[i]),
+                    latest_finish: day(latest_finish[i] - 1),
+                    float_days,
+                    critical: float_days <= 0,
+                    due_date: node.task.due_date.map(|date| date.date_naive()),
+                    slip_days: node.due.map_or(0, |due| (earliest_finish[i] - due).max(0)),
+                }
+            })
+            .collect();
+        scheduled.sort_by_key(|task| (task.earliest_start, task.earliest_finish));
+
+        let milestones = milestones
+            .iter()
+            .filter(|milestone| !milestone.completed)
+            .map(|milestone| {
+                let driving = tasks
+                    .iter()
+                    .filter(|task| task.milestone_id == Some(milestone.id))
+                    .flat_map(|task| leaves(task.id))
+                    .max_by_key(|&i| earliest_finish[i]);
+                let forecast_date = driving.map(|i| day(earliest_finish[i] - 1));
+                let due_date = milestone.due_date.map(|date| date.date_naive());
+                let slip_days = match (forecast_date, due_date) {
+                    (Some(forecast), Some(due)) => (forecast - due).num_days().max(0),
+                    _ => 0,
+                };
+                MilestoneForecast {
+                    milestone_id: milestone.id,
+                    name: milestone.name.clone(),
+                    due_date,
+                    forecast_date,
+                    slip_days,
+                    driving_task_id: driving.map(|i| nodes[i].task.id),
+                }
+            })
+            .collect();
+
+        Ok(ProjectSchedule {
+            project_id: project.id,
+            start_date: start,
+            finish_date: finish.map(|finish| day(finish - 1)),
+            critical_path: scheduled.iter().filter(|task| task.critical).map(|task| task.task_id).collect(),
+            tasks: scheduled,
+            milestones,
+        })
+    }
+
+    /// Remaining estimate in working days, else the planned start to due span, else a single day
+    fn duration_days(task: &Task, hours_per_day: f32) -> i64 {
+        let days = match (task.estimated_hours, task.start_date, task.due_date) {
+            (Some(estimate), _, _) => {
+                let remaining = (estimate - task.actual_hours.unwrap_or(0.0)).max(0.0);
+                (remaining / hours_per_day).ceil() as i64
+            }
+            (None, Some(start_date), Some(due_date)) => (due_date.date_naive() - start_date.date_naive()).num_days() + 1,
+            _ => 1,
+        };
+        days.max(1)
+    }
+
+    /// Scheduled tasks standing for the task: itself, or the open subtasks it is split into
+    fn leaves(id: Uuid, index: &HashMap<Uuid, usize>, open_children: &HashMap<Uuid, Vec<Uuid>>) -> Vec<usize> {
+        if let Some(&i) = index.get(&id) {
+            return vec![i];
+        }
+        open_children
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .flat_map(|&child| Self::leaves(child, index, open_children))
+            .collect()
+    }
+
+    fn topological_order(predecessors: &[Vec<usize>], successors: &[Vec<usize>]) -> Result<Vec<usize>, DomainError> {
+        let mut waiting: Vec<usize> = predecessors.iter().map(Vec::len).collect();
+        let mut ready: VecDeque<usize> = (0..waiting.len()).filter(|&i| waiting[i] == 0).collect();
+        let mut order = Vec::with_capacity(waiting.len());
+
+        while let Some(i) = ready.pop_front() {
+            order.push(i);
+            for &s in &successors[i] {
+                waiting[s] -= 1;
+                if waiting[s] == 0 {
+                    ready.push_back(s);
+                }
+            }
+        }
+
+        if order.len() < waiting.len() {
+            return Err(DomainError::ValidationError("Task dependencies form a cycle".into()));
+        }
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::TaskStatus;
+    use chrono::TimeZone;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2030, 3, 4).unwrap()
+    }
+
+    fn at(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2030, 3, day, 17, 0, 0).unwrap()
+    }
+
+    fn project() -> Project {
+        Project::new("Launch".to_string(), None, Uuid::new_v4(), None, None, None, None, None)
+    }
+
+    fn task(project: &Project, title: &str, hours: f32) -> Task {
+        Task::new(project.id, title.to_string(), None, None, None, None, Some(hours))
+    }
+
+    fn blocks(blocking: &Task, blocked: &Task) -> TaskDependency {
+        TaskDependency::new(blocking.id, blocked.id, None)
+    }
+
+    fn find(schedule: &ProjectSchedule, task: &Task) -> ScheduledTask {
+        schedule.tasks.iter().find(|t| t.task_id == task.id).cloned().unwrap()
+    }
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2030, 3, day).unwrap()
+    }
+
+    #[test]
+    fn test_critical_path_and_float() {
+        let project = project();
+        let design = task(&project, "Design", 16.0);
+        let build = task(&project, "Build", 24.0);
+        let docs = task(&project, "Docs", 8.0);
+        let release = task(&project, "Release", 8.0);
+        let dependencies = [
+            blocks(&design, &build),
+            blocks(&design, &docs),
+            blocks(&build, &release),
+            blocks(&docs, &release),
+        ];
+        let tasks = [design.clone(), build.clone(), docs.clone(), release.clone()];
+
+        let schedule = Schedule::build(&project, &tasks, &dependencies, &[], 8.0, today()).unwrap();
+
+        let build_slot = find(&schedule, &build);
+        assert_eq!(build_slot.earliest_start, day(6));
+        assert_eq!(build_slot.earliest_finish, day(8));
+        assert_eq!(build_slot.float_days, 0);
+
+        let docs_slot = find(&schedule, &docs);
+        assert_eq!(docs_slot.earliest_start, day(6));
+        assert_eq!(docs_slot.latest_start, day(8));
+        assert_eq!(docs_slot.latest_finish, day(8));
+        assert_eq!(docs_slot.float_days, 2);
+        assert!(!docs_slot.critical);
+
+        assert_eq!(schedule.critical_path, vec![design.id, build.id, release.id]);
+        assert_eq!(schedule.finish_date, Some(day(9)));
+    }
+
+    #[test]
+    fn test_due_date_gives_negative_float() {
+        let project = project();
+        let mut late = task(&project, "Late", 24.0);
+        late.due_date = Some(at(4));
+
+        let schedule = Schedule::build(&project, &[late.clone()], &[], &[], 8.0, today()).unwrap();
+
+        let slot = find(&schedule, &late);
+        assert_eq!(slot.float_days, -2);
+        assert_eq!(slot.slip_days, 2);
+        assert!(slot.critical);
+    }
+
+    #[test]
+    fn test_slipping_task_delays_milestone() {
+        let project = project();
+        let milestone = Milestone::new(project.id, "Beta".to_string(), None, Some(at(6)));
+        let mut api = task(&project, "API", 24.0);
+        api.start_date = Some(at(5));
+        let mut ui = task(&project, "UI", 8.0);
+        ui.milestone_id = Some(milestone.id);
+        let dependencies = [blocks(&api, &ui)];
+
+        let schedule =
+            Schedule::build(&project, &[api.clone(), ui.clone()], &dependencies, &[milestone], 8.0, today()).unwrap();
+
+        let forecast = &schedule.milestones[0];
+        assert_eq!(forecast.forecast_date, Some(day(8)));
+        assert_eq!(forecast.slip_days, 2);
+        assert_eq!(forecast.driving_task_id, Some(ui.id));
+    }
+
+    #[test]
+    fn test_done_tasks_and_parents() {
+        let project = project();
+        let mut done = task(&project, "Spec", 40.0);
+        done.update_status(TaskStatus::Done);
+        let parent = task(&project, "Build", 16.0);
+        let mut child = task(&project, "Backend", 16.0);
+        child.parent_id = Some(parent.id);
+        let release = task(&project, "Release", 4.0);
+        let dependencies = [blocks(&done, &parent), blocks(&parent, &release)];
+        let tasks = [done.clone(), parent.clone(), child.clone(), release.clone()];
+
+        let schedule = Schedule::build(&project, &tasks, &dependencies, &[], 8.0, today()).unwrap();
+
+        assert_eq!(schedule.tasks.len(), 2);
+        assert_eq!(find(&schedule, &child).earliest_start, today());
+        assert_eq!(find(&schedule, &release).earliest_start, day(6));
+    }
+
+    #[test]
+    fn test_duration_falls_back_to_planned_span() {
+        let project = project();
+        let mut task = Task::new(project.id, "Audit".to_string(), None, None, None, Some(at(9)), None);
+        task.start_date = Some(at(7));
+
+        let schedule = Schedule::build(&project, &[task.clone()], &[], &[], 8.0, today()).unwrap();
+
+        let slot = find(&schedule, &task);
+        assert_eq!(slot.duration_days, 3);
+        assert_eq!(slot.earliest_start, day(7));
+        assert_eq!(slot.earliest_finish, day(9));
+        assert_eq!(slot.float_days, 0);
+    }
+
+    #[test]
+    fn test_cycle_is_rejected() {
+        let project = project();
+        let a = task(&project, "A", 8.0);
+        let b = task(&project, "B", 8.0);
+        let dependencies = [blocks(&a, &b), blocks(&b, &a)];
+
+        assert!(Schedule::build(&project, &[a, b], &dependencies, &[], 8.0, today()).is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc, Weekday};
+use serde::Serialize;
+
+use crate::domain::entities::{SlaPolicy, SlaTarget};
+
+/// State of one SLA target of a task
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SlaTimer {
+    pub target: SlaTarget,
+    pub target_minutes: i32,
+    /// Minutes counted so far, or until the clock stopped
+    pub elapsed_minutes: i64,
+    pub due_at: DateTime<Utc>,
+    /// When the target was met, or missed late
+    pub stopped_at: Option<DateTime<Utc>>,
+    pub breached: bool,
+}
+
+/// Measures SLA time; business-hours policies only count Monday to Friday, 09:00 to 17:00 UTC
+#[derive(Debug, Clone)]
+pub struct SlaClock {
+    day_start: NaiveTime,
+    day_end: NaiveTime,
+}
+
+impl Default for SlaClock {
+    fn default() -> Self {
+        Self {
+            day_start: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            day_end: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+        }
+    }
+}
+
+impl SlaClock {
+    /// Timer of one target of the policy; `None` when the policy has no such target.
+    /// The clock runs from `started_at` until `stopped_at`, or `now` while the target is open.
+    pub fn evaluate(
+        &self,
+        policy: &SlaPolicy,
+        target: SlaTarget,
+        started_at: DateTime<Utc>,
+        stopped_at: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Option<SlaTimer> {
+        let target_minutes = policy.target_minutes(target)?;
+        let due_at = self.deadline(started_at, target_minutes as i64, policy.business_hours);
+        let end = stopped_at.unwrap_or(now);
+
+        Some(SlaTimer {
+            target,
+            target_minutes,
+            elapsed_minutes: self.elapsed_minutes(started_at, end, policy.business_hours),
+            due_at,
+            stopped_at,
+            breached: end > due_at,
+        })
+    }
+
+    /// Minutes between two moments, counting only working hours if asked
+    pub fn elapsed_minutes(&self, from: DateTime<Utc>, to: DateTime<Utc>, business_hours: bool) -> i64 {
+        if to <= from {
+            return 0;
+        }
+        if !business_hours {
+            return (to - from).num_minutes();
+        }
+
+        let mut total = Duration::zero();
+        for date in from.date_naive().iter_days().take_while(|date| *date <= to.date_naive()) {
+            if let Some((open, close)) = self.working_window(date) {
+                let start = open.max(from);
+                let end = close.min(to);
+                if end > start {
+                    total += end - start;
+                }
+            }
+        }
+        total.num_minutes()
+    }
+
+    /// Moment `minutes` after `from`, counting only working hours if asked
+    pub fn deadline(&self, from: DateTime<Utc>, minutes: i64, business_hours: bool) -> DateTime<Utc> {
+        let mut remaining = Duration::minutes(minutes);
+        if !business_hours {
+            return from + remaining;
+        }
+
+        for date in from.date_naive().iter_days() {
+            let Some((open, close)) = self.working_window(date) else {
+                continue;
+            };
+            let start = open.max(from);
+            if close <= start {
+                continue;
+            }
+            if start + remaining <= close {
+                return start + remaining;
+            }
+            remaining -= close - start;
+        }
+        DateTime::<Utc>::MAX_UTC
+    }
+
+    fn working_window(&self, date: NaiveDate) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        if matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            return None;
+        }
+        Some((
+            date.and_time(self.day_start).and_utc(),
+            date.and_time(self.day_end).and_utc(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::Priority;
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    /// March 2024; the 1st is a Friday
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, day, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_elapsed_working_minutes_skip_nights_and_weekends() {
+        let clock = SlaClock::default();
+        assert_eq!(clock.elapsed_minutes(at(1, 16, 0), at(4, 10, 0), true), 120);
+        assert_eq!(clock.elapsed_minutes(at(1, 18, 0), at(2, 12, 0), true), 0);
+        assert_eq!(clock.elapsed_minutes(at(4, 8, 0), at(5, 8, 0), true), 480);
+        assert_eq!(clock.elapsed_minutes(at(1, 16, 0), at(4, 10, 0), false), 66 * 60);
+    }
+
+    #[test]
+    fn test_deadline_carries_over_to_next_working_day() {
+        let clock = SlaClock::default();
+        assert_eq!(clock.deadline(at(1, 15, 0), 4 * 60, true), at(4, 11, 0));
+        assert_eq!(clock.deadline(at(2, 10, 0), 60, true), at(4, 10, 0));
+        assert_eq!(clock.deadline(at(4, 9, 0), 8 * 60, true), at(4, 17, 0));
+        assert_eq!(clock.deadline(at(1, 15, 0), 4 * 60, false), at(1, 19, 0));
+    }
+
+    #[test]
+    fn test_evaluate_flags_breaches() {
+        let clock = SlaClock::default();
+        let policy = SlaPolicy::new(Uuid::nil(), Priority::Critical, Some(4 * 60), None, true);
+
+        let open = clock.evaluate(&policy, SlaTarget::Response, at(4, 9, 0), None, at(4, 12, 0)).unwrap();
+        assert!(!open.breached);
+        assert_eq!(open.elapsed_minutes, 180);
+        assert_eq!(open.due_at, at(4, 13, 0));
+
+        let late = clock.evaluate(&policy, SlaTarget::Response, at(4, 9, 0), None, at(4, 14, 0)).unwrap();
+        assert!(late.breached);
+
+        // A target met in time stays met
+        let met = clock
+            .evaluate(&policy, SlaTarget::Response, at(4, 9, 0), Some(at(4, 10, 0)), at(5, 9, 0))
+            .unwrap();
+        assert!(!met.breached);
+        assert_eq!(met.elapsed_minutes, 60);
+
+        assert!(clock.evaluate(&policy, SlaTarget::Resolution, at(4, 9, 0), None, at(4, 9, 0)).is_none());
+    }
+}
@@ -0,0 +1,145 @@
+/// Gap left between neighbouring ranks when a column is (re)spaced
+const RANK_STEP: f64 = 1024.0;
+/// Smallest gap that can still be split; below this the column is respaced
+const MIN_RANK_GAP: f64 = 1e-6;
+/// Number of neighbours on each side respaced first when a gap runs out
+const REBALANCE_RADIUS: usize = 4;
+
+/// Where a task lands in a board column and which neighbours had to be respaced
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankPlacement {
+    pub rank: f64,
+    /// `(index in the column, new rank)` of neighbours that were respaced
+    pub rebalanced: Vec<(usize, f64)>,
+}
+
+/// Fractional ordering of tasks within a (project, status) board column
+pub struct TaskRank;
+
+impl TaskRank {
+    /// Rank for a task appended after the current last rank of a column
+    pub fn after(last: Option<f64>) -> f64 {
+        last.map_or(RANK_STEP, |last| last + RANK_STEP)
+    }
+
+    /// Rank strictly between two neighbours, if there is room left
+    pub fn between(lower: Option<f64>, upper: Option<f64>) -> Option<f64> {
+        match (lower, upper) {
+            (None, None) => Some(RANK_STEP),
+            (Some(lower), None) => Some(lower + RANK_STEP),
+            (None, Some(upper)) => Some(upper - RANK_STEP),
+            (Some(lower), Some(upper)) if upper - lower > 2.0 * MIN_RANK_GAP => Some((lower + upper) / 2.0),
+            _ => None,
+        }
+    }
+
+    /// Place a task at `index` of an ordered column that does not contain it.
+    ///
+    /// When its neighbours are too close, a window around `index` is respaced
+    /// evenly between the ranks just outside it, widening the window until the
+    /// spacing fits; only the whole column is respaced as a last resort.
+    pub fn place(column: &[f64], index: usize) -> RankPlacement {
+        let index = index.min(column.len());
+        let lower = index.checked_sub(1).map(|i| column[i]);
+        if let Some(rank) = Self::between(lower, column.get(index).copied()) {
+            return RankPlacement { rank, rebalanced: Vec::new() };
+        }
+
+        let mut radius = REBALANCE_RADIUS;
+        loop {
+            let start = index.saturating_sub(radius);
+            let end = (index + radius).min(column.len());
+            let whole_column = start == 0 && end == column.len();
+            let lower = if whole_column { None } else { start.checked_sub(1).map(|i| column[i]) };
+            let upper = if whole_column { None } else { column.get(end).copied() };
+
+            if let Some(ranks) = Self::spread(lower, upper, end - start + 1) {
+                let moved = index - start;
+                let rebalanced = (start..end)
+                    .zip(ranks.iter().enumerate().filter(|(i, _)| *i != moved).map(|(_, rank)| *rank))
+                    .filter(|(i, rank)| column[*i] != *rank)
+                    .collect();
+                return RankPlacement { rank: ranks[moved], rebalanced };
+            }
+            radius *= 4;
+        }
+    }
+
+    /// `count` evenly spaced ranks strictly between two bounds
+    fn spread(lower: Option<f64>, upper: Option<f64>, count: usize) -> Option<Vec<f64>> {
+        let steps = 1..=count;
+        match (lower, upper) {
+            (None, None) => Some(steps.map(|i| i as f64 * RANK_STEP).collect()),
+            (Some(lower), None) => Some(steps.map(|i| lower + i as f64 * RANK_STEP).collect()),
+            (None, Some(upper)) => Some(steps.map(|i| upper - (count + 1 - i) as f64 * RANK_STEP).collect()),
+            (Some(lower), Some(upper)) => {
+                let gap = (upper - lower) / (count + 1) as f64;
+                (gap > MIN_RANK_GAP).then(|| steps.map(|i| lower + i as f64 * gap).collect())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(column: &[f64], index: usize, placement: &RankPlacement) -> Vec<f64> {
+        let mut ranks = column.to_vec();
+        for (i, rank) in &placement.rebalanced {
+            ranks[*i] = *rank;
+        }
+        ranks.insert(index, placement.rank);
+        ranks
+    }
+
+    fn is_strictly_increasing(ranks: &[f64]) -> bool {
+        ranks.windows(2).all(|pair| pair[0] < pair[1])
+    }
+
+    #[test]
+    fn test_place_between_neighbours_without_rebalance() {
+        let placement = TaskRank::place(&[1024.0, 2048.0, 3072.0], 1);
+        assert_eq!(placement.rank, 1536.0);
+        assert!(placement.rebalanced.is_empty());
+    }
+
+    #[test]
+    fn test_place_at_ends_and_in_empty_column() {
+        assert_eq!(TaskRank::place(&[], 0).rank, 1024.0);
+        assert_eq!(TaskRank::place(&[1024.0, 2048.0], 0).rank, 0.0);
+        assert_eq!(TaskRank::place(&[1024.0, 2048.0], 2).rank, 3072.0);
+        assert_eq!(TaskRank::after(None), 1024.0);
+        assert_eq!(TaskRank::after(Some(2048.0)), 3072.0);
+    }
+
+    #[test]
+    fn test_exhausted_gap_rebalances_only_a_window() {
+        let mut column: Vec<f64> = (1..=40).map(|i| i as f64 * 1024.0).collect();
+        column[20] = column[19] + 1e-7;
+        let placement = TaskRank::place(&column, 20);
+
+        assert!(!placement.rebalanced.is_empty());
+        assert!(placement.rebalanced.len() < column.len());
+        assert!(is_strictly_increasing(&apply(&column, 20, &placement)));
+    }
+
+    #[test]
+    fn test_equal_ranks_fall_back_to_whole_column() {
+        let column = vec![0.0; 10];
+        let placement = TaskRank::place(&column, 5);
+        let ranks = apply(&column, 5, &placement);
+        assert!(is_strictly_increasing(&ranks));
+        assert_eq!(placement.rebalanced.len(), 10);
+    }
+
+    #[test]
+    fn test_repeated_inserts_stay_ordered() {
+        let mut column = vec![1024.0, 2048.0];
+        for _ in 0..200 {
+            let placement = TaskRank::place(&column, 1);
+            column = apply(&column, 1, &placement);
+            assert!(is_strictly_increasing(&column));
+        }
+    }
+}
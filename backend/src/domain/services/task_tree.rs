@@ -0,0 +1,169 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::domain::entities::Task;
+
+/// A task with its subtasks and completion rolled up from its descendants
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskTreeNode {
+    #[serde(flatten)]
+    pub task: Task,
+    pub subtask_count: usize,
+    pub done_subtask_count: usize,
+    pub completion_percent: f32,
+    pub children: Vec<TaskTreeNode>,
+}
+
+pub struct TaskTree;
+
+impl TaskTree {
+    /// Build the tree rooted at `root_id` from a flat list of the root and its descendants
+    pub fn build(root_id: Uuid, tasks: Vec<Task>) -> Option<TaskTreeNode> {
+        let mut root = None;
+        let mut children_by_parent: HashMap<Uuid, Vec<Task>> = HashMap::new();
+        for task in tasks {
+            if task.id == root_id {
+                root = Some(task);
+            } else if let Some(parent_id) = task.parent_id {
+                children_by_parent.entry(parent_id).or_default().push(task);
+            }
+        }
+
+        root.map(|task| Self::build_node(task, &mut children_by_parent))
+    }
+
+    fn build_node(task: Task, children_by_parent: &mut HashMap<Uuid, Vec<Task>>) -> TaskTreeNode {
+        let children: Vec<TaskTreeNode> = children_by_parent
+            .remove(&task.id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|child| Self::build_node(child, children_by_parent))
+            .collect();
+
+        let subtask_count = children.iter().map(|c| 1 + c.subtask_count).sum();
+        let done_subtask_count = children
+            .iter()
+            .map(|c| usize::from(c.task.is_done()) + c.done_subtask_count)
+            .sum();
+        let completion_percent = if subtask_count == 0 {
+            if task.is_done() { 100.0 } else { 0.0 }
+        } else {
+            done_subtask_count as f32 / subtask_count as f32 * 100.0
+        };
+
+        TaskTreeNode {
+            task,
+            subtask_count,
+            done_subtask_count,
+            completion_percent,
+            children,
+        }
+    }
+
+    /// Sum of the children's hours, None when no child has a value
+    pub fn rollup_hours(children: &[Task], hours: impl Fn(&Task) -> Option<f32>) -> Option<f32> {
+        children
+            .iter()
+            .filter_map(hours)
+            .fold(None, |acc, h| Some(acc.unwrap_or(0.0) + h))
+    }
+
+    /// Replace the parent's estimated and actual hours with its children's sums; a figure
+    /// no child carries keeps the parent's own value
+    pub fn rollup_into(parent: &mut Task, children: &[Task]) {
+        if let Some(estimated) = Self::rollup_hours(children, |t| t.estimated_hours) {
+            parent.estimated_hours = Some(estimated);
+        }
+        if let Some(actual) = Self::rollup_hours(children, |t| t.actual_hours) {
+            parent.actual_hours = Some(actual);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::TaskStatus;
+
+    fn task(parent_id: Option<Uuid>, done: bool, estimate: Option<f32>) -> Task {
+        let mut task = Task::new(
+            Uuid::nil(),
+            "Task".to_string(),
+            None,
+            None,
+            None,
+            None,
+            estimate,
+        );
+        task.parent_id = parent_id;
+        if done {
+            task.update_status(TaskStatus::Done);
+        }
+        task
+    }
+
+    #[test]
+    fn test_build_nested_tree_with_completion() {
+        let epic = task(None, false, None);
+        let story = task(Some(epic.id), false, None);
+        let subtask_done = task(Some(story.id), true, None);
+        let subtask_open = task(Some(story.id), false, None);
+        let epic_id = epic.id;
+
+        let tree = TaskTree::build(epic_id, vec![subtask_open, story, epic, subtask_done]).unwrap();
+
+        assert_eq!(tree.task.id, epic_id);
+        assert_eq!(tree.children.len(), 1);
+        assert_eq!(tree.subtask_count, 3);
+        assert_eq!(tree.done_subtask_count, 1);
+        assert_eq!(tree.children[0].children.len(), 2);
+        assert_eq!(tree.children[0].completion_percent, 50.0);
+    }
+
+    #[test]
+    fn test_leaf_completion_follows_status() {
+        let leaf = task(None, true, None);
+        let id = leaf.id;
+        let tree = TaskTree::build(id, vec![leaf]).unwrap();
+        assert_eq!(tree.subtask_count, 0);
+        assert_eq!(tree.completion_percent, 100.0);
+    }
+
+    #[test]
+    fn test_build_without_root_returns_none() {
+        let orphan = task(Some(Uuid::new_v4()), false, None);
+        assert!(TaskTree::build(Uuid::new_v4(), vec![orphan]).is_none());
+    }
+
+    #[test]
+    fn test_rollup_hours() {
+        let parent = Uuid::new_v4();
+        let children = vec![
+            task(Some(parent), false, Some(2.5)),
+            task(Some(parent), false, None),
+            task(Some(parent), false, Some(4.0)),
+        ];
+        assert_eq!(TaskTree::rollup_hours(&children, |t| t.estimated_hours), Some(6.5));
+        assert_eq!(TaskTree::rollup_hours(&children, |t| t.actual_hours), None);
+    }
+
+    #[test]
+    fn test_rollup_into_keeps_parent_hours_no_child_carries() {
+        let mut parent = task(None, false, Some(10.0));
+        parent.actual_hours = Some(3.0);
+        let children = vec![task(Some(parent.id), false, None)];
+
+        TaskTree::rollup_into(&mut parent, &children);
+        assert_eq!(parent.estimated_hours, Some(10.0));
+        assert_eq!(parent.actual_hours, Some(3.0));
+
+        let children = vec![
+            task(Some(parent.id), false, Some(2.0)),
+            task(Some(parent.id), false, None),
+        ];
+        TaskTree::rollup_into(&mut parent, &children);
+        assert_eq!(parent.estimated_hours, Some(2.0));
+        assert_eq!(parent.actual_hours, Some(3.0));
+    }
+}
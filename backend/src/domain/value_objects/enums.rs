@@ -1,22 +1,18 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
 #[sqlx(type_name = "user_role", rename_all = "lowercase")]
 pub enum UserRole {
     Admin,
     Manager,
+    #[default]
     Member,
 }
 
-impl Default for UserRole {
-    fn default() -> Self {
-        Self::Member
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
 #[sqlx(type_name = "project_status", rename_all = "lowercase")]
 pub enum ProjectStatus {
+    #[default]
     Planning,
     Active,
     #[serde(rename = "onhold")]
@@ -26,30 +22,33 @@ pub enum ProjectStatus {
     Cancelled,
 }
 
-impl Default for ProjectStatus {
-    fn default() -> Self {
-        Self::Planning
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
 #[sqlx(type_name = "priority", rename_all = "lowercase")]
 pub enum Priority {
     Low,
+    #[default]
     Medium,
     High,
     Critical,
 }
 
-impl Default for Priority {
-    fn default() -> Self {
-        Self::Medium
+impl Priority {
+    pub const ALL: [Priority; 4] = [Priority::Low, Priority::Medium, Priority::High, Priority::Critical];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Priority::Low => "low",
+            Priority::Medium => "medium",
+            Priority::High => "high",
+            Priority::Critical => "critical",
+        }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
 #[sqlx(type_name = "task_status", rename_all = "lowercase")]
 pub enum TaskStatus {
+    #[default]
     Todo,
     #[serde(rename = "inprogress")]
     #[sqlx(rename = "inprogress")]
@@ -59,22 +58,75 @@ pub enum TaskStatus {
     Blocked,
 }
 
-impl Default for TaskStatus {
-    fn default() -> Self {
-        Self::Todo
+impl TaskStatus {
+    pub const ALL: [TaskStatus; 5] = [
+        TaskStatus::Todo,
+        TaskStatus::InProgress,
+        TaskStatus::Review,
+        TaskStatus::Done,
+        TaskStatus::Blocked,
+    ];
+
+    /// Database representation of the status, also the key used by the default workflow
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskStatus::Todo => "todo",
+            TaskStatus::InProgress => "inprogress",
+            TaskStatus::Review => "review",
+            TaskStatus::Done => "done",
+            TaskStatus::Blocked => "blocked",
+        }
+    }
+
+    /// Built-in workflow used by projects without a custom workflow
+    pub fn can_transition_to(&self, new_status: &TaskStatus) -> bool {
+        use TaskStatus::*;
+        match (self, new_status) {
+            (Todo, InProgress) => true,
+            (InProgress, Review | Blocked | Todo) => true,
+            (Review, Done | InProgress) => true,
+            (Blocked, InProgress | Todo) => true,
+            (Done, InProgress) => true, // Reopen
+            _ => false,
+        }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
 #[sqlx(type_name = "team_member_role", rename_all = "lowercase")]
 pub enum TeamMemberRole {
     Lead,
+    #[default]
     Member,
 }
 
-impl Default for TeamMemberRole {
-    fn default() -> Self {
-        Self::Member
+/// Role of a member inside a project; each role includes the rights of the ones before it.
+/// The project owner has every right, including managing members.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, sqlx::Type)]
+#[sqlx(type_name = "project_role", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectRole {
+    /// Read the project and its tasks
+    Viewer,
+    /// Create and edit tasks, comments, checklists, sprints and recurring tasks
+    #[default]
+    Contributor,
+    /// Delete tasks and manage workflows, custom fields, SLA policies and templates
+    Maintainer,
+}
+
+impl ProjectRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProjectRole::Viewer => "viewer",
+            ProjectRole::Contributor => "contributor",
+            ProjectRole::Maintainer => "maintainer",
+        }
+    }
+
+    /// Whether this role has at least the rights of `required`
+    pub fn includes(&self, required: ProjectRole) -> bool {
+        *self >= required
     }
 }
 
@@ -195,6 +247,22 @@ mod tests {
         assert_ne!(TaskStatus::Todo, TaskStatus::Blocked);
     }
 
+    #[test]
+    fn test_task_status_as_str_is_lowercase_database_name() {
+        assert_eq!(TaskStatus::Todo.as_str(), "todo");
+        assert_eq!(TaskStatus::InProgress.as_str(), "inprogress");
+        assert_eq!(TaskStatus::Blocked.as_str(), "blocked");
+    }
+
+    #[test]
+    fn test_task_status_builtin_transitions() {
+        assert!(TaskStatus::Todo.can_transition_to(&TaskStatus::InProgress));
+        assert!(TaskStatus::Review.can_transition_to(&TaskStatus::Done));
+        assert!(TaskStatus::Done.can_transition_to(&TaskStatus::InProgress));
+        assert!(!TaskStatus::Todo.can_transition_to(&TaskStatus::Done));
+        assert!(!TaskStatus::Blocked.can_transition_to(&TaskStatus::Done));
+    }
+
     // ============ TeamMemberRole Tests ============
 
     #[test]
@@ -221,6 +289,22 @@ mod tests {
         assert_ne!(TeamMemberRole::Lead, TeamMemberRole::Member);
     }
 
+    // ============ ProjectRole Tests ============
+
+    #[test]
+    fn test_project_role_default_is_contributor() {
+        assert_eq!(ProjectRole::default(), ProjectRole::Contributor);
+    }
+
+    #[test]
+    fn test_project_role_includes_lower_roles() {
+        assert!(ProjectRole::Maintainer.includes(ProjectRole::Contributor));
+        assert!(ProjectRole::Contributor.includes(ProjectRole::Viewer));
+        assert!(ProjectRole::Contributor.includes(ProjectRole::Contributor));
+        assert!(!ProjectRole::Viewer.includes(ProjectRole::Contributor));
+        assert!(!ProjectRole::Contributor.includes(ProjectRole::Maintainer));
+    }
+
     // ============ Serde Serialization Tests ============
 
     #[test]
@@ -262,6 +346,14 @@ mod tests {
         assert_eq!(status, TaskStatus::InProgress);
     }
 
+    #[test]
+    fn test_project_role_serialize_lowercase() {
+        let json = serde_json::to_string(&ProjectRole::Maintainer).unwrap();
+        assert_eq!(json, "\"maintainer\"");
+        let role: ProjectRole = serde_json::from_str("\"viewer\"").unwrap();
+        assert_eq!(role, ProjectRole::Viewer);
+    }
+
     #[test]
     fn test_priority_round_trip() {
         let priority = Priority::Critical;
@@ -1,7 +1,11 @@
 mod email;
 mod enums;
 mod password;
+mod recurrence_rule;
+mod task_sort;
 
 pub use email::Email;
-pub use enums::{Priority, ProjectStatus, TaskStatus, TeamMemberRole, UserRole};
+pub use enums::{Priority, ProjectRole, ProjectStatus, TaskStatus, TeamMemberRole, UserRole};
 pub use password::PasswordValidator;
+pub use recurrence_rule::{ByDay, Frequency, RecurrenceRule};
+pub use task_sort::{TaskSort, TaskSortField};
@@ -0,0 +1,496 @@
+use chrono::{Datelike, Duration, Months, NaiveDate, Weekday};
+use std::fmt;
+
+use crate::shared::error::DomainError;
+
+/// Upper bound on the number of periods walked when expanding a rule
+const MAX_PERIODS: u32 = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A `BYDAY` entry such as `MO`, `1MO` (first Monday) or `-1FR` (last Friday)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByDay {
+    pub ordinal: Option<i32>,
+    pub weekday: Weekday,
+}
+
+/// RFC 5545 recurrence rule (RRULE), date based.
+///
+/// Supports FREQ (DAILY, WEEKLY, MONTHLY, YEARLY), INTERVAL, COUNT, UNTIL,
+/// BYDAY, BYMONTHDAY, BYMONTH and WKST=MO. Weeks start on Monday.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    frequency: Frequency,
+    interval: u32,
+    by_day: Vec<ByDay>,
+    by_month_day: Vec<i32>,
+    by_month: Vec<u32>,
+    count: Option<u32>,
+    until: Option<NaiveDate>,
+}
+
+impl RecurrenceRule {
+    pub fn parse(rule: &str) -> Result<Self, DomainError> {
+        let rule = rule.trim();
+        let rule = rule.strip_prefix("RRULE:").unwrap_or(rule);
+
+        let mut frequency = None;
+        let mut interval = 1;
+        let mut by_day = Vec::new();
+        let mut by_month_day = Vec::new();
+        let mut by_month = Vec::new();
+        let mut count = None;
+        let mut until = None;
+
+        for part in rule.split(';').filter(|p| !p.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("expected KEY=VALUE, got '{}'", part)))?;
+
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        other => return Err(invalid(format!("unsupported FREQ '{}'", other))),
+                    })
+                }
+                "INTERVAL" => {
+                    interval = parse_number(key, value)?;
+                    if interval == 0 {
+                        return Err(invalid("INTERVAL must be at least 1".into()));
+                    }
+                }
+                "COUNT" => {
+                    let value: u32 = parse_number(key, value)?;
+                    if value == 0 {
+                        return Err(invalid("COUNT must be at least 1".into()));
+                    }
+                    count = Some(value);
+                }
+                "UNTIL" => until = Some(parse_until(value)?),
+                "BYDAY" => {
+                    by_day = value.split(',').map(parse_by_day).collect::<Result<_, _>>()?;
+                }
+                "BYMONTHDAY" => {
+                    for day in value.split(',') {
+                        let day: i32 = parse_number(key, day)?;
+                        if day == 0 || day.unsigned_abs() > 31 {
+                            return Err(invalid(format!("BYMONTHDAY out of range: {}", day)));
+                        }
+                        by_month_day.push(day);
+                    }
+                }
+                "BYMONTH" => {
+                    for month in value.split(',') {
+                        let month: u32 = parse_number(key, month)?;
+                        if !(1..=12).contains(&month) {
+                            return Err(invalid(format!("BYMONTH out of range: {}", month)));
+                        }
+                        by_month.push(month);
+                    }
+                }
+                "WKST" => {
+                    if !value.eq_ignore_ascii_case("MO") {
+                        return Err(invalid("only WKST=MO is supported".into()));
+                    }
+                }
+                other => return Err(invalid(format!("unsupported part '{}'", other))),
+            }
+        }
+
+        let frequency = frequency.ok_or_else(|| invalid("FREQ is required".into()))?;
+        if count.is_some() && until.is_some() {
+            return Err(invalid("COUNT and UNTIL cannot be combined".into()));
+        }
+        let has_ordinal = by_day.iter().any(|d| d.ordinal.is_some());
+        if has_ordinal && matches!(frequency, Frequency::Daily | Frequency::Weekly) {
+            return Err(invalid("BYDAY ordinals require FREQ=MONTHLY or FREQ=YEARLY".into()));
+        }
+        if frequency == Frequency::Yearly && !by_day.is_empty() && by_month.is_empty() {
+            return Err(invalid("BYDAY with FREQ=YEARLY requires BYMONTH".into()));
+        }
+
+        Ok(Self {
+            frequency,
+            interval,
+            by_day,
+            by_month_day,
+            by_month,
+            count,
+            until,
+        })
+    }
+
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// Occurrence dates of the series starting at `start` (DTSTART), up to and including `end`
+    pub fn occurrences(&self, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+        let last = match self.until {
+            Some(until) => until.min(end),
+            None => end,
+        };
+        let mut result = Vec::new();
+        let mut emitted = 0;
+
+        for period in 0..MAX_PERIODS {
+            let Some(period_start) = self.period_start(start, period) else {
+                break;
+            };
+            if period_start > last {
+                break;
+            }
+
+            let mut dates = self.expand(start, period_start);
+            dates.sort();
+            dates.dedup();
+            for date in dates.into_iter().filter(|d| *d >= start) {
+                if date > last || self.count.is_some_and(|c| emitted >= c) {
+                    return result;
+                }
+                emitted += 1;
+                result.push(date);
+            }
+        }
+
+        result
+    }
+
+    /// Whether `date` is an occurrence of the series starting at `start`
+    pub fn is_occurrence(&self, start: NaiveDate, date: NaiveDate) -> bool {
+        self.occurrences(start, date).last() == Some(&date)
+    }
+
+    fn period_start(&self, start: NaiveDate, period: u32) -> Option<NaiveDate> {
+        let step = period.checked_mul(self.interval)?;
+        match self.frequency {
+            Frequency::Daily => start.checked_add_signed(Duration::days(step as i64)),
+            Frequency::Weekly => {
+                let monday = start - Duration::days(start.weekday().num_days_from_monday() as i64);
+                monday.checked_add_signed(Duration::weeks(step as i64))
+            }
+            Frequency::Monthly => start.with_day(1)?.checked_add_months(Months::new(step)),
+            Frequency::Yearly => {
+                NaiveDate::from_ymd_opt(start.year().checked_add(step as i32)?, 1, 1)
+            }
+        }
+    }
+
+    /// Candidate dates within the period beginning at `period_start`
+    fn expand(&self, start: NaiveDate, period_start: NaiveDate) -> Vec<NaiveDate> {
+        match self.frequency {
+            Frequency::Daily => {
+                let date = period_start;
+                let matches_month_day = self.by_month_day.is_empty()
+                    || self.by_month_day.iter().any(|d| resolve_month_day(date.year(), date.month(), *d) == Some(date));
+                let matches_day = self.by_day.is_empty() || self.by_day.iter().any(|d| d.weekday == date.weekday());
+                if self.month_allowed(date.month()) && matches_month_day && matches_day {
+                    vec![date]
+                } else {
+                    Vec::new()
+                }
+            }
+            Frequency::Weekly => {
+                let weekdays: Vec<Weekday> = if self.by_day.is_empty() {
+                    vec![start.weekday()]
+                } else {
+                    self.by_day.iter().map(|d| d.weekday).collect()
+                };
+                weekdays
+                    .into_iter()
+                    .map(|wd| period_start + Duration::days(wd.num_days_from_monday() as i64))
+                    .filter(|date| self.month_allowed(date.month()))
+                    .collect()
+            }
+            Frequency::Monthly => {
+                if self.month_allowed(period_start.month()) {
+                    self.month_days(start, period_start.year(), period_start.month())
+                } else {
+                    Vec::new()
+                }
+            }
+            Frequency::Yearly => {
+                let months: Vec<u32> = if !self.by_month.is_empty() {
+                    self.by_month.clone()
+                } else if !self.by_month_day.is_empty() {
+                    (1..=12).collect()
+                } else {
+                    vec![start.month()]
+                };
+                months
+                    .into_iter()
+                    .flat_map(|month| self.month_days(start, period_start.year(), month))
+                    .collect()
+            }
+        }
+    }
+
+    fn month_days(&self, start: NaiveDate, year: i32, month: u32) -> Vec<NaiveDate> {
+        if !self.by_month_day.is_empty() {
+            return self
+                .by_month_day
+                .iter()
+                .filter_map(|day| resolve_month_day(year, month, *day))
+                .filter(|date| self.by_day.is_empty() || self.by_day.iter().any(|d| d.weekday == date.weekday()))
+                .collect();
+        }
+
+        if !self.by_day.is_empty() {
+            return self
+                .by_day
+                .iter()
+                .flat_map(|by_day| {
+                    let days: Vec<NaiveDate> = weekdays_in_month(year, month, by_day.weekday);
+                    match by_day.ordinal {
+                        None => days,
+                        Some(n) if n > 0 => days.get(n as usize - 1).copied().into_iter().collect(),
+                        Some(n) => days
+                            .len()
+                            .checked_sub(n.unsigned_abs() as usize)
+                            .and_then(|i| days.get(i).copied())
+                            .into_iter()
+                            .collect(),
+                    }
+                })
+                .collect();
+        }
+
+        // Months without the start day (e.g. the 31st) are skipped
+        NaiveDate::from_ymd_opt(year, month, start.day()).into_iter().collect()
+    }
+
+    fn month_allowed(&self, month: u32) -> bool {
+        self.by_month.is_empty() || self.by_month.contains(&month)
+    }
+}
+
+impl fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let frequency = match self.frequency {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        };
+        write!(f, "FREQ={}", frequency)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until.format("%Y%m%d"))?;
+        }
+        if !self.by_month.is_empty() {
+            let months: Vec<String> = self.by_month.iter().map(|m| m.to_string()).collect();
+            write!(f, ";BYMONTH={}", months.join(","))?;
+        }
+        if !self.by_month_day.is_empty() {
+            let days: Vec<String> = self.by_month_day.iter().map(|d| d.to_string()).collect();
+            write!(f, ";BYMONTHDAY={}", days.join(","))?;
+        }
+        if !self.by_day.is_empty() {
+            let days: Vec<String> = self
+                .by_day
+                .iter()
+                .map(|d| match d.ordinal {
+                    Some(n) => format!("{}{}", n, weekday_code(d.weekday)),
+                    None => weekday_code(d.weekday).to_string(),
+                })
+                .collect();
+            write!(f, ";BYDAY={}", days.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+fn invalid(message: String) -> DomainError {
+    DomainError::ValidationError(format!("Invalid recurrence rule: {}", message))
+}
+
+fn parse_number<T: std::str::FromStr>(key: &str, value: &str) -> Result<T, DomainError> {
+    value
+        .trim_start_matches('+')
+        .parse()
+        .map_err(|_| invalid(format!("{} must be a number, got '{}'", key, value)))
+}
+
+fn parse_until(value: &str) -> Result<NaiveDate, DomainError> {
+    let date = value.get(..8).unwrap_or(value);
+    NaiveDate::parse_from_str(date, "%Y%m%d")
+        .map_err(|_| invalid(format!("UNTIL must be YYYYMMDD or YYYYMMDDTHHMMSSZ, got '{}'", value)))
+}
+
+fn parse_by_day(value: &str) -> Result<ByDay, DomainError> {
+    let value = value.trim().to_ascii_uppercase();
+    // Weekday codes and ordinals are ASCII; checking first keeps the byte split below on a char boundary
+    if !value.is_ascii() || value.len() < 2 {
+        return Err(invalid(format!("invalid BYDAY '{}'", value)));
+    }
+    let (ordinal, code) = value.split_at(value.len() - 2);
+    let weekday = match code {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return Err(invalid(format!("invalid BYDAY '{}'", value))),
+    };
+    let ordinal = if ordinal.is_empty() {
+        None
+    } else {
+        let n: i32 = parse_number("BYDAY", ordinal)?;
+        if n == 0 || n.unsigned_abs() > 5 {
+            return Err(invalid(format!("BYDAY ordinal out of range: {}", n)));
+        }
+        Some(n)
+    };
+    Ok(ByDay { ordinal, weekday })
+}
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let first = NaiveDate::from_ymd_opt(year, month, 1).expect("valid month");
+    let next = first + Months::new(1);
+    (next - first).num_days() as u32
+}
+
+/// Day of month counting from the end when negative (-1 is the last day)
+fn resolve_month_day(year: i32, month: u32, day: i32) -> Option<NaiveDate> {
+    let length = days_in_month(year, month) as i32;
+    let day = if day < 0 { length + day + 1 } else { day };
+    if day < 1 || day > length {
+        return None;
+    }
+    NaiveDate::from_ymd_opt(year, month, day as u32)
+}
+
+fn weekdays_in_month(year: i32, month: u32, weekday: Weekday) -> Vec<NaiveDate> {
+    (1..=days_in_month(year, month))
+        .filter_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+        .filter(|date| date.weekday() == weekday)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_weekly_by_day() {
+        let rule = RecurrenceRule::parse("FREQ=WEEKLY;BYDAY=MO").unwrap();
+        // 2024-01-03 is a Wednesday
+        let dates = rule.occurrences(date(2024, 1, 3), date(2024, 1, 22));
+        assert_eq!(dates, vec![date(2024, 1, 8), date(2024, 1, 15), date(2024, 1, 22)]);
+    }
+
+    #[test]
+    fn test_weekly_defaults_to_start_weekday_with_interval() {
+        let rule = RecurrenceRule::parse("RRULE:FREQ=WEEKLY;INTERVAL=2").unwrap();
+        let dates = rule.occurrences(date(2024, 1, 3), date(2024, 2, 1));
+        assert_eq!(dates, vec![date(2024, 1, 3), date(2024, 1, 17), date(2024, 1, 31)]);
+    }
+
+    #[test]
+    fn test_daily_with_count() {
+        let rule = RecurrenceRule::parse("FREQ=DAILY;INTERVAL=2;COUNT=3").unwrap();
+        let dates = rule.occurrences(date(2024, 1, 1), date(2024, 12, 31));
+        assert_eq!(dates, vec![date(2024, 1, 1), date(2024, 1, 3), date(2024, 1, 5)]);
+    }
+
+    #[test]
+    fn test_monthly_last_day() {
+        let rule = RecurrenceRule::parse("FREQ=MONTHLY;BYMONTHDAY=-1").unwrap();
+        let dates = rule.occurrences(date(2024, 1, 15), date(2024, 3, 31));
+        assert_eq!(dates, vec![date(2024, 1, 31), date(2024, 2, 29), date(2024, 3, 31)]);
+    }
+
+    #[test]
+    fn test_monthly_first_monday() {
+        let rule = RecurrenceRule::parse("FREQ=MONTHLY;BYDAY=1MO").unwrap();
+        let dates = rule.occurrences(date(2024, 1, 1), date(2024, 3, 31));
+        assert_eq!(dates, vec![date(2024, 1, 1), date(2024, 2, 5), date(2024, 3, 4)]);
+    }
+
+    #[test]
+    fn test_monthly_skips_months_without_start_day() {
+        let rule = RecurrenceRule::parse("FREQ=MONTHLY").unwrap();
+        let dates = rule.occurrences(date(2024, 1, 31), date(2024, 4, 30));
+        assert_eq!(dates, vec![date(2024, 1, 31), date(2024, 3, 31)]);
+    }
+
+    #[test]
+    fn test_yearly_by_month_with_until() {
+        let rule = RecurrenceRule::parse("FREQ=YEARLY;BYMONTH=6;BYDAY=-1FR;UNTIL=20260101T000000Z").unwrap();
+        let dates = rule.occurrences(date(2024, 1, 1), date(2030, 1, 1));
+        assert_eq!(dates, vec![date(2024, 6, 28), date(2025, 6, 27)]);
+    }
+
+    #[test]
+    fn test_is_occurrence() {
+        let rule = RecurrenceRule::parse("FREQ=WEEKLY;BYDAY=MO,FR").unwrap();
+        assert!(rule.is_occurrence(date(2024, 1, 1), date(2024, 1, 5)));
+        assert!(!rule.is_occurrence(date(2024, 1, 1), date(2024, 1, 4)));
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        let rule = RecurrenceRule::parse("freq=monthly;interval=3;byday=-1fr").unwrap();
+        assert_eq!(rule.to_string(), "FREQ=MONTHLY;INTERVAL=3;BYDAY=-1FR");
+        assert_eq!(RecurrenceRule::parse(&rule.to_string()).unwrap(), rule);
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_rules() {
+        for rule in [
+            "BYDAY=MO",
+            "FREQ=HOURLY",
+            "FREQ=DAILY;INTERVAL=0",
+            "FREQ=WEEKLY;BYDAY=1MO",
+            "FREQ=DAILY;COUNT=2;UNTIL=20250101",
+            "FREQ=MONTHLY;BYMONTHDAY=32",
+            "FREQ=YEARLY;BYDAY=MO",
+            "FREQ=DAILY;BYSETPOS=1",
+            "FREQ=MONTHLY;BYMONTHDAY=-2147483648",
+            "FREQ=MONTHLY;BYDAY=-2147483648MO",
+        ] {
+            assert!(RecurrenceRule::parse(rule).is_err(), "{} should be rejected", rule);
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_non_ascii_by_day() {
+        for rule in ["FREQ=WEEKLY;BYDAY=éX", "FREQ=MONTHLY;BYDAY=1é", "FREQ=WEEKLY;BYDAY=MÖ"] {
+            assert!(RecurrenceRule::parse(rule).is_err(), "{} should be rejected", rule);
+        }
+    }
+}
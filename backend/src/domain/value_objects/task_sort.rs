@@ -0,0 +1,109 @@
+use crate::shared::error::DomainError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskSortField {
+    CreatedAt,
+    UpdatedAt,
+    DueDate,
+    Priority,
+    Status,
+    Title,
+    Rank,
+    EstimatedHours,
+}
+
+impl TaskSortField {
+    pub const ALL: [TaskSortField; 8] = [
+        TaskSortField::CreatedAt,
+        TaskSortField::UpdatedAt,
+        TaskSortField::DueDate,
+        TaskSortField::Priority,
+        TaskSortField::Status,
+        TaskSortField::Title,
+        TaskSortField::Rank,
+        TaskSortField::EstimatedHours,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskSortField::CreatedAt => "created_at",
+            TaskSortField::UpdatedAt => "updated_at",
+            TaskSortField::DueDate => "due_date",
+            TaskSortField::Priority => "priority",
+            TaskSortField::Status => "status",
+            TaskSortField::Title => "title",
+            TaskSortField::Rank => "rank",
+            TaskSortField::EstimatedHours => "estimated_hours",
+        }
+    }
+}
+
+/// One key of a task ordering such as `-priority`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskSort {
+    pub field: TaskSortField,
+    pub descending: bool,
+}
+
+impl TaskSort {
+    /// Newest first, used when no sort is requested
+    pub const DEFAULT: TaskSort = TaskSort {
+        field: TaskSortField::CreatedAt,
+        descending: true,
+    };
+
+    /// Parse comma separated keys, `-` marking descending: `-priority,due_date`.
+    /// Repeated fields keep their first occurrence.
+    pub fn parse_list(sort: Option<&str>) -> Result<Vec<TaskSort>, DomainError> {
+        let mut keys: Vec<TaskSort> = Vec::new();
+        for key in sort.unwrap_or_default().split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let (name, descending) = match key.strip_prefix('-') {
+                Some(name) => (name, true),
+                None => (key.strip_prefix('+').unwrap_or(key), false),
+            };
+            let field = TaskSortField::ALL
+                .into_iter()
+                .find(|f| f.as_str() == name)
+                .ok_or_else(|| DomainError::ValidationError(format!("Cannot sort tasks by '{}'", name)))?;
+
+            if !keys.iter().any(|k| k.field == field) {
+                keys.push(TaskSort { field, descending });
+            }
+        }
+
+        if keys.is_empty() {
+            keys.push(Self::DEFAULT);
+        }
+        Ok(keys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_multiple_keys() {
+        let keys = TaskSort::parse_list(Some("-priority, due_date,+title")).unwrap();
+        assert_eq!(
+            keys,
+            vec![
+                TaskSort { field: TaskSortField::Priority, descending: true },
+                TaskSort { field: TaskSortField::DueDate, descending: false },
+                TaskSort { field: TaskSortField::Title, descending: false },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_defaults_and_duplicates() {
+        assert_eq!(TaskSort::parse_list(None).unwrap(), vec![TaskSort::DEFAULT]);
+        assert_eq!(TaskSort::parse_list(Some("title,-title")).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_field() {
+        assert!(TaskSort::parse_list(Some("assignee")).is_err());
+        assert!(TaskSort::parse_list(Some("--priority")).is_err());
+    }
+}
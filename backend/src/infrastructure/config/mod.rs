@@ -8,6 +8,28 @@ pub struct AppConfig {
     pub jwt_secret: String,
     pub jwt_expiration: i64,
     pub allowed_origins: Vec<String>,
+    /// Seconds between runs of the recurring task generator
+    pub recurring_task_interval_secs: u64,
+    /// Seconds between sprint burndown snapshots; the last one of a day is kept
+    pub sprint_snapshot_interval_secs: u64,
+    /// Seconds between scans for due and overdue tasks and milestones
+    pub due_reminder_interval_secs: u64,
+    /// Lead times of due date reminders, e.g. `24h,1h`
+    pub due_reminder_lead_times: String,
+    /// Seconds between checks for SLA breaches
+    pub sla_check_interval_secs: u64,
+    /// Days deleted tasks and projects stay restorable
+    pub trash_retention_days: i64,
+    /// Seconds between purges of expired trash
+    pub trash_purge_interval_secs: u64,
+    /// Amber and red thresholds of project health factors, e.g. `0.1,0.25`
+    pub health_overdue_tasks: String,
+    pub health_overdue_milestones: String,
+    pub health_blocked_tasks: String,
+    pub health_schedule_slip_days: String,
+    pub health_budget_consumption: String,
+    /// Working hours per day when turning task estimates into schedule durations
+    pub schedule_hours_per_day: f32,
 }
 
 impl AppConfig {
@@ -27,6 +49,31 @@ impl AppConfig {
                 .parse()
                 .expect("JWT_EXPIRATION must be a number"),
             allowed_origins,
+            recurring_task_interval_secs: interval_secs("RECURRING_TASK_INTERVAL_SECS", "3600"),
+            sprint_snapshot_interval_secs: interval_secs("SPRINT_SNAPSHOT_INTERVAL_SECS", "3600"),
+            due_reminder_interval_secs: interval_secs("DUE_REMINDER_INTERVAL_SECS", "300"),
+            due_reminder_lead_times: std::env::var("DUE_REMINDER_LEAD_TIMES")
+                .unwrap_or_else(|_| "24h,1h".to_string()),
+            sla_check_interval_secs: interval_secs("SLA_CHECK_INTERVAL_SECS", "300"),
+            trash_retention_days: std::env::var("TRASH_RETENTION_DAYS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .expect("TRASH_RETENTION_DAYS must be a number"),
+            trash_purge_interval_secs: interval_secs("TRASH_PURGE_INTERVAL_SECS", "3600"),
+            health_overdue_tasks: std::env::var("HEALTH_OVERDUE_TASKS")
+                .unwrap_or_else(|_| "0.1,0.25".to_string()),
+            health_overdue_milestones: std::env::var("HEALTH_OVERDUE_MILESTONES")
+                .unwrap_or_else(|_| "1,2".to_string()),
+            health_blocked_tasks: std::env::var("HEALTH_BLOCKED_TASKS")
+                .unwrap_or_else(|_| "0.15,0.3".to_string()),
+            health_schedule_slip_days: std::env::var("HEALTH_SCHEDULE_SLIP_DAYS")
+                .unwrap_or_else(|_| "3,14".to_string()),
+            health_budget_consumption: std::env::var("HEALTH_BUDGET_CONSUMPTION")
+                .unwrap_or_else(|_| "0.9,1".to_string()),
+            schedule_hours_per_day: std::env::var("SCHEDULE_HOURS_PER_DAY")
+                .unwrap_or_else(|_| "8".to_string())
+                .parse()
+                .expect("SCHEDULE_HOURS_PER_DAY must be a number"),
         }
     }
 
@@ -39,3 +86,13 @@ impl AppConfig {
         AllowOrigin::list(origins)
     }
 }
+
+/// Seconds between runs of a background job; zero is rejected as the job timer can't tick at it
+fn interval_secs(name: &str, default: &str) -> u64 {
+    let secs: u64 = std::env::var(name)
+        .unwrap_or_else(|_| default.to_string())
+        .parse()
+        .unwrap_or_else(|_| panic!("{} must be a number", name));
+    assert!(secs > 0, "{} must be at least 1", name);
+    secs
+}
@@ -0,0 +1,78 @@
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::application::services::{
+    DueReminderAppService, RecurringTaskAppService, SlaAppService, SprintAppService, TrashAppService,
+};
+
+/// Periodically create the tasks of recurring task series that have come due
+pub fn spawn_recurring_task_generator(service: Arc<RecurringTaskAppService>, every: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(every);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = service.generate_due_tasks(Utc::now()).await {
+                tracing::error!(error = %e, "Recurring task generation failed");
+            }
+        }
+    });
+}
+
+/// Periodically record the day's story point totals of every active sprint
+pub fn spawn_sprint_snapshotter(service: Arc<SprintAppService>, every: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(every);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = service.capture_snapshots(Utc::now().date_naive()).await {
+                tracing::error!(error = %e, "Sprint snapshot failed");
+            }
+        }
+    });
+}
+
+/// Periodically notify about tasks and milestones that are due soon or overdue
+pub fn spawn_due_reminder_scheduler(service: Arc<DueReminderAppService>, every: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(every);
+        loop {
+            ticker.tick().await;
+            match service.send_due_reminders(Utc::now()).await {
+                Ok(0) => {}
+                Ok(sent) => tracing::info!(sent, "Due date reminders sent"),
+                Err(e) => tracing::error!(error = %e, "Due date reminders failed"),
+            }
+        }
+    });
+}
+
+/// Periodically flag SLA breaches and escalate them
+pub fn spawn_sla_monitor(service: Arc<SlaAppService>, every: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(every);
+        loop {
+            ticker.tick().await;
+            match service.check_breaches(Utc::now()).await {
+                Ok(0) => {}
+                Ok(breached) => tracing::info!(breached, "SLA breaches escalated"),
+                Err(e) => tracing::error!(error = %e, "SLA breach check failed"),
+            }
+        }
+    });
+}
+
+/// Periodically remove items that outlived their retention in the trash
+pub fn spawn_trash_purger(service: Arc<TrashAppService>, every: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(every);
+        loop {
+            ticker.tick().await;
+            match service.purge_expired(Utc::now()).await {
+                Ok(0) => {}
+                Ok(purged) => tracing::info!(purged, "Trash purged"),
+                Err(e) => tracing::error!(error = %e, "Trash purge failed"),
+            }
+        }
+    });
+}
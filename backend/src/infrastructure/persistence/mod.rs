@@ -1,19 +1,51 @@
 mod pg_activity_log_repository;
 mod pg_attachment_repository;
+mod pg_budget_repository;
+mod pg_change_history_repository;
+mod pg_checklist_repository;
+mod pg_custom_field_repository;
+mod pg_due_reminder_repository;
+mod pg_milestone_repository;
 mod pg_notification_repository;
 mod pg_project_repository;
+mod pg_project_template_repository;
+mod pg_recurring_task_repository;
+mod pg_search_repository;
+mod pg_sla_repository;
+mod pg_sprint_repository;
 mod pg_tag_repository;
+mod pg_task_comment_repository;
+mod pg_task_dependency_repository;
 mod pg_task_repository;
+mod pg_task_template_repository;
+mod pg_task_watcher_repository;
 mod pg_team_repository;
 mod pg_time_log_repository;
 mod pg_user_repository;
+mod pg_workflow_repository;
 
 pub use pg_activity_log_repository::PgActivityLogRepository;
 pub use pg_attachment_repository::PgAttachmentRepository;
+pub use pg_budget_repository::PgBudgetRepository;
+pub use pg_change_history_repository::PgChangeHistoryRepository;
+pub use pg_checklist_repository::PgChecklistRepository;
+pub use pg_custom_field_repository::PgCustomFieldRepository;
+pub use pg_due_reminder_repository::PgDueReminderRepository;
+pub use pg_milestone_repository::PgMilestoneRepository;
 pub use pg_notification_repository::PgNotificationRepository;
 pub use pg_project_repository::PgProjectRepository;
+pub use pg_project_template_repository::PgProjectTemplateRepository;
+pub use pg_recurring_task_repository::PgRecurringTaskRepository;
+pub use pg_search_repository::PgSearchRepository;
+pub use pg_sla_repository::PgSlaRepository;
+pub use pg_sprint_repository::PgSprintRepository;
 pub use pg_tag_repository::PgTagRepository;
+pub use pg_task_comment_repository::PgTaskCommentRepository;
+pub use pg_task_dependency_repository::PgTaskDependencyRepository;
 pub use pg_task_repository::PgTaskRepository;
+pub use pg_task_template_repository::PgTaskTemplateRepository;
+pub use pg_task_watcher_repository::PgTaskWatcherRepository;
 pub use pg_team_repository::PgTeamRepository;
 pub use pg_time_log_repository::PgTimeLogRepository;
 pub use pg_user_repository::PgUserRepository;
+pub use pg_workflow_repository::PgWorkflowRepository;
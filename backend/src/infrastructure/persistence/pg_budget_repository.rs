@@ -0,0 +1,258 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{CostRate, Expense, LabourEntry};
+use crate::domain::repositories::BudgetRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct CostRateRow {
+    id: Uuid,
+    user_id: Uuid,
+    hourly_rate: Decimal,
+    effective_from: NaiveDate,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<CostRateRow> for CostRate {
+    fn from(row: CostRateRow) -> Self {
+        CostRate {
+            id: row.id,
+            user_id: row.user_id,
+            hourly_rate: row.hourly_rate,
+            effective_from: row.effective_from,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct ExpenseRow {
+    id: Uuid,
+    project_id: Uuid,
+    category: String,
+    description: Option<String>,
+    amount: Decimal,
+    incurred_on: NaiveDate,
+    created_by: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ExpenseRow> for Expense {
+    fn from(row: ExpenseRow) -> Self {
+        Expense {
+            id: row.id,
+            project_id: row.project_id,
+            category: row.category,
+            description: row.description,
+            amount: row.amount,
+            incurred_on: row.incurred_on,
+            created_by: row.created_by,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct LabourEntryRow {
+    user_id: Uuid,
+    user_name: String,
+    team_ids: Vec<Uuid>,
+    team_names: Vec<String>,
+    date: NaiveDate,
+    hours: f32,
+}
+
+impl From<LabourEntryRow> for LabourEntry {
+    fn from(row: LabourEntryRow) -> Self {
+        LabourEntry {
+            user_id: row.user_id,
+            user_name: row.user_name,
+            teams: row.team_ids.into_iter().zip(row.team_names).collect(),
+            date: row.date,
+            hours: row.hours,
+        }
+    }
+}
+
+pub struct PgBudgetRepository {
+    pool: PgPool,
+}
+
+impl PgBudgetRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BudgetRepository for PgBudgetRepository {
+    async fn find_rate(&self, id: Uuid) -> Result<Option<CostRate>, DomainError> {
+        let row = sqlx::query_as::<_, CostRateRow>("SELECT * FROM cost_rates WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_rates(&self, user_id: Uuid) -> Result<Vec<CostRate>, DomainError> {
+        let rows = sqlx::query_as::<_, CostRateRow>(
+            "SELECT * FROM cost_rates WHERE user_id = $1 ORDER BY effective_from DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_project_rates(&self, project_id: Uuid) -> Result<Vec<CostRate>, DomainError> {
+        let rows = sqlx::query_as::<_, CostRateRow>(
+            r#"
+            SELECT r.* FROM cost_rates r
+            WHERE r.user_id IN (
+                SELECT tl.user_id FROM time_logs tl
+                INNER JOIN tasks t ON t.id = tl.task_id
+                WHERE t.project_id = $1
+            )
+            "#,
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create_rate(&self, rate: &CostRate) -> Result<CostRate, DomainError> {
+        let row = sqlx::query_as::<_, CostRateRow>(
+            r#"
+            INSERT INTO cost_rates (id, user_id, hourly_rate, effective_from, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(rate.id)
+        .bind(rate.user_id)
+        .bind(rate.hourly_rate)
+        .bind(rate.effective_from)
+        .bind(rate.created_at)
+        .bind(rate.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete_rate(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM cost_rates WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_expense(&self, id: Uuid) -> Result<Option<Expense>, DomainError> {
+        let row = sqlx::query_as::<_, ExpenseRow>("SELECT * FROM expenses WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_expenses(&self, project_id: Uuid) -> Result<Vec<Expense>, DomainError> {
+        let rows = sqlx::query_as::<_, ExpenseRow>(
+            "SELECT * FROM expenses WHERE project_id = $1 ORDER BY incurred_on DESC, created_at DESC",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create_expense(&self, expense: &Expense) -> Result<Expense, DomainError> {
+        let row = sqlx::query_as::<_, ExpenseRow>(
+            r#"
+            INSERT INTO expenses (id, project_id, category, description, amount, incurred_on, created_by, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING *
+            "#,
+        )
+        .bind(expense.id)
+        .bind(expense.project_id)
+        .bind(&expense.category)
+        .bind(&expense.description)
+        .bind(expense.amount)
+        .bind(expense.incurred_on)
+        .bind(expense.created_by)
+        .bind(expense.created_at)
+        .bind(expense.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn update_expense(&self, expense: &Expense) -> Result<Expense, DomainError> {
+        let row = sqlx::query_as::<_, ExpenseRow>(
+            r#"
+            UPDATE expenses
+            SET category = $1, description = $2, amount = $3, incurred_on = $4
+            WHERE id = $5
+            RETURNING *
+            "#,
+        )
+        .bind(&expense.category)
+        .bind(&expense.description)
+        .bind(expense.amount)
+        .bind(expense.incurred_on)
+        .bind(expense.id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete_expense(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM expenses WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_labour(&self, project_id: Uuid) -> Result<Vec<LabourEntry>, DomainError> {
+        let rows = sqlx::query_as::<_, LabourEntryRow>(
+            r#"
+            SELECT tl.user_id, u.name AS user_name, tl.date, SUM(tl.hours)::real AS hours,
+                   ARRAY(SELECT tm.team_id FROM team_members tm INNER JOIN teams tt ON tt.id = tm.team_id
+                         WHERE tm.user_id = tl.user_id ORDER BY tt.name, tt.id) AS team_ids,
+                   ARRAY(SELECT tt.name FROM team_members tm INNER JOIN teams tt ON tt.id = tm.team_id
+                         WHERE tm.user_id = tl.user_id ORDER BY tt.name, tt.id) AS team_names
+            FROM time_logs tl
+            INNER JOIN tasks t ON t.id = tl.task_id
+            INNER JOIN users u ON u.id = tl.user_id
+            WHERE t.project_id = $1 AND t.deleted_at IS NULL
+            GROUP BY tl.user_id, u.name, tl.date
+            ORDER BY tl.date ASC
+            "#,
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+}
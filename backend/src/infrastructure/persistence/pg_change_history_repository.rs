@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::types::Json;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{ChangeAction, ChangeEntityType, ChangeRecord, FieldChange};
+use crate::domain::repositories::ChangeHistoryRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct ChangeRecordRow {
+    id: Uuid,
+    entity_type: ChangeEntityType,
+    entity_id: Uuid,
+    project_id: Uuid,
+    action: ChangeAction,
+    changes: Json<Vec<FieldChange>>,
+    changed_by: Option<Uuid>,
+    changed_by_name: Option<String>,
+    changed_at: DateTime<Utc>,
+}
+
+impl From<ChangeRecordRow> for ChangeRecord {
+    fn from(row: ChangeRecordRow) -> Self {
+        ChangeRecord {
+            id: row.id,
+            entity_type: row.entity_type,
+            entity_id: row.entity_id,
+            project_id: row.project_id,
+            action: row.action,
+            changes: row.changes.0,
+            changed_by: row.changed_by,
+            changed_by_name: row.changed_by_name,
+            changed_at: row.changed_at,
+        }
+    }
+}
+
+pub struct PgChangeHistoryRepository {
+    pool: PgPool,
+}
+
+impl PgChangeHistoryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ChangeHistoryRepository for PgChangeHistoryRepository {
+    async fn record(&self, records: &[ChangeRecord]) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await?;
+
+        for record in records {
+            sqlx::query(
+                r#"
+                INSERT INTO change_history (id, entity_type, entity_id, project_id, action, changes, changed_by, changed_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                "#,
+            )
+            .bind(record.id)
+            .bind(record.entity_type)
+            .bind(record.entity_id)
+            .bind(record.project_id)
+            .bind(record.action)
+            .bind(Json(&record.changes))
+            .bind(record.changed_by)
+            .bind(record.changed_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_by_entity(
+        &self,
+        entity_type: ChangeEntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<ChangeRecord>, DomainError> {
+        let rows = sqlx::query_as::<_, ChangeRecordRow>(
+            r#"
+            SELECT h.id, h.entity_type, h.entity_id, h.project_id, h.action, h.changes,
+                   h.changed_by, u.name as changed_by_name, h.changed_at
+            FROM change_history h
+            LEFT JOIN users u ON h.changed_by = u.id
+            WHERE h.entity_type = $1 AND h.entity_id = $2
+            ORDER BY h.changed_at DESC
+            "#,
+        )
+        .bind(entity_type)
+        .bind(entity_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,160 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::ChecklistItem;
+use crate::domain::repositories::ChecklistRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct ChecklistItemRow {
+    id: Uuid,
+    task_id: Uuid,
+    content: String,
+    is_checked: bool,
+    position: i32,
+    checked_by: Option<Uuid>,
+    checked_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ChecklistItemRow> for ChecklistItem {
+    fn from(row: ChecklistItemRow) -> Self {
+        ChecklistItem {
+            id: row.id,
+            task_id: row.task_id,
+            content: row.content,
+            is_checked: row.is_checked,
+            position: row.position,
+            checked_by: row.checked_by,
+            checked_at: row.checked_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgChecklistRepository {
+    pool: PgPool,
+}
+
+impl PgChecklistRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ChecklistRepository for PgChecklistRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ChecklistItem>, DomainError> {
+        let row = sqlx::query_as::<_, ChecklistItemRow>("SELECT * FROM task_checklist_items WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_task(&self, task_id: Uuid) -> Result<Vec<ChecklistItem>, DomainError> {
+        let rows = sqlx::query_as::<_, ChecklistItemRow>(
+            "SELECT * FROM task_checklist_items WHERE task_id = $1 ORDER BY position ASC, created_at ASC",
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, item: &ChecklistItem) -> Result<ChecklistItem, DomainError> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("UPDATE task_checklist_items SET position = position + 1 WHERE task_id = $1 AND position >= $2")
+            .bind(item.task_id)
+            .bind(item.position)
+            .execute(&mut *tx)
+            .await?;
+
+        let row = sqlx::query_as::<_, ChecklistItemRow>(
+            r#"
+            INSERT INTO task_checklist_items (id, task_id, content, is_checked, position, checked_by, checked_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING *
+            "#,
+        )
+        .bind(item.id)
+        .bind(item.task_id)
+        .bind(&item.content)
+        .bind(item.is_checked)
+        .bind(item.position)
+        .bind(item.checked_by)
+        .bind(item.checked_at)
+        .bind(item.created_at)
+        .bind(item.updated_at)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(row.into())
+    }
+
+    async fn update(&self, item: &ChecklistItem) -> Result<ChecklistItem, DomainError> {
+        let row = sqlx::query_as::<_, ChecklistItemRow>(
+            r#"
+            UPDATE task_checklist_items
+            SET content = $1, is_checked = $2, checked_by = $3, checked_at = $4
+            WHERE id = $5
+            RETURNING *
+            "#,
+        )
+        .bind(&item.content)
+        .bind(item.is_checked)
+        .bind(item.checked_by)
+        .bind(item.checked_at)
+        .bind(item.id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await?;
+
+        let deleted: Option<(Uuid, i32)> =
+            sqlx::query_as("DELETE FROM task_checklist_items WHERE id = $1 RETURNING task_id, position")
+                .bind(id)
+                .fetch_optional(&mut *tx)
+                .await?;
+
+        if let Some((task_id, position)) = deleted {
+            sqlx::query("UPDATE task_checklist_items SET position = position - 1 WHERE task_id = $1 AND position > $2")
+                .bind(task_id)
+                .bind(position)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn reorder(&self, task_id: Uuid, item_ids: &[Uuid]) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            UPDATE task_checklist_items AS i
+            SET position = o.ordinality - 1
+            FROM UNNEST($2::uuid[]) WITH ORDINALITY AS o(id, ordinality)
+            WHERE i.id = o.id AND i.task_id = $1
+            "#,
+        )
+        .bind(task_id)
+        .bind(item_ids)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{CustomField, CustomFieldType};
+use crate::domain::repositories::CustomFieldRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct CustomFieldRow {
+    id: Uuid,
+    project_id: Uuid,
+    name: String,
+    field_type: CustomFieldType,
+    options: Vec<String>,
+    required: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<CustomFieldRow> for CustomField {
+    fn from(row: CustomFieldRow) -> Self {
+        CustomField {
+            id: row.id,
+            project_id: row.project_id,
+            name: row.name,
+            field_type: row.field_type,
+            options: row.options,
+            required: row.required,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgCustomFieldRepository {
+    pool: PgPool,
+}
+
+impl PgCustomFieldRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CustomFieldRepository for PgCustomFieldRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<CustomField>, DomainError> {
+        let row = sqlx::query_as::<_, CustomFieldRow>("SELECT * FROM custom_fields WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<CustomField>, DomainError> {
+        let rows = sqlx::query_as::<_, CustomFieldRow>(
+            "SELECT * FROM custom_fields WHERE project_id = $1 ORDER BY created_at ASC",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, field: &CustomField) -> Result<CustomField, DomainError> {
+        let row = sqlx::query_as::<_, CustomFieldRow>(
+            r#"
+            INSERT INTO custom_fields (id, project_id, name, field_type, options, required, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(field.id)
+        .bind(field.project_id)
+        .bind(&field.name)
+        .bind(field.field_type)
+        .bind(&field.options)
+        .bind(field.required)
+        .bind(field.created_at)
+        .bind(field.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn update(&self, field: &CustomField) -> Result<CustomField, DomainError> {
+        let row = sqlx::query_as::<_, CustomFieldRow>(
+            r#"
+            UPDATE custom_fields
+            SET name = $1, options = $2, required = $3
+            WHERE id = $4
+            RETURNING *
+            "#,
+        )
+        .bind(&field.name)
+        .bind(&field.options)
+        .bind(field.required)
+        .bind(field.id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            r#"
+            UPDATE tasks SET custom_fields = custom_fields - $1::text
+            WHERE custom_fields ? $1::text
+            "#,
+        )
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query("DELETE FROM custom_fields WHERE id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn is_option_used(&self, id: Uuid, option: &str) -> Result<bool, DomainError> {
+        let used: bool = sqlx::query_scalar(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM tasks
+                WHERE custom_fields @> jsonb_build_object($1::text, $2::text)
+                   OR custom_fields @> jsonb_build_object($1::text, jsonb_build_array($2::text))
+            )
+            "#,
+        )
+        .bind(id)
+        .bind(option)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(used)
+    }
+}
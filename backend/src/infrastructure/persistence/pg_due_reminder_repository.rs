@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::repositories::{DueItem, DueItemType, DueReminderRepository};
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct DueItemRow {
+    item_type: DueItemType,
+    id: Uuid,
+    project_id: Uuid,
+    title: String,
+    due_date: DateTime<Utc>,
+    recipients: Vec<Uuid>,
+    last_lead_minutes: Option<i32>,
+}
+
+impl From<DueItemRow> for DueItem {
+    fn from(row: DueItemRow) -> Self {
+        let mut recipients = row.recipients;
+        recipients.sort();
+        recipients.dedup();
+        DueItem {
+            item_type: row.item_type,
+            id: row.id,
+            project_id: row.project_id,
+            title: row.title,
+            due_date: row.due_date,
+            recipients,
+            last_lead_minutes: row.last_lead_minutes,
+        }
+    }
+}
+
+pub struct PgDueReminderRepository {
+    pool: PgPool,
+}
+
+impl PgDueReminderRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DueReminderRepository for PgDueReminderRepository {
+    async fn find_due_items(&self, until: DateTime<Utc>) -> Result<Vec<DueItem>, DomainError> {
+        // Milestones remind the owner and everyone assigned to their unfinished tasks
+        let rows = sqlx::query_as::<_, DueItemRow>(
+            r#"
+            WITH items AS (
+                SELECT 'task'::due_item_type AS item_type, t.id, t.project_id, t.title, t.due_date,
+                       array_remove(ARRAY[t.assignee_id, p.owner_id], NULL) AS recipients
+                FROM tasks t
+                INNER JOIN projects p ON p.id = t.project_id
+                WHERE t.due_date <= $1 AND t.status <> 'done' AND t.deleted_at IS NULL
+                UNION ALL
+                SELECT 'milestone'::due_item_type, m.id, m.project_id, m.name, m.due_date,
+                       ARRAY(
+                           SELECT t.assignee_id FROM tasks t
+                           WHERE t.milestone_id = m.id AND t.status <> 'done' AND t.assignee_id IS NOT NULL
+                             AND t.deleted_at IS NULL
+                       ) || p.owner_id
+                FROM milestones m
+                INNER JOIN projects p ON p.id = m.project_id
+                WHERE m.due_date <= $1 AND NOT m.completed AND p.deleted_at IS NULL
+            )
+            SELECT i.*, r.last_lead_minutes
+            FROM items i
+            LEFT JOIN LATERAL (
+                SELECT MIN(dr.lead_minutes) AS last_lead_minutes FROM due_reminders dr
+                WHERE dr.item_type = i.item_type AND dr.item_id = i.id AND dr.due_date = i.due_date
+            ) r ON TRUE
+            WHERE r.last_lead_minutes IS DISTINCT FROM 0
+            "#,
+        )
+        .bind(until)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn record(&self, item: &DueItem, lead_minutes: i32) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO due_reminders (item_type, item_id, due_date, lead_minutes)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(item.item_type)
+        .bind(item.id)
+        .bind(item.due_date)
+        .bind(lead_minutes)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,119 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::Milestone;
+use crate::domain::repositories::MilestoneRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct MilestoneRow {
+    id: Uuid,
+    project_id: Uuid,
+    name: String,
+    description: Option<String>,
+    due_date: Option<DateTime<Utc>>,
+    completed: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<MilestoneRow> for Milestone {
+    fn from(row: MilestoneRow) -> Self {
+        Milestone {
+            id: row.id,
+            project_id: row.project_id,
+            name: row.name,
+            description: row.description,
+            due_date: row.due_date,
+            completed: row.completed,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgMilestoneRepository {
+    pool: PgPool,
+}
+
+impl PgMilestoneRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MilestoneRepository for PgMilestoneRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Milestone>, DomainError> {
+        let row = sqlx::query_as::<_, MilestoneRow>("SELECT * FROM milestones WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<Milestone>, DomainError> {
+        let rows = sqlx::query_as::<_, MilestoneRow>(
+            "SELECT * FROM milestones WHERE project_id = $1 ORDER BY due_date NULLS LAST, name",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, milestone: &Milestone) -> Result<Milestone, DomainError> {
+        let row = sqlx::query_as::<_, MilestoneRow>(
+            r#"
+            INSERT INTO milestones (id, project_id, name, description, due_date, completed, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(milestone.id)
+        .bind(milestone.project_id)
+        .bind(&milestone.name)
+        .bind(&milestone.description)
+        .bind(milestone.due_date)
+        .bind(milestone.completed)
+        .bind(milestone.created_at)
+        .bind(milestone.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn update(&self, milestone: &Milestone) -> Result<Milestone, DomainError> {
+        let row = sqlx::query_as::<_, MilestoneRow>(
+            r#"
+            UPDATE milestones
+            SET name = $1, description = $2, due_date = $3, completed = $4
+            WHERE id = $5
+            RETURNING *
+            "#,
+        )
+        .bind(&milestone.name)
+        .bind(&milestone.description)
+        .bind(milestone.due_date)
+        .bind(milestone.completed)
+        .bind(milestone.id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM milestones WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,12 +1,15 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
+use serde_json::Value;
+use sqlx::types::Json;
 use sqlx::{FromRow, PgPool};
+use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::domain::entities::{Milestone, Project, Task};
+use crate::domain::entities::{Project, ProjectMember, ProjectSkeleton, Task, Trashed};
 use crate::domain::repositories::ProjectRepository;
-use crate::domain::value_objects::{Priority, ProjectStatus, TaskStatus};
+use crate::domain::value_objects::{Priority, ProjectRole, ProjectStatus, TaskStatus};
 use crate::shared::DomainError;
 
 #[derive(Debug, FromRow)]
@@ -42,19 +45,42 @@ impl From<ProjectRow> for Project {
     }
 }
 
+#[derive(Debug, FromRow)]
+struct TrashedProjectRow {
+    #[sqlx(flatten)]
+    project: ProjectRow,
+    deleted_at: DateTime<Utc>,
+}
+
+impl From<TrashedProjectRow> for Trashed<Project> {
+    fn from(row: TrashedProjectRow) -> Self {
+        Trashed {
+            item: row.project.into(),
+            deleted_at: row.deleted_at,
+        }
+    }
+}
+
 #[derive(Debug, FromRow)]
 struct TaskRow {
     id: Uuid,
     project_id: Uuid,
+    parent_id: Option<Uuid>,
     milestone_id: Option<Uuid>,
+    sprint_id: Option<Uuid>,
     title: String,
     description: Option<String>,
     status: TaskStatus,
+    workflow_status: String,
     priority: Priority,
     assignee_id: Option<Uuid>,
+    start_date: Option<DateTime<Utc>>,
     due_date: Option<DateTime<Utc>>,
     estimated_hours: Option<f32>,
     actual_hours: Option<f32>,
+    story_points: Option<i32>,
+    rank: f64,
+    custom_fields: Json<HashMap<Uuid, Value>>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -64,15 +90,22 @@ impl From<TaskRow> for Task {
         Task {
             id: row.id,
             project_id: row.project_id,
+            parent_id: row.parent_id,
             milestone_id: row.milestone_id,
+            sprint_id: row.sprint_id,
             title: row.title,
             description: row.description,
             status: row.status,
+            workflow_status: row.workflow_status,
             priority: row.priority,
             assignee_id: row.assignee_id,
+            start_date: row.start_date,
             due_date: row.due_date,
             estimated_hours: row.estimated_hours,
             actual_hours: row.actual_hours,
+            story_points: row.story_points,
+            rank: row.rank,
+            custom_fields: row.custom_fields.0,
             created_at: row.created_at,
             updated_at: row.updated_at,
         }
@@ -80,28 +113,22 @@ impl From<TaskRow> for Task {
 }
 
 #[derive(Debug, FromRow)]
-struct MilestoneRow {
+struct ProjectMemberRow {
     id: Uuid,
     project_id: Uuid,
-    name: String,
-    description: Option<String>,
-    due_date: Option<DateTime<Utc>>,
-    completed: bool,
-    created_at: DateTime<Utc>,
-    updated_at: DateTime<Utc>,
+    user_id: Uuid,
+    role: ProjectRole,
+    joined_at: DateTime<Utc>,
 }
 
-impl From<MilestoneRow> for Milestone {
-    fn from(row: MilestoneRow) -> Self {
-        Milestone {
+impl From<ProjectMemberRow> for ProjectMember {
+    fn from(row: ProjectMemberRow) -> Self {
+        ProjectMember {
             id: row.id,
             project_id: row.project_id,
-            name: row.name,
-            description: row.description,
-            due_date: row.due_date,
-            completed: row.completed,
-            created_at: row.created_at,
-            updated_at: row.updated_at,
+            user_id: row.user_id,
+            role: row.role,
+            joined_at: row.joined_at,
         }
     }
 }
@@ -119,7 +146,7 @@ impl PgProjectRepository {
 #[async_trait]
 impl ProjectRepository for PgProjectRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Project>, DomainError> {
-        let row = sqlx::query_as::<_, ProjectRow>("SELECT * FROM projects WHERE id = $1")
+        let row = sqlx::query_as::<_, ProjectRow>("SELECT * FROM projects WHERE id = $1 AND deleted_at IS NULL")
             .bind(id)
             .fetch_optional(&self.pool)
             .await?;
@@ -129,7 +156,7 @@ impl ProjectRepository for PgProjectRepository {
 
     async fn find_all(&self) -> Result<Vec<Project>, DomainError> {
         let rows =
-            sqlx::query_as::<_, ProjectRow>("SELECT * FROM projects ORDER BY created_at DESC")
+            sqlx::query_as::<_, ProjectRow>("SELECT * FROM projects WHERE deleted_at IS NULL ORDER BY created_at DESC")
                 .fetch_all(&self.pool)
                 .await?;
 
@@ -138,7 +165,7 @@ impl ProjectRepository for PgProjectRepository {
 
     async fn find_by_owner(&self, owner_id: Uuid) -> Result<Vec<Project>, DomainError> {
         let rows = sqlx::query_as::<_, ProjectRow>(
-            "SELECT * FROM projects WHERE owner_id = $1 ORDER BY created_at DESC",
+            "SELECT * FROM projects WHERE owner_id = $1 AND deleted_at IS NULL ORDER BY created_at DESC",
         )
         .bind(owner_id)
         .fetch_all(&self.pool)
@@ -152,7 +179,7 @@ impl ProjectRepository for PgProjectRepository {
             r#"
             SELECT DISTINCT p.* FROM projects p
             LEFT JOIN project_members pm ON p.id = pm.project_id
-            WHERE p.owner_id = $1 OR pm.user_id = $1
+            WHERE p.deleted_at IS NULL AND (p.owner_id = $1 OR pm.user_id = $1)
             ORDER BY p.created_at DESC
             "#,
         )
@@ -168,7 +195,7 @@ impl ProjectRepository for PgProjectRepository {
             r#"
             SELECT 1 FROM projects p
             LEFT JOIN project_members pm ON p.id = pm.project_id
-            WHERE p.id = $1 AND (p.owner_id = $2 OR pm.user_id = $2)
+            WHERE p.id = $1 AND p.deleted_at IS NULL AND (p.owner_id = $2 OR pm.user_id = $2)
             LIMIT 1
             "#,
         )
@@ -192,6 +219,24 @@ impl ProjectRepository for PgProjectRepository {
         Ok(result.is_some())
     }
 
+    async fn has_role(&self, project_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        let result: Option<(i64,)> = sqlx::query_as(
+            r#"
+            SELECT 1 FROM projects p
+            LEFT JOIN project_members pm ON p.id = pm.project_id AND pm.user_id = $2
+            WHERE p.id = $1 AND p.deleted_at IS NULL AND (p.owner_id = $2 OR pm.role >= $3)
+            LIMIT 1
+            "#,
+        )
+        .bind(project_id)
+        .bind(user_id)
+        .bind(role)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result.is_some())
+    }
+
     async fn create(&self, project: &Project) -> Result<Project, DomainError> {
         let row = sqlx::query_as::<_, ProjectRow>(
             r#"
@@ -217,6 +262,153 @@ impl ProjectRepository for PgProjectRepository {
         Ok(row.into())
     }
 
+    async fn create_skeleton(&self, skeleton: &ProjectSkeleton) -> Result<Project, DomainError> {
+        let project = &skeleton.project;
+        let mut tx = self.pool.begin().await?;
+
+        let row = sqlx::query_as::<_, ProjectRow>(
+            r#"
+            INSERT INTO projects (id, name, description, status, priority, start_date, end_date, budget, owner_id, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING *
+            "#,
+        )
+        .bind(project.id)
+        .bind(&project.name)
+        .bind(&project.description)
+        .bind(&project.status)
+        .bind(&project.priority)
+        .bind(project.start_date)
+        .bind(project.end_date)
+        .bind(project.budget)
+        .bind(project.owner_id)
+        .bind(project.created_at)
+        .bind(project.updated_at)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for member in &skeleton.members {
+            sqlx::query(
+                r#"
+                INSERT INTO project_members (id, project_id, user_id, role, joined_at)
+                SELECT $1, $2, id, $4, $5 FROM users WHERE id = $3
+                "#,
+            )
+            .bind(member.id)
+            .bind(member.project_id)
+            .bind(member.user_id)
+            .bind(member.role)
+            .bind(member.joined_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for milestone in &skeleton.milestones {
+            sqlx::query(
+                r#"
+                INSERT INTO milestones (id, project_id, name, description, due_date, completed, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                "#,
+            )
+            .bind(milestone.id)
+            .bind(milestone.project_id)
+            .bind(&milestone.name)
+            .bind(&milestone.description)
+            .bind(milestone.due_date)
+            .bind(milestone.completed)
+            .bind(milestone.created_at)
+            .bind(milestone.updated_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for task in &skeleton.tasks {
+            sqlx::query(
+                r#"
+                INSERT INTO tasks (id, project_id, parent_id, milestone_id, sprint_id, title, description, status, workflow_status, priority, assignee_id, start_date, due_date, estimated_hours, actual_hours, story_points, rank, custom_fields, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20)
+                "#,
+            )
+            .bind(task.id)
+            .bind(task.project_id)
+            .bind(task.parent_id)
+            .bind(task.milestone_id)
+            .bind(task.sprint_id)
+            .bind(&task.title)
+            .bind(&task.description)
+            .bind(&task.status)
+            .bind(&task.workflow_status)
+            .bind(&task.priority)
+            .bind(task.assignee_id)
+            .bind(task.start_date)
+            .bind(task.due_date)
+            .bind(task.estimated_hours)
+            .bind(task.actual_hours)
+            .bind(task.story_points)
+            .bind(task.rank)
+            .bind(Json(&task.custom_fields))
+            .bind(task.created_at)
+            .bind(task.updated_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for item in &skeleton.checklist_items {
+            sqlx::query(
+                r#"
+                INSERT INTO task_checklist_items (id, task_id, content, is_checked, position, checked_by, checked_at, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                "#,
+            )
+            .bind(item.id)
+            .bind(item.task_id)
+            .bind(&item.content)
+            .bind(item.is_checked)
+            .bind(item.position)
+            .bind(item.checked_by)
+            .bind(item.checked_at)
+            .bind(item.created_at)
+            .bind(item.updated_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for task_tag in &skeleton.task_tags {
+            sqlx::query(
+                r#"
+                INSERT INTO task_tags (id, task_id, tag_id, created_at)
+                SELECT $1, $2, id, $4 FROM tags WHERE id = $3
+                "#,
+            )
+            .bind(task_tag.id)
+            .bind(task_tag.task_id)
+            .bind(task_tag.tag_id)
+            .bind(task_tag.created_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for dependency in &skeleton.dependencies {
+            sqlx::query(
+                r#"
+                INSERT INTO task_dependencies (id, blocking_task_id, blocked_task_id, created_by, created_at)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+            )
+            .bind(dependency.id)
+            .bind(dependency.blocking_task_id)
+            .bind(dependency.blocked_task_id)
+            .bind(dependency.created_by)
+            .bind(dependency.created_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(row.into())
+    }
+
     async fn update(&self, project: &Project) -> Result<Project, DomainError> {
         let row = sqlx::query_as::<_, ProjectRow>(
             r#"
@@ -242,17 +434,89 @@ impl ProjectRepository for PgProjectRepository {
     }
 
     async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
-        sqlx::query("DELETE FROM projects WHERE id = $1")
+        // Tasks share the project's timestamp so a restore brings back exactly these
+        let deleted_at = Utc::now();
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("UPDATE projects SET deleted_at = $1 WHERE id = $2 AND deleted_at IS NULL")
+            .bind(deleted_at)
             .bind(id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("UPDATE tasks SET deleted_at = $1 WHERE project_id = $2 AND deleted_at IS NULL")
+            .bind(deleted_at)
+            .bind(id)
+            .execute(&mut *tx)
             .await?;
 
+        tx.commit().await?;
         Ok(())
     }
 
+    async fn find_trashed(&self, owner_id: Option<Uuid>) -> Result<Vec<Trashed<Project>>, DomainError> {
+        let rows = sqlx::query_as::<_, TrashedProjectRow>(
+            r#"
+            SELECT * FROM projects
+            WHERE deleted_at IS NOT NULL AND ($1::uuid IS NULL OR owner_id = $1)
+            ORDER BY deleted_at DESC
+            "#,
+        )
+        .bind(owner_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_trashed_by_id(&self, id: Uuid) -> Result<Option<Trashed<Project>>, DomainError> {
+        let row = sqlx::query_as::<_, TrashedProjectRow>(
+            "SELECT * FROM projects WHERE id = $1 AND deleted_at IS NOT NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn restore(&self, id: Uuid) -> Result<Project, DomainError> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            r#"
+            UPDATE tasks t SET deleted_at = NULL
+            FROM projects p
+            WHERE p.id = $1 AND t.project_id = p.id AND t.deleted_at = p.deleted_at
+            "#,
+        )
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+        let row = sqlx::query_as::<_, ProjectRow>(
+            "UPDATE projects SET deleted_at = NULL WHERE id = $1 RETURNING *",
+        )
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(row.into())
+    }
+
+    async fn purge(&self, trashed_before: DateTime<Utc>) -> Result<u64, DomainError> {
+        let result = sqlx::query("DELETE FROM projects WHERE deleted_at < $1")
+            .bind(trashed_before)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
     async fn find_tasks(&self, project_id: Uuid) -> Result<Vec<Task>, DomainError> {
         let rows = sqlx::query_as::<_, TaskRow>(
-            "SELECT * FROM tasks WHERE project_id = $1 ORDER BY created_at DESC",
+            "SELECT * FROM tasks WHERE project_id = $1 AND deleted_at IS NULL ORDER BY rank ASC, created_at DESC",
         )
         .bind(project_id)
         .fetch_all(&self.pool)
@@ -261,9 +525,9 @@ impl ProjectRepository for PgProjectRepository {
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
-    async fn find_milestones(&self, project_id: Uuid) -> Result<Vec<Milestone>, DomainError> {
-        let rows = sqlx::query_as::<_, MilestoneRow>(
-            "SELECT * FROM milestones WHERE project_id = $1 ORDER BY due_date ASC",
+    async fn find_members(&self, project_id: Uuid) -> Result<Vec<ProjectMember>, DomainError> {
+        let rows = sqlx::query_as::<_, ProjectMemberRow>(
+            "SELECT * FROM project_members WHERE project_id = $1 ORDER BY joined_at ASC",
         )
         .bind(project_id)
         .fetch_all(&self.pool)
@@ -271,4 +535,57 @@ impl ProjectRepository for PgProjectRepository {
 
         Ok(rows.into_iter().map(Into::into).collect())
     }
+
+    async fn find_member(&self, project_id: Uuid, user_id: Uuid) -> Result<Option<ProjectMember>, DomainError> {
+        let row = sqlx::query_as::<_, ProjectMemberRow>(
+            "SELECT * FROM project_members WHERE project_id = $1 AND user_id = $2",
+        )
+        .bind(project_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn add_member(&self, member: &ProjectMember) -> Result<ProjectMember, DomainError> {
+        let row = sqlx::query_as::<_, ProjectMemberRow>(
+            r#"
+            INSERT INTO project_members (id, project_id, user_id, role, joined_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#,
+        )
+        .bind(member.id)
+        .bind(member.project_id)
+        .bind(member.user_id)
+        .bind(member.role)
+        .bind(member.joined_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn update_member(&self, member: &ProjectMember) -> Result<ProjectMember, DomainError> {
+        let row = sqlx::query_as::<_, ProjectMemberRow>(
+            "UPDATE project_members SET role = $1 WHERE id = $2 RETURNING *",
+        )
+        .bind(member.role)
+        .bind(member.id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn remove_member(&self, project_id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM project_members WHERE project_id = $1 AND user_id = $2")
+            .bind(project_id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
 }
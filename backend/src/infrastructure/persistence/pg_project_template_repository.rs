@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::types::Json;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{ProjectTemplate, TemplateMember, TemplateMilestone, TemplateTask};
+use crate::domain::repositories::ProjectTemplateRepository;
+use crate::domain::value_objects::Priority;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct ProjectTemplateRow {
+    id: Uuid,
+    name: String,
+    description: Option<String>,
+    priority: Priority,
+    end_offset_secs: Option<i64>,
+    milestones: Json<Vec<TemplateMilestone>>,
+    tasks: Json<Vec<TemplateTask>>,
+    members: Json<Vec<TemplateMember>>,
+    created_by: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ProjectTemplateRow> for ProjectTemplate {
+    fn from(row: ProjectTemplateRow) -> Self {
+        ProjectTemplate {
+            id: row.id,
+            name: row.name,
+            description: row.description,
+            priority: row.priority,
+            end_offset_secs: row.end_offset_secs,
+            milestones: row.milestones.0,
+            tasks: row.tasks.0,
+            members: row.members.0,
+            created_by: row.created_by,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgProjectTemplateRepository {
+    pool: PgPool,
+}
+
+impl PgProjectTemplateRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ProjectTemplateRepository for PgProjectTemplateRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ProjectTemplate>, DomainError> {
+        let row = sqlx::query_as::<_, ProjectTemplateRow>("SELECT * FROM project_templates WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_all(&self) -> Result<Vec<ProjectTemplate>, DomainError> {
+        let rows = sqlx::query_as::<_, ProjectTemplateRow>("SELECT * FROM project_templates ORDER BY name ASC")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, template: &ProjectTemplate) -> Result<ProjectTemplate, DomainError> {
+        let row = sqlx::query_as::<_, ProjectTemplateRow>(
+            r#"
+            INSERT INTO project_templates (id, name, description, priority, end_offset_secs, milestones, tasks, members,
+                                           created_by, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING *
+            "#,
+        )
+        .bind(template.id)
+        .bind(&template.name)
+        .bind(&template.description)
+        .bind(&template.priority)
+        .bind(template.end_offset_secs)
+        .bind(Json(&template.milestones))
+        .bind(Json(&template.tasks))
+        .bind(Json(&template.members))
+        .bind(template.created_by)
+        .bind(template.created_at)
+        .bind(template.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM project_templates WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,332 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{RecurringTask, RecurringTaskOccurrence};
+use crate::domain::repositories::RecurringTaskRepository;
+use crate::domain::value_objects::Priority;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct RecurringTaskRow {
+    id: Uuid,
+    project_id: Uuid,
+    title: String,
+    description: Option<String>,
+    priority: Priority,
+    assignee_id: Option<Uuid>,
+    estimated_hours: Option<f32>,
+    rrule: String,
+    dtstart: DateTime<Utc>,
+    lead_days: i32,
+    is_active: bool,
+    generated_through: Option<NaiveDate>,
+    created_by: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<RecurringTaskRow> for RecurringTask {
+    fn from(row: RecurringTaskRow) -> Self {
+        RecurringTask {
+            id: row.id,
+            project_id: row.project_id,
+            title: row.title,
+            description: row.description,
+            priority: row.priority,
+            assignee_id: row.assignee_id,
+            estimated_hours: row.estimated_hours,
+            rrule: row.rrule,
+            dtstart: row.dtstart,
+            lead_days: row.lead_days,
+            is_active: row.is_active,
+            generated_through: row.generated_through,
+            created_by: row.created_by,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct OccurrenceRow {
+    id: Uuid,
+    recurring_task_id: Uuid,
+    occurrence_date: NaiveDate,
+    skipped: bool,
+    title: Option<String>,
+    description: Option<String>,
+    priority: Option<Priority>,
+    assignee_id: Option<Uuid>,
+    due_date: Option<DateTime<Utc>>,
+    estimated_hours: Option<f32>,
+    generated_at: Option<DateTime<Utc>>,
+    task_id: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<OccurrenceRow> for RecurringTaskOccurrence {
+    fn from(row: OccurrenceRow) -> Self {
+        RecurringTaskOccurrence {
+            id: row.id,
+            recurring_task_id: row.recurring_task_id,
+            occurrence_date: row.occurrence_date,
+            skipped: row.skipped,
+            title: row.title,
+            description: row.description,
+            priority: row.priority,
+            assignee_id: row.assignee_id,
+            due_date: row.due_date,
+            estimated_hours: row.estimated_hours,
+            generated_at: row.generated_at,
+            task_id: row.task_id,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgRecurringTaskRepository {
+    pool: PgPool,
+}
+
+impl PgRecurringTaskRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RecurringTaskRepository for PgRecurringTaskRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<RecurringTask>, DomainError> {
+        let row = sqlx::query_as::<_, RecurringTaskRow>("SELECT * FROM recurring_tasks WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<RecurringTask>, DomainError> {
+        let rows = sqlx::query_as::<_, RecurringTaskRow>(
+            "SELECT * FROM recurring_tasks WHERE project_id = $1 ORDER BY created_at DESC",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_active(&self) -> Result<Vec<RecurringTask>, DomainError> {
+        let rows = sqlx::query_as::<_, RecurringTaskRow>(
+            "SELECT * FROM recurring_tasks WHERE is_active = TRUE ORDER BY created_at ASC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, recurring_task: &RecurringTask) -> Result<RecurringTask, DomainError> {
+        let row = sqlx::query_as::<_, RecurringTaskRow>(
+            r#"
+            INSERT INTO recurring_tasks (id, project_id, title, description, priority, assignee_id, estimated_hours,
+                                         rrule, dtstart, lead_days, is_active, generated_through, created_by, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+            RETURNING *
+            "#,
+        )
+        .bind(recurring_task.id)
+        .bind(recurring_task.project_id)
+        .bind(&recurring_task.title)
+        .bind(&recurring_task.description)
+        .bind(&recurring_task.priority)
+        .bind(recurring_task.assignee_id)
+        .bind(recurring_task.estimated_hours)
+        .bind(&recurring_task.rrule)
+        .bind(recurring_task.dtstart)
+        .bind(recurring_task.lead_days)
+        .bind(recurring_task.is_active)
+        .bind(recurring_task.generated_through)
+        .bind(recurring_task.created_by)
+        .bind(recurring_task.created_at)
+        .bind(recurring_task.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn update(&self, recurring_task: &RecurringTask) -> Result<RecurringTask, DomainError> {
+        let row = sqlx::query_as::<_, RecurringTaskRow>(
+            r#"
+            UPDATE recurring_tasks
+            SET title = $1, description = $2, priority = $3, assignee_id = $4, estimated_hours = $5,
+                rrule = $6, dtstart = $7, lead_days = $8, is_active = $9
+            WHERE id = $10
+            RETURNING *
+            "#,
+        )
+        .bind(&recurring_task.title)
+        .bind(&recurring_task.description)
+        .bind(&recurring_task.priority)
+        .bind(recurring_task.assignee_id)
+        .bind(recurring_task.estimated_hours)
+        .bind(&recurring_task.rrule)
+        .bind(recurring_task.dtstart)
+        .bind(recurring_task.lead_days)
+        .bind(recurring_task.is_active)
+        .bind(recurring_task.id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM recurring_tasks WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_generated_through(&self, id: Uuid, date: NaiveDate) -> Result<(), DomainError> {
+        sqlx::query("UPDATE recurring_tasks SET generated_through = $1 WHERE id = $2")
+            .bind(date)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_occurrence(
+        &self,
+        recurring_task_id: Uuid,
+        occurrence_date: NaiveDate,
+    ) -> Result<Option<RecurringTaskOccurrence>, DomainError> {
+        let row = sqlx::query_as::<_, OccurrenceRow>(
+            "SELECT * FROM recurring_task_occurrences WHERE recurring_task_id = $1 AND occurrence_date = $2",
+        )
+        .bind(recurring_task_id)
+        .bind(occurrence_date)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_occurrences(
+        &self,
+        recurring_task_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<RecurringTaskOccurrence>, DomainError> {
+        let rows = sqlx::query_as::<_, OccurrenceRow>(
+            r#"
+            SELECT * FROM recurring_task_occurrences
+            WHERE recurring_task_id = $1 AND occurrence_date BETWEEN $2 AND $3
+            ORDER BY occurrence_date ASC
+            "#,
+        )
+        .bind(recurring_task_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn save_occurrence(&self, occurrence: &RecurringTaskOccurrence) -> Result<RecurringTaskOccurrence, DomainError> {
+        let row = sqlx::query_as::<_, OccurrenceRow>(
+            r#"
+            INSERT INTO recurring_task_occurrences (id, recurring_task_id, occurrence_date, skipped, title, description,
+                                                    priority, assignee_id, due_date, estimated_hours, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (recurring_task_id, occurrence_date) DO UPDATE
+            SET skipped = EXCLUDED.skipped, title = EXCLUDED.title, description = EXCLUDED.description,
+                priority = EXCLUDED.priority, assignee_id = EXCLUDED.assignee_id, due_date = EXCLUDED.due_date,
+                estimated_hours = EXCLUDED.estimated_hours
+            RETURNING *
+            "#,
+        )
+        .bind(occurrence.id)
+        .bind(occurrence.recurring_task_id)
+        .bind(occurrence.occurrence_date)
+        .bind(occurrence.skipped)
+        .bind(&occurrence.title)
+        .bind(&occurrence.description)
+        .bind(&occurrence.priority)
+        .bind(occurrence.assignee_id)
+        .bind(occurrence.due_date)
+        .bind(occurrence.estimated_hours)
+        .bind(occurrence.created_at)
+        .bind(occurrence.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete_occurrence(&self, recurring_task_id: Uuid, occurrence_date: NaiveDate) -> Result<(), DomainError> {
+        sqlx::query(
+            "DELETE FROM recurring_task_occurrences WHERE recurring_task_id = $1 AND occurrence_date = $2",
+        )
+        .bind(recurring_task_id)
+        .bind(occurrence_date)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn claim_occurrence(
+        &self,
+        recurring_task_id: Uuid,
+        occurrence_date: NaiveDate,
+    ) -> Result<Option<RecurringTaskOccurrence>, DomainError> {
+        let row = sqlx::query_as::<_, OccurrenceRow>(
+            r#"
+            INSERT INTO recurring_task_occurrences (id, recurring_task_id, occurrence_date, generated_at)
+            VALUES ($1, $2, $3, NOW())
+            ON CONFLICT (recurring_task_id, occurrence_date) DO UPDATE
+            SET generated_at = NOW()
+            WHERE recurring_task_occurrences.generated_at IS NULL
+              AND NOT recurring_task_occurrences.skipped
+            RETURNING *
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(recurring_task_id)
+        .bind(occurrence_date)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn release_occurrence(&self, occurrence_id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("UPDATE recurring_task_occurrences SET generated_at = NULL WHERE id = $1")
+            .bind(occurrence_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_occurrence_task(&self, occurrence_id: Uuid, task_id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("UPDATE recurring_task_occurrences SET task_id = $1 WHERE id = $2")
+            .bind(task_id)
+            .bind(occurrence_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{SearchEntityType, SearchHit};
+use crate::domain::repositories::{SearchFilter, SearchRepository};
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct SearchHitRow {
+    entity_type: String,
+    id: Uuid,
+    project_id: Uuid,
+    task_id: Option<Uuid>,
+    title: String,
+    snippet: String,
+    rank: f32,
+}
+
+impl TryFrom<SearchHitRow> for SearchHit {
+    type Error = DomainError;
+
+    fn try_from(row: SearchHitRow) -> Result<Self, Self::Error> {
+        let entity_type = SearchEntityType::ALL
+            .into_iter()
+            .find(|t| t.as_str() == row.entity_type)
+            .ok_or_else(|| {
+                DomainError::InternalError(format!("Unknown search type '{}'", row.entity_type))
+            })?;
+
+        Ok(SearchHit {
+            entity_type,
+            id: row.id,
+            project_id: row.project_id,
+            task_id: row.task_id,
+            title: row.title,
+            snippet: row.snippet,
+            rank: row.rank,
+        })
+    }
+}
+
+pub struct PgSearchRepository {
+    pool: PgPool,
+}
+
+impl PgSearchRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SearchRepository for PgSearchRepository {
+    async fn search(&self, filter: &SearchFilter) -> Result<Vec<SearchHit>, DomainError> {
+        let types: Vec<&str> = filter.types.iter().map(|t| t.as_str()).collect();
+        let rows = sqlx::query_as::<_, SearchHitRow>(
+            r#"
+            WITH query AS (
+                SELECT websearch_to_tsquery('english', $1) AS q,
+                       'StartSel=<mark>, StopSel=</mark>, MaxWords=30, MinWords=10, MaxFragments=2' AS options
+            ),
+            accessible AS (
+                SELECT p.id FROM projects p
+                WHERE p.deleted_at IS NULL
+                  AND ($3::uuid IS NULL
+                   OR p.owner_id = $3
+                   OR EXISTS (SELECT 1 FROM project_members pm WHERE pm.project_id = p.id AND pm.user_id = $3))
+            )
+            SELECT 'project' AS entity_type, p.id, p.id AS project_id, NULL::uuid AS task_id, p.name AS title,
+                   ts_headline('english', concat_ws(' ', p.name, p.description), query.q, query.options) AS snippet,
+                   ts_rank(p.search_vector, query.q) AS rank
+            FROM projects p, query
+            WHERE 'project' = ANY($2)
+              AND p.search_vector @@ query.q
+              AND p.id IN (SELECT id FROM accessible)
+              AND ($4::uuid IS NULL OR p.id = $4)
+            UNION ALL
+            SELECT 'task', t.id, t.project_id, t.id, t.title,
+                   ts_headline('english', concat_ws(' ', t.title, t.description), query.q, query.options),
+                   ts_rank(t.search_vector, query.q)
+            FROM tasks t, query
+            WHERE 'task' = ANY($2)
+              AND t.search_vector @@ query.q
+              AND t.deleted_at IS NULL
+              AND t.project_id IN (SELECT id FROM accessible)
+              AND ($4::uuid IS NULL OR t.project_id = $4)
+            UNION ALL
+            SELECT 'comment', c.id, t.project_id, t.id, t.title,
+                   ts_headline('english', c.content, query.q, query.options),
+                   ts_rank(c.search_vector, query.q)
+            FROM task_comments c
+            INNER JOIN tasks t ON c.task_id = t.id, query
+            WHERE 'comment' = ANY($2)
+              AND c.search_vector @@ query.q
+              AND t.deleted_at IS NULL
+              AND t.project_id IN (SELECT id FROM accessible)
+              AND ($4::uuid IS NULL OR t.project_id = $4)
+            ORDER BY rank DESC, title ASC
+            LIMIT $5 OFFSET $6
+            "#,
+        )
+        .bind(&filter.query)
+        .bind(&types)
+        .bind(filter.user_id)
+        .bind(filter.project_id)
+        .bind(filter.limit)
+        .bind(filter.offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+}
@@ -0,0 +1,319 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::types::Json;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{SlaBreach, SlaBreachCount, SlaPolicy, SlaTarget};
+use crate::domain::repositories::{SlaRepository, SlaTrackedTask};
+use crate::domain::value_objects::{Priority, TaskStatus};
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct SlaPolicyRow {
+    id: Uuid,
+    project_id: Uuid,
+    priority: Priority,
+    response_minutes: Option<i32>,
+    resolution_minutes: Option<i32>,
+    business_hours: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<SlaPolicyRow> for SlaPolicy {
+    fn from(row: SlaPolicyRow) -> Self {
+        SlaPolicy {
+            id: row.id,
+            project_id: row.project_id,
+            priority: row.priority,
+            response_minutes: row.response_minutes,
+            resolution_minutes: row.resolution_minutes,
+            business_hours: row.business_hours,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct SlaBreachRow {
+    id: Uuid,
+    task_id: Uuid,
+    project_id: Uuid,
+    policy_id: Option<Uuid>,
+    priority: Priority,
+    target: SlaTarget,
+    target_minutes: i32,
+    breached_at: DateTime<Utc>,
+    escalated_to: Vec<Uuid>,
+    recorded_at: DateTime<Utc>,
+}
+
+impl From<SlaBreachRow> for SlaBreach {
+    fn from(row: SlaBreachRow) -> Self {
+        SlaBreach {
+            id: row.id,
+            task_id: row.task_id,
+            project_id: row.project_id,
+            policy_id: row.policy_id,
+            priority: row.priority,
+            target: row.target,
+            target_minutes: row.target_minutes,
+            breached_at: row.breached_at,
+            escalated_to: row.escalated_to,
+            recorded_at: row.recorded_at,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct SlaTrackedTaskRow {
+    task_id: Uuid,
+    title: String,
+    task_created_at: DateTime<Utc>,
+    responded_at: Option<DateTime<Utc>>,
+    resolved_at: Option<DateTime<Utc>>,
+    breached: Vec<String>,
+    escalate_to: Vec<Uuid>,
+    #[sqlx(flatten)]
+    policy: SlaPolicyRow,
+}
+
+impl From<SlaTrackedTaskRow> for SlaTrackedTask {
+    fn from(row: SlaTrackedTaskRow) -> Self {
+        let mut escalate_to = row.escalate_to;
+        escalate_to.sort();
+        escalate_to.dedup();
+        SlaTrackedTask {
+            task_id: row.task_id,
+            project_id: row.policy.project_id,
+            title: row.title,
+            created_at: row.task_created_at,
+            responded_at: row.responded_at,
+            resolved_at: row.resolved_at,
+            policy: row.policy.into(),
+            breached: SlaTarget::ALL
+                .into_iter()
+                .filter(|target| row.breached.iter().any(|b| b == target.as_str()))
+                .collect(),
+            escalate_to,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct SlaBreachCountRow {
+    priority: Priority,
+    target: SlaTarget,
+    count: i64,
+}
+
+impl From<SlaBreachCountRow> for SlaBreachCount {
+    fn from(row: SlaBreachCountRow) -> Self {
+        SlaBreachCount {
+            priority: row.priority,
+            target: row.target,
+            count: row.count,
+        }
+    }
+}
+
+/// Tasks joined with the policy of their project and priority. The clocks stop on the
+/// status changes found in the change history: $1 is the Todo status, $2 the Done status.
+/// Tasks created past Todo count as responded on creation.
+const TRACKED_TASKS: &str = r#"
+    SELECT t.id AS task_id, t.title, t.created_at AS task_created_at,
+           COALESCE(
+               (SELECT MIN(h.changed_at) FROM change_history h
+                WHERE h.entity_type = 'task' AND h.entity_id = t.id
+                  AND h.changes @> jsonb_build_array(jsonb_build_object('field', 'status', 'old_value', $1::jsonb))),
+               CASE WHEN t.status <> 'todo' THEN t.created_at END
+           ) AS responded_at,
+           CASE WHEN t.status = 'done' THEN COALESCE(
+               (SELECT MAX(h.changed_at) FROM change_history h
+                WHERE h.entity_type = 'task' AND h.entity_id = t.id
+                  AND h.changes @> jsonb_build_array(jsonb_build_object('field', 'status', 'new_value', $2::jsonb))),
+               t.updated_at
+           ) END AS resolved_at,
+           ARRAY(SELECT b.target::text FROM sla_breaches b WHERE b.task_id = t.id) AS breached,
+           ARRAY(
+               SELECT lead.user_id FROM team_members tm
+               INNER JOIN team_members lead ON lead.team_id = tm.team_id AND lead.role = 'lead'
+               WHERE tm.user_id = t.assignee_id
+           ) || p.owner_id AS escalate_to,
+           s.*
+    FROM tasks t
+    INNER JOIN projects p ON p.id = t.project_id
+    INNER JOIN sla_policies s ON s.project_id = t.project_id AND s.priority = t.priority
+    WHERE t.deleted_at IS NULL
+"#;
+
+pub struct PgSlaRepository {
+    pool: PgPool,
+}
+
+impl PgSlaRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SlaRepository for PgSlaRepository {
+    async fn find_policy(&self, id: Uuid) -> Result<Option<SlaPolicy>, DomainError> {
+        let row = sqlx::query_as::<_, SlaPolicyRow>("SELECT * FROM sla_policies WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_policies(&self, project_id: Uuid) -> Result<Vec<SlaPolicy>, DomainError> {
+        let rows = sqlx::query_as::<_, SlaPolicyRow>(
+            "SELECT * FROM sla_policies WHERE project_id = $1 ORDER BY priority DESC",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create_policy(&self, policy: &SlaPolicy) -> Result<SlaPolicy, DomainError> {
+        let row = sqlx::query_as::<_, SlaPolicyRow>(
+            r#"
+            INSERT INTO sla_policies (id, project_id, priority, response_minutes, resolution_minutes, business_hours, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(policy.id)
+        .bind(policy.project_id)
+        .bind(policy.priority.clone())
+        .bind(policy.response_minutes)
+        .bind(policy.resolution_minutes)
+        .bind(policy.business_hours)
+        .bind(policy.created_at)
+        .bind(policy.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn update_policy(&self, policy: &SlaPolicy) -> Result<SlaPolicy, DomainError> {
+        let row = sqlx::query_as::<_, SlaPolicyRow>(
+            r#"
+            UPDATE sla_policies
+            SET response_minutes = $1, resolution_minutes = $2, business_hours = $3
+            WHERE id = $4
+            RETURNING *
+            "#,
+        )
+        .bind(policy.response_minutes)
+        .bind(policy.resolution_minutes)
+        .bind(policy.business_hours)
+        .bind(policy.id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete_policy(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM sla_policies WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_tracked(&self, done_since: DateTime<Utc>) -> Result<Vec<SlaTrackedTask>, DomainError> {
+        let rows = sqlx::query_as::<_, SlaTrackedTaskRow>(&format!(
+            "{} AND (t.status <> 'done' OR t.updated_at >= $3)",
+            TRACKED_TASKS
+        ))
+        .bind(Json(TaskStatus::Todo))
+        .bind(Json(TaskStatus::Done))
+        .bind(done_since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_tracked_task(&self, task_id: Uuid) -> Result<Option<SlaTrackedTask>, DomainError> {
+        let row = sqlx::query_as::<_, SlaTrackedTaskRow>(&format!("{} AND t.id = $3", TRACKED_TASKS))
+            .bind(Json(TaskStatus::Todo))
+            .bind(Json(TaskStatus::Done))
+            .bind(task_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn record_breach(&self, breach: &SlaBreach) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO sla_breaches (id, task_id, project_id, policy_id, priority, target, target_minutes, breached_at, escalated_to, recorded_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (task_id, target) DO NOTHING
+            "#,
+        )
+        .bind(breach.id)
+        .bind(breach.task_id)
+        .bind(breach.project_id)
+        .bind(breach.policy_id)
+        .bind(breach.priority.clone())
+        .bind(breach.target)
+        .bind(breach.target_minutes)
+        .bind(breach.breached_at)
+        .bind(&breach.escalated_to)
+        .bind(breach.recorded_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_breaches(&self, task_id: Uuid) -> Result<Vec<SlaBreach>, DomainError> {
+        let rows = sqlx::query_as::<_, SlaBreachRow>(
+            "SELECT * FROM sla_breaches WHERE task_id = $1 ORDER BY breached_at ASC",
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn count_breaches(
+        &self,
+        project_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<SlaBreachCount>, DomainError> {
+        let rows = sqlx::query_as::<_, SlaBreachCountRow>(
+            r#"
+            SELECT priority, target, COUNT(*) AS count
+            FROM sla_breaches
+            WHERE project_id = $1
+              AND ($2::timestamptz IS NULL OR breached_at >= $2)
+              AND ($3::timestamptz IS NULL OR breached_at < $3)
+            GROUP BY priority, target
+            ORDER BY priority DESC, target ASC
+            "#,
+        )
+        .bind(project_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,246 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{Sprint, SprintSnapshot, SprintStatus};
+use crate::domain::repositories::SprintRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct SprintRow {
+    id: Uuid,
+    project_id: Uuid,
+    name: String,
+    goal: Option<String>,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    status: SprintStatus,
+    committed_points: Option<i32>,
+    completed_points: Option<i32>,
+    started_at: Option<DateTime<Utc>>,
+    closed_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<SprintRow> for Sprint {
+    fn from(row: SprintRow) -> Self {
+        Sprint {
+            id: row.id,
+            project_id: row.project_id,
+            name: row.name,
+            goal: row.goal,
+            start_date: row.start_date,
+            end_date: row.end_date,
+            status: row.status,
+            committed_points: row.committed_points,
+            completed_points: row.completed_points,
+            started_at: row.started_at,
+            closed_at: row.closed_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct SprintSnapshotRow {
+    sprint_id: Uuid,
+    snapshot_date: NaiveDate,
+    total_points: i32,
+    completed_points: i32,
+    total_tasks: i32,
+    completed_tasks: i32,
+    captured_at: DateTime<Utc>,
+}
+
+impl From<SprintSnapshotRow> for SprintSnapshot {
+    fn from(row: SprintSnapshotRow) -> Self {
+        SprintSnapshot {
+            sprint_id: row.sprint_id,
+            snapshot_date: row.snapshot_date,
+            total_points: row.total_points,
+            completed_points: row.completed_points,
+            total_tasks: row.total_tasks,
+            completed_tasks: row.completed_tasks,
+            captured_at: row.captured_at,
+        }
+    }
+}
+
+pub struct PgSprintRepository {
+    pool: PgPool,
+}
+
+impl PgSprintRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+async fn update_sprint<'e, E: PgExecutor<'e>>(executor: E, sprint: &Sprint) -> Result<Sprint, DomainError> {
+    let row = sqlx::query_as::<_, SprintRow>(
+        r#"
+        UPDATE sprints
+        SET name = $1, goal = $2, start_date = $3, end_date = $4, status = $5,
+            committed_points = $6, completed_points = $7, started_at = $8, closed_at = $9
+        WHERE id = $10
+        RETURNING *
+        "#,
+    )
+    .bind(&sprint.name)
+    .bind(&sprint.goal)
+    .bind(sprint.start_date)
+    .bind(sprint.end_date)
+    .bind(sprint.status)
+    .bind(sprint.committed_points)
+    .bind(sprint.completed_points)
+    .bind(sprint.started_at)
+    .bind(sprint.closed_at)
+    .bind(sprint.id)
+    .fetch_one(executor)
+    .await?;
+
+    Ok(row.into())
+}
+
+async fn save_snapshot<'e, E: PgExecutor<'e>>(executor: E, snapshot: &SprintSnapshot) -> Result<(), DomainError> {
+    sqlx::query(
+        r#"
+        INSERT INTO sprint_snapshots (sprint_id, snapshot_date, total_points, completed_points, total_tasks, completed_tasks, captured_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT (sprint_id, snapshot_date) DO UPDATE
+        SET total_points = EXCLUDED.total_points, completed_points = EXCLUDED.completed_points,
+            total_tasks = EXCLUDED.total_tasks, completed_tasks = EXCLUDED.completed_tasks,
+            captured_at = EXCLUDED.captured_at
+        "#,
+    )
+    .bind(snapshot.sprint_id)
+    .bind(snapshot.snapshot_date)
+    .bind(snapshot.total_points)
+    .bind(snapshot.completed_points)
+    .bind(snapshot.total_tasks)
+    .bind(snapshot.completed_tasks)
+    .bind(snapshot.captured_at)
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl SprintRepository for PgSprintRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Sprint>, DomainError> {
+        let row = sqlx::query_as::<_, SprintRow>("SELECT * FROM sprints WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<Sprint>, DomainError> {
+        let rows = sqlx::query_as::<_, SprintRow>(
+            "SELECT * FROM sprints WHERE project_id = $1 ORDER BY start_date DESC, created_at DESC",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_active(&self, project_id: Uuid) -> Result<Option<Sprint>, DomainError> {
+        let row = sqlx::query_as::<_, SprintRow>(
+            "SELECT * FROM sprints WHERE project_id = $1 AND status = 'active'",
+        )
+        .bind(project_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_all_active(&self) -> Result<Vec<Sprint>, DomainError> {
+        let rows = sqlx::query_as::<_, SprintRow>("SELECT * FROM sprints WHERE status = 'active'")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, sprint: &Sprint) -> Result<Sprint, DomainError> {
+        let row = sqlx::query_as::<_, SprintRow>(
+            r#"
+            INSERT INTO sprints (id, project_id, name, goal, start_date, end_date, status, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING *
+            "#,
+        )
+        .bind(sprint.id)
+        .bind(sprint.project_id)
+        .bind(&sprint.name)
+        .bind(&sprint.goal)
+        .bind(sprint.start_date)
+        .bind(sprint.end_date)
+        .bind(sprint.status)
+        .bind(sprint.created_at)
+        .bind(sprint.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn update(&self, sprint: &Sprint) -> Result<Sprint, DomainError> {
+        update_sprint(&self.pool, sprint).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM sprints WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn close(
+        &self,
+        sprint: &Sprint,
+        snapshot: &SprintSnapshot,
+        unfinished_task_ids: &[Uuid],
+        carry_over_to: Option<Uuid>,
+    ) -> Result<Sprint, DomainError> {
+        let mut tx = self.pool.begin().await?;
+
+        let sprint = update_sprint(&mut *tx, sprint).await?;
+        save_snapshot(&mut *tx, snapshot).await?;
+        if !unfinished_task_ids.is_empty() {
+            sqlx::query("UPDATE tasks SET sprint_id = $1 WHERE id = ANY($2)")
+                .bind(carry_over_to)
+                .bind(unfinished_task_ids)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+        Ok(sprint)
+    }
+
+    async fn save_snapshot(&self, snapshot: &SprintSnapshot) -> Result<(), DomainError> {
+        save_snapshot(&self.pool, snapshot).await
+    }
+
+    async fn find_snapshots(&self, sprint_id: Uuid) -> Result<Vec<SprintSnapshot>, DomainError> {
+        let rows = sqlx::query_as::<_, SprintSnapshotRow>(
+            "SELECT * FROM sprint_snapshots WHERE sprint_id = $1 ORDER BY snapshot_date ASC",
+        )
+        .bind(sprint_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,179 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{TaskComment, TaskCommentEdit};
+use crate::domain::repositories::TaskCommentRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct TaskCommentRow {
+    id: Uuid,
+    task_id: Uuid,
+    user_id: Uuid,
+    parent_id: Option<Uuid>,
+    content: String,
+    edited_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    user_name: Option<String>,
+}
+
+impl From<TaskCommentRow> for TaskComment {
+    fn from(row: TaskCommentRow) -> Self {
+        TaskComment {
+            id: row.id,
+            task_id: row.task_id,
+            user_id: row.user_id,
+            parent_id: row.parent_id,
+            content: row.content,
+            edited_at: row.edited_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            user_name: row.user_name,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct TaskCommentEditRow {
+    id: Uuid,
+    comment_id: Uuid,
+    previous_content: String,
+    edited_at: DateTime<Utc>,
+}
+
+impl From<TaskCommentEditRow> for TaskCommentEdit {
+    fn from(row: TaskCommentEditRow) -> Self {
+        TaskCommentEdit {
+            id: row.id,
+            comment_id: row.comment_id,
+            previous_content: row.previous_content,
+            edited_at: row.edited_at,
+        }
+    }
+}
+
+pub struct PgTaskCommentRepository {
+    pool: PgPool,
+}
+
+impl PgTaskCommentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn base_query() -> &'static str {
+        r#"
+        SELECT
+            c.id,
+            c.task_id,
+            c.user_id,
+            c.parent_id,
+            c.content,
+            c.edited_at,
+            c.created_at,
+            c.updated_at,
+            u.name as user_name
+        FROM task_comments c
+        LEFT JOIN users u ON c.user_id = u.id
+        "#
+    }
+}
+
+#[async_trait]
+impl TaskCommentRepository for PgTaskCommentRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<TaskComment>, DomainError> {
+        let query = format!("{} WHERE c.id = $1", Self::base_query());
+        let row = sqlx::query_as::<_, TaskCommentRow>(&query)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_task(&self, task_id: Uuid) -> Result<Vec<TaskComment>, DomainError> {
+        let query = format!("{} WHERE c.task_id = $1 ORDER BY c.created_at ASC", Self::base_query());
+        let rows = sqlx::query_as::<_, TaskCommentRow>(&query)
+            .bind(task_id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_edits(&self, comment_id: Uuid) -> Result<Vec<TaskCommentEdit>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskCommentEditRow>(
+            "SELECT * FROM task_comment_edits WHERE comment_id = $1 ORDER BY edited_at DESC",
+        )
+        .bind(comment_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, comment: &TaskComment) -> Result<TaskComment, DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO task_comments (id, task_id, user_id, parent_id, content, edited_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(comment.id)
+        .bind(comment.task_id)
+        .bind(comment.user_id)
+        .bind(comment.parent_id)
+        .bind(&comment.content)
+        .bind(comment.edited_at)
+        .bind(comment.created_at)
+        .bind(comment.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        self.find_by_id(comment.id)
+            .await?
+            .ok_or_else(|| DomainError::InternalError("Failed to create comment".into()))
+    }
+
+    async fn update(&self, comment: &TaskComment, edit: &TaskCommentEdit) -> Result<TaskComment, DomainError> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO task_comment_edits (id, comment_id, previous_content, edited_at)
+            VALUES ($1, $2, $3, $4)
+            "#,
+        )
+        .bind(edit.id)
+        .bind(edit.comment_id)
+        .bind(&edit.previous_content)
+        .bind(edit.edited_at)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query("UPDATE task_comments SET content = $1, edited_at = $2 WHERE id = $3")
+            .bind(&comment.content)
+            .bind(comment.edited_at)
+            .bind(comment.id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        self.find_by_id(comment.id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound("Comment not found".into()))
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM task_comments WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
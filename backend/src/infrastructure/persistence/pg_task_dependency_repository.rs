@@ -0,0 +1,145 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::TaskDependency;
+use crate::domain::repositories::TaskDependencyRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct TaskDependencyRow {
+    id: Uuid,
+    blocking_task_id: Uuid,
+    blocked_task_id: Uuid,
+    created_by: Option<Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<TaskDependencyRow> for TaskDependency {
+    fn from(row: TaskDependencyRow) -> Self {
+        TaskDependency {
+            id: row.id,
+            blocking_task_id: row.blocking_task_id,
+            blocked_task_id: row.blocked_task_id,
+            created_by: row.created_by,
+            created_at: row.created_at,
+        }
+    }
+}
+
+pub struct PgTaskDependencyRepository {
+    pool: PgPool,
+}
+
+impl PgTaskDependencyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TaskDependencyRepository for PgTaskDependencyRepository {
+    async fn find_blockers(&self, task_id: Uuid) -> Result<Vec<TaskDependency>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskDependencyRow>(
+            "SELECT * FROM task_dependencies WHERE blocked_task_id = $1 ORDER BY created_at ASC",
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_blocked(&self, task_id: Uuid) -> Result<Vec<TaskDependency>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskDependencyRow>(
+            "SELECT * FROM task_dependencies WHERE blocking_task_id = $1 ORDER BY created_at ASC",
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_downstream(&self, task_id: Uuid) -> Result<Vec<TaskDependency>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskDependencyRow>(
+            r#"
+            WITH RECURSIVE downstream AS (
+                SELECT * FROM task_dependencies WHERE blocking_task_id = $1
+                UNION
+                SELECT d.* FROM task_dependencies d
+                INNER JOIN downstream ds ON d.blocking_task_id = ds.blocked_task_id
+            )
+            SELECT * FROM downstream
+            "#,
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<TaskDependency>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskDependencyRow>(
+            r#"
+            SELECT d.* FROM task_dependencies d
+            INNER JOIN tasks blocking ON blocking.id = d.blocking_task_id
+            INNER JOIN tasks blocked ON blocked.id = d.blocked_task_id
+            WHERE blocking.project_id = $1 AND blocked.project_id = $1
+              AND blocking.deleted_at IS NULL AND blocked.deleted_at IS NULL
+            ORDER BY d.created_at ASC
+            "#,
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn count_unfinished_blockers(&self, task_id: Uuid) -> Result<i64, DomainError> {
+        let (count,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*) FROM task_dependencies d
+            INNER JOIN tasks t ON t.id = d.blocking_task_id
+            WHERE d.blocked_task_id = $1 AND t.status <> 'done' AND t.deleted_at IS NULL
+            "#,
+        )
+        .bind(task_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    async fn create(&self, dependency: &TaskDependency) -> Result<TaskDependency, DomainError> {
+        let row = sqlx::query_as::<_, TaskDependencyRow>(
+            r#"
+            INSERT INTO task_dependencies (id, blocking_task_id, blocked_task_id, created_by, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#,
+        )
+        .bind(dependency.id)
+        .bind(dependency.blocking_task_id)
+        .bind(dependency.blocked_task_id)
+        .bind(dependency.created_by)
+        .bind(dependency.created_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, blocking_task_id: Uuid, blocked_task_id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM task_dependencies WHERE blocking_task_id = $1 AND blocked_task_id = $2")
+            .bind(blocking_task_id)
+            .bind(blocked_task_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
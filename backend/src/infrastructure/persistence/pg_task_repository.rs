@@ -1,26 +1,36 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use sqlx::{FromRow, PgPool};
+use serde_json::Value;
+use sqlx::types::Json;
+use sqlx::{FromRow, PgExecutor, PgPool, Postgres, QueryBuilder};
+use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::domain::entities::Task;
-use crate::domain::repositories::TaskRepository;
-use crate::domain::value_objects::{Priority, TaskStatus};
+use crate::domain::entities::{Task, Trashed};
+use crate::domain::repositories::{TaskBatch, TaskFilter, TaskRepository};
+use crate::domain::value_objects::{Priority, ProjectRole, TaskSortField, TaskStatus};
 use crate::shared::DomainError;
 
 #[derive(Debug, FromRow)]
 struct TaskRow {
     id: Uuid,
     project_id: Uuid,
+    parent_id: Option<Uuid>,
     milestone_id: Option<Uuid>,
+    sprint_id: Option<Uuid>,
     title: String,
     description: Option<String>,
     status: TaskStatus,
+    workflow_status: String,
     priority: Priority,
     assignee_id: Option<Uuid>,
+    start_date: Option<DateTime<Utc>>,
     due_date: Option<DateTime<Utc>>,
     estimated_hours: Option<f32>,
     actual_hours: Option<f32>,
+    story_points: Option<i32>,
+    rank: f64,
+    custom_fields: Json<HashMap<Uuid, Value>>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -30,21 +40,44 @@ impl From<TaskRow> for Task {
         Task {
             id: row.id,
             project_id: row.project_id,
+            parent_id: row.parent_id,
             milestone_id: row.milestone_id,
+            sprint_id: row.sprint_id,
             title: row.title,
             description: row.description,
             status: row.status,
+            workflow_status: row.workflow_status,
             priority: row.priority,
             assignee_id: row.assignee_id,
+            start_date: row.start_date,
             due_date: row.due_date,
             estimated_hours: row.estimated_hours,
             actual_hours: row.actual_hours,
+            story_points: row.story_points,
+            rank: row.rank,
+            custom_fields: row.custom_fields.0,
             created_at: row.created_at,
             updated_at: row.updated_at,
         }
     }
 }
 
+#[derive(Debug, FromRow)]
+struct TrashedTaskRow {
+    #[sqlx(flatten)]
+    task: TaskRow,
+    deleted_at: DateTime<Utc>,
+}
+
+impl From<TrashedTaskRow> for Trashed<Task> {
+    fn from(row: TrashedTaskRow) -> Self {
+        Trashed {
+            item: row.task.into(),
+            deleted_at: row.deleted_at,
+        }
+    }
+}
+
 pub struct PgTaskRepository {
     pool: PgPool,
 }
@@ -55,10 +88,138 @@ impl PgTaskRepository {
     }
 }
 
+async fn update_task<'e, E: PgExecutor<'e>>(executor: E, task: &Task) -> Result<Task, DomainError> {
+    let row = sqlx::query_as::<_, TaskRow>(
+        r#"
+        UPDATE tasks
+        SET title = $1, description = $2, status = $3, workflow_status = $4,
+            priority = $5, assignee_id = $6, due_date = $7, estimated_hours = $8,
+            actual_hours = $9, milestone_id = $10, parent_id = $11, rank = $12, custom_fields = $13,
+            project_id = $14, sprint_id = $15, story_points = $16, start_date = $17, updated_at = NOW()
+        WHERE id = $18
+        RETURNING *
+        "#,
+    )
+    .bind(&task.title)
+    .bind(&task.description)
+    .bind(&task.status)
+    .bind(&task.workflow_status)
+    .bind(&task.priority)
+    .bind(task.assignee_id)
+    .bind(task.due_date)
+    .bind(task.estimated_hours)
+    .bind(task.actual_hours)
+    .bind(task.milestone_id)
+    .bind(task.parent_id)
+    .bind(task.rank)
+    .bind(Json(&task.custom_fields))
+    .bind(task.project_id)
+    .bind(task.sprint_id)
+    .bind(task.story_points)
+    .bind(task.start_date)
+    .bind(task.id)
+    .fetch_one(executor)
+    .await?;
+
+    Ok(row.into())
+}
+
+/// Move the tasks and their live subtasks to the trash, all with the same timestamp
+async fn trash_tasks<'e, E: PgExecutor<'e>>(executor: E, ids: &[Uuid]) -> Result<(), DomainError> {
+    sqlx::query(
+        r#"
+        WITH RECURSIVE subtree AS (
+            SELECT id FROM tasks WHERE id = ANY($1) AND deleted_at IS NULL
+            UNION
+            SELECT t.id FROM tasks t
+            INNER JOIN subtree st ON t.parent_id = st.id
+            WHERE t.deleted_at IS NULL
+        )
+        UPDATE tasks SET deleted_at = NOW() WHERE id IN (SELECT id FROM subtree)
+        "#,
+    )
+    .bind(ids)
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+/// Append the WHERE clause for `filter` to a query over `tasks t`
+fn push_task_filter(builder: &mut QueryBuilder<'_, Postgres>, filter: &TaskFilter) {
+    builder.push(" WHERE t.deleted_at IS NULL");
+
+    if let Some(user_id) = filter.accessible_by {
+        builder
+            .push(
+                " AND EXISTS (SELECT 1 FROM projects p LEFT JOIN project_members pm ON p.id = pm.project_id \
+                 WHERE p.id = t.project_id AND p.deleted_at IS NULL AND (p.owner_id = ",
+            )
+            .push_bind(user_id)
+            .push(" OR pm.user_id = ")
+            .push_bind(user_id)
+            .push("))");
+    }
+    if let Some(project_id) = filter.project_id {
+        builder.push(" AND t.project_id = ").push_bind(project_id);
+    }
+    if let Some(assignee_id) = filter.assignee_id {
+        builder.push(" AND t.assignee_id = ").push_bind(assignee_id);
+    }
+    if filter.unassigned {
+        builder.push(" AND t.assignee_id IS NULL");
+    }
+    if let Some(status) = &filter.status {
+        builder.push(" AND t.status = ").push_bind(status.clone());
+    }
+    if !filter.priorities.is_empty() {
+        let priorities: Vec<&str> = filter.priorities.iter().map(|p| p.as_str()).collect();
+        builder
+            .push(" AND t.priority = ANY(")
+            .push_bind(priorities)
+            .push("::priority[])");
+    }
+    if let Some(due_from) = filter.due_from {
+        builder.push(" AND t.due_date >= ").push_bind(due_from);
+    }
+    if let Some(due_to) = filter.due_to {
+        builder.push(" AND t.due_date <= ").push_bind(due_to);
+    }
+    if filter.overdue {
+        builder.push(" AND t.due_date < NOW() AND t.status <> 'done'");
+    }
+    if !filter.tag_ids.is_empty() {
+        builder
+            .push(" AND EXISTS (SELECT 1 FROM task_tags tt WHERE tt.task_id = t.id AND tt.tag_id = ANY(")
+            .push_bind(filter.tag_ids.clone())
+            .push("))");
+    }
+    if let Some(milestone_id) = filter.milestone_id {
+        builder.push(" AND t.milestone_id = ").push_bind(milestone_id);
+    }
+    if let Some(sprint_id) = filter.sprint_id {
+        builder.push(" AND t.sprint_id = ").push_bind(sprint_id);
+    }
+    if filter.sla_breached {
+        builder.push(" AND EXISTS (SELECT 1 FROM sla_breaches b WHERE b.task_id = t.id)");
+    }
+    if let Some(text) = &filter.text {
+        builder
+            .push(" AND t.search_vector @@ websearch_to_tsquery('english', ")
+            .push_bind(text.clone())
+            .push(")");
+    }
+    if !filter.custom_fields.is_empty() {
+        builder
+            .push(" AND t.custom_fields @> ")
+            .push_bind(Json(filter.custom_fields.clone()));
+    }
+}
+
 #[async_trait]
 impl TaskRepository for PgTaskRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Task>, DomainError> {
-        let row = sqlx::query_as::<_, TaskRow>("SELECT * FROM tasks WHERE id = $1")
+        let row = sqlx::query_as::<_, TaskRow>("SELECT * FROM tasks WHERE id = $1 AND deleted_at IS NULL")
             .bind(id)
             .fetch_optional(&self.pool)
             .await?;
@@ -67,16 +228,27 @@ impl TaskRepository for PgTaskRepository {
     }
 
     async fn find_all(&self) -> Result<Vec<Task>, DomainError> {
-        let rows = sqlx::query_as::<_, TaskRow>("SELECT * FROM tasks ORDER BY created_at DESC")
+        let rows = sqlx::query_as::<_, TaskRow>("SELECT * FROM tasks WHERE deleted_at IS NULL ORDER BY created_at DESC")
             .fetch_all(&self.pool)
             .await?;
 
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Task>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskRow>(
+            "SELECT * FROM tasks WHERE id = ANY($1) AND deleted_at IS NULL ORDER BY created_at DESC",
+        )
+        .bind(ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
     async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<Task>, DomainError> {
         let rows = sqlx::query_as::<_, TaskRow>(
-            "SELECT * FROM tasks WHERE project_id = $1 ORDER BY created_at DESC",
+            "SELECT * FROM tasks WHERE project_id = $1 AND deleted_at IS NULL ORDER BY rank ASC, created_at DESC",
         )
         .bind(project_id)
         .fetch_all(&self.pool)
@@ -85,9 +257,85 @@ impl TaskRepository for PgTaskRepository {
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
+    async fn find_children(&self, parent_id: Uuid) -> Result<Vec<Task>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskRow>(
+            "SELECT * FROM tasks WHERE parent_id = $1 AND deleted_at IS NULL ORDER BY created_at ASC",
+        )
+        .bind(parent_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_subtree(&self, root_id: Uuid) -> Result<Vec<Task>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskRow>(
+            r#"
+            WITH RECURSIVE subtree AS (
+                SELECT * FROM tasks WHERE id = $1 AND deleted_at IS NULL
+                UNION ALL
+                SELECT t.* FROM tasks t
+                INNER JOIN subtree st ON t.parent_id = st.id
+                WHERE t.deleted_at IS NULL
+            )
+            SELECT * FROM subtree ORDER BY created_at ASC
+            "#,
+        )
+        .bind(root_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_sprint(&self, sprint_id: Uuid) -> Result<Vec<Task>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskRow>(
+            "SELECT * FROM tasks WHERE sprint_id = $1 AND deleted_at IS NULL ORDER BY rank ASC, created_at DESC",
+        )
+        .bind(sprint_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_milestone(&self, milestone_id: Uuid) -> Result<Vec<Task>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskRow>(
+            "SELECT * FROM tasks WHERE milestone_id = $1 AND deleted_at IS NULL ORDER BY rank ASC, created_at DESC",
+        )
+        .bind(milestone_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_column(&self, project_id: Uuid, workflow_status: &str) -> Result<Vec<Task>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskRow>(
+            "SELECT * FROM tasks WHERE project_id = $1 AND workflow_status = $2 AND deleted_at IS NULL ORDER BY rank ASC, created_at DESC",
+        )
+        .bind(project_id)
+        .bind(workflow_status)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_last_rank(&self, project_id: Uuid, workflow_status: &str) -> Result<Option<f64>, DomainError> {
+        let rank: Option<f64> =
+            sqlx::query_scalar("SELECT MAX(rank) FROM tasks WHERE project_id = $1 AND workflow_status = $2 AND deleted_at IS NULL")
+                .bind(project_id)
+                .bind(workflow_status)
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok(rank)
+    }
+
     async fn find_by_assignee(&self, user_id: Uuid) -> Result<Vec<Task>, DomainError> {
         let rows = sqlx::query_as::<_, TaskRow>(
-            "SELECT * FROM tasks WHERE assignee_id = $1 ORDER BY created_at DESC",
+            "SELECT * FROM tasks WHERE assignee_id = $1 AND deleted_at IS NULL ORDER BY created_at DESC",
         )
         .bind(user_id)
         .fetch_all(&self.pool)
@@ -98,7 +346,7 @@ impl TaskRepository for PgTaskRepository {
 
     async fn find_by_status(&self, status: TaskStatus) -> Result<Vec<Task>, DomainError> {
         let rows = sqlx::query_as::<_, TaskRow>(
-            "SELECT * FROM tasks WHERE status = $1 ORDER BY created_at DESC",
+            "SELECT * FROM tasks WHERE status = $1 AND deleted_at IS NULL ORDER BY created_at DESC",
         )
         .bind(status)
         .fetch_all(&self.pool)
@@ -107,13 +355,46 @@ impl TaskRepository for PgTaskRepository {
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
+    async fn find_filtered(&self, filter: &TaskFilter) -> Result<(Vec<Task>, i64), DomainError> {
+        let mut count = QueryBuilder::new("SELECT COUNT(*) FROM tasks t");
+        push_task_filter(&mut count, filter);
+        let total: i64 = count.build_query_scalar().fetch_one(&self.pool).await?;
+
+        let mut select = QueryBuilder::new("SELECT t.* FROM tasks t");
+        push_task_filter(&mut select, filter);
+        select.push(" ORDER BY ");
+        for sort in &filter.sort {
+            let column = match sort.field {
+                TaskSortField::CreatedAt => "t.created_at",
+                TaskSortField::UpdatedAt => "t.updated_at",
+                TaskSortField::DueDate => "t.due_date",
+                TaskSortField::Priority => "t.priority",
+                TaskSortField::Status => "t.status",
+                TaskSortField::Title => "t.title",
+                TaskSortField::Rank => "t.rank",
+                TaskSortField::EstimatedHours => "t.estimated_hours",
+            };
+            let direction = if sort.descending { "DESC" } else { "ASC" };
+            select.push(format!("{} {} NULLS LAST, ", column, direction));
+        }
+        // Tie-break on id so pages are stable
+        select
+            .push("t.id ASC LIMIT ")
+            .push_bind(filter.limit)
+            .push(" OFFSET ")
+            .push_bind(filter.offset);
+
+        let rows: Vec<TaskRow> = select.build_query_as().fetch_all(&self.pool).await?;
+        Ok((rows.into_iter().map(Into::into).collect(), total))
+    }
+
     async fn find_accessible_by_user(&self, user_id: Uuid) -> Result<Vec<Task>, DomainError> {
         let rows = sqlx::query_as::<_, TaskRow>(
             r#"
             SELECT DISTINCT t.* FROM tasks t
             INNER JOIN projects p ON t.project_id = p.id
             LEFT JOIN project_members pm ON p.id = pm.project_id
-            WHERE p.owner_id = $1 OR pm.user_id = $1
+            WHERE t.deleted_at IS NULL AND p.deleted_at IS NULL AND (p.owner_id = $1 OR pm.user_id = $1)
             ORDER BY t.created_at DESC
             "#,
         )
@@ -130,7 +411,8 @@ impl TaskRepository for PgTaskRepository {
             SELECT 1 FROM tasks t
             INNER JOIN projects p ON t.project_id = p.id
             LEFT JOIN project_members pm ON p.id = pm.project_id
-            WHERE t.id = $1 AND (p.owner_id = $2 OR pm.user_id = $2)
+            WHERE t.id = $1 AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+              AND (p.owner_id = $2 OR pm.user_id = $2)
             LIMIT 1
             "#,
         )
@@ -142,17 +424,58 @@ impl TaskRepository for PgTaskRepository {
         Ok(result.is_some())
     }
 
-    async fn is_project_owner(&self, task_id: Uuid, user_id: Uuid) -> Result<bool, DomainError> {
+    async fn has_role(&self, task_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
         let result: Option<(i64,)> = sqlx::query_as(
             r#"
             SELECT 1 FROM tasks t
             INNER JOIN projects p ON t.project_id = p.id
-            WHERE t.id = $1 AND p.owner_id = $2
+            LEFT JOIN project_members pm ON p.id = pm.project_id AND pm.user_id = $2
+            WHERE t.id = $1 AND t.deleted_at IS NULL AND p.deleted_at IS NULL
+              AND (p.owner_id = $2 OR pm.role >= $3)
             LIMIT 1
             "#,
         )
         .bind(task_id)
         .bind(user_id)
+        .bind(role)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result.is_some())
+    }
+
+    async fn has_trashed_role(&self, task_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        let result: Option<(i64,)> = sqlx::query_as(
+            r#"
+            SELECT 1 FROM tasks t
+            INNER JOIN projects p ON t.project_id = p.id
+            LEFT JOIN project_members pm ON p.id = pm.project_id AND pm.user_id = $2
+            WHERE t.id = $1 AND t.deleted_at IS NOT NULL AND p.deleted_at IS NULL
+              AND (p.owner_id = $2 OR pm.role >= $3)
+            LIMIT 1
+            "#,
+        )
+        .bind(task_id)
+        .bind(user_id)
+        .bind(role)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result.is_some())
+    }
+
+    async fn has_project_role(&self, project_id: Uuid, user_id: Uuid, role: ProjectRole) -> Result<bool, DomainError> {
+        let result: Option<(i64,)> = sqlx::query_as(
+            r#"
+            SELECT 1 FROM projects p
+            LEFT JOIN project_members pm ON p.id = pm.project_id AND pm.user_id = $2
+            WHERE p.id = $1 AND p.deleted_at IS NULL AND (p.owner_id = $2 OR pm.role >= $3)
+            LIMIT 1
+            "#,
+        )
+        .bind(project_id)
+        .bind(user_id)
+        .bind(role)
         .fetch_optional(&self.pool)
         .await?;
 
@@ -164,7 +487,7 @@ impl TaskRepository for PgTaskRepository {
             r#"
             SELECT 1 FROM projects p
             LEFT JOIN project_members pm ON p.id = pm.project_id
-            WHERE p.id = $1 AND (p.owner_id = $2 OR pm.user_id = $2)
+            WHERE p.id = $1 AND p.deleted_at IS NULL AND (p.owner_id = $2 OR pm.user_id = $2)
             LIMIT 1
             "#,
         )
@@ -179,22 +502,29 @@ impl TaskRepository for PgTaskRepository {
     async fn create(&self, task: &Task) -> Result<Task, DomainError> {
         let row = sqlx::query_as::<_, TaskRow>(
             r#"
-            INSERT INTO tasks (id, project_id, milestone_id, title, description, status, priority, assignee_id, due_date, estimated_hours, actual_hours, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            INSERT INTO tasks (id, project_id, parent_id, milestone_id, sprint_id, title, description, status, workflow_status, priority, assignee_id, start_date, due_date, estimated_hours, actual_hours, story_points, rank, custom_fields, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20)
             RETURNING *
             "#,
         )
         .bind(task.id)
         .bind(task.project_id)
+        .bind(task.parent_id)
         .bind(task.milestone_id)
+        .bind(task.sprint_id)
         .bind(&task.title)
         .bind(&task.description)
         .bind(&task.status)
+        .bind(&task.workflow_status)
         .bind(&task.priority)
         .bind(task.assignee_id)
+        .bind(task.start_date)
         .bind(task.due_date)
         .bind(task.estimated_hours)
         .bind(task.actual_hours)
+        .bind(task.story_points)
+        .bind(task.rank)
+        .bind(Json(&task.custom_fields))
         .bind(task.created_at)
         .bind(task.updated_at)
         .fetch_one(&self.pool)
@@ -204,38 +534,148 @@ impl TaskRepository for PgTaskRepository {
     }
 
     async fn update(&self, task: &Task) -> Result<Task, DomainError> {
-        let row = sqlx::query_as::<_, TaskRow>(
+        update_task(&self.pool, task).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        trash_tasks(&self.pool, &[id]).await
+    }
+
+    async fn find_trashed(&self, project_id: Uuid) -> Result<Vec<Trashed<Task>>, DomainError> {
+        // Subtasks trashed together with their parent are restored with it, so only list the parent
+        let rows = sqlx::query_as::<_, TrashedTaskRow>(
             r#"
-            UPDATE tasks
-            SET title = $1, description = $2, status = $3, priority = $4,
-                assignee_id = $5, due_date = $6, estimated_hours = $7,
-                actual_hours = $8, milestone_id = $9, updated_at = NOW()
-            WHERE id = $10
+            SELECT t.* FROM tasks t
+            WHERE t.project_id = $1 AND t.deleted_at IS NOT NULL
+              AND NOT EXISTS (
+                  SELECT 1 FROM tasks parent
+                  WHERE parent.id = t.parent_id AND parent.deleted_at = t.deleted_at
+              )
+            ORDER BY t.deleted_at DESC
+            "#,
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_trashed_by_id(&self, id: Uuid) -> Result<Option<Trashed<Task>>, DomainError> {
+        let row = sqlx::query_as::<_, TrashedTaskRow>(
+            "SELECT * FROM tasks WHERE id = $1 AND deleted_at IS NOT NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn restore(&self, id: Uuid) -> Result<Vec<Task>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskRow>(
+            r#"
+            WITH RECURSIVE subtree AS (
+                SELECT t.id, t.deleted_at FROM tasks t
+                INNER JOIN projects p ON p.id = t.project_id
+                WHERE t.id = $1 AND t.deleted_at IS NOT NULL AND p.deleted_at IS NULL
+                UNION
+                SELECT t.id, t.deleted_at FROM tasks t
+                INNER JOIN subtree st ON t.parent_id = st.id AND t.deleted_at = st.deleted_at
+            )
+            UPDATE tasks SET deleted_at = NULL
+            WHERE id IN (SELECT id FROM subtree)
             RETURNING *
             "#,
         )
-        .bind(&task.title)
-        .bind(&task.description)
-        .bind(&task.status)
-        .bind(&task.priority)
-        .bind(task.assignee_id)
-        .bind(task.due_date)
-        .bind(task.estimated_hours)
-        .bind(task.actual_hours)
-        .bind(task.milestone_id)
-        .bind(task.id)
-        .fetch_one(&self.pool)
+        .bind(id)
+        .fetch_all(&self.pool)
         .await?;
 
-        Ok(row.into())
+        Ok(rows.into_iter().map(Into::into).collect())
     }
 
-    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
-        sqlx::query("DELETE FROM tasks WHERE id = $1")
-            .bind(id)
-            .execute(&self.pool)
+    async fn purge(&self, trashed_before: DateTime<Utc>) -> Result<Vec<Uuid>, DomainError> {
+        let ids: Vec<Uuid> = sqlx::query_scalar(
+            r#"
+            WITH RECURSIVE doomed AS (
+                SELECT id FROM tasks WHERE deleted_at < $1
+                UNION
+                SELECT t.id FROM tasks t
+                INNER JOIN doomed d ON t.parent_id = d.id
+            )
+            DELETE FROM tasks WHERE id IN (SELECT id FROM doomed)
+            RETURNING id
+            "#,
+        )
+        .bind(trashed_before)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(ids)
+    }
+
+    async fn move_task(&self, task: &Task, ranks: &[(Uuid, f64)], subtasks: &[Task]) -> Result<Task, DomainError> {
+        let mut tx = self.pool.begin().await?;
+
+        if !ranks.is_empty() {
+            let (ids, values): (Vec<Uuid>, Vec<f64>) = ranks.iter().copied().unzip();
+            sqlx::query(
+                r#"
+                UPDATE tasks AS t
+                SET rank = r.rank
+                FROM UNNEST($1::uuid[], $2::float8[]) AS r(id, rank)
+                WHERE t.id = r.id
+                "#,
+            )
+            .bind(&ids)
+            .bind(&values)
+            .execute(&mut *tx)
+            .await?;
+        }
+        let task = update_task(&mut *tx, task).await?;
+        for subtask in subtasks {
+            update_task(&mut *tx, subtask).await?;
+        }
+
+        tx.commit().await?;
+        Ok(task)
+    }
+
+    async fn apply_batch(&self, batch: &TaskBatch) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await?;
+
+        for task in &batch.updated {
+            update_task(&mut *tx, task).await?;
+        }
+
+        let updated_ids: Vec<Uuid> = batch.updated.iter().map(|task| task.id).collect();
+        if !updated_ids.is_empty() && !batch.add_tag_ids.is_empty() {
+            sqlx::query(
+                r#"
+                INSERT INTO task_tags (task_id, tag_id)
+                SELECT task_id, tag_id FROM UNNEST($1::uuid[]) AS task_id CROSS JOIN UNNEST($2::uuid[]) AS tag_id
+                ON CONFLICT (task_id, tag_id) DO NOTHING
+                "#,
+            )
+            .bind(&updated_ids)
+            .bind(&batch.add_tag_ids)
+            .execute(&mut *tx)
             .await?;
+        }
+        if !updated_ids.is_empty() && !batch.remove_tag_ids.is_empty() {
+            sqlx::query("DELETE FROM task_tags WHERE task_id = ANY($1) AND tag_id = ANY($2)")
+                .bind(&updated_ids)
+                .bind(&batch.remove_tag_ids)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        if !batch.deleted.is_empty() {
+            trash_tasks(&mut *tx, &batch.deleted).await?;
+        }
 
+        tx.commit().await?;
         Ok(())
     }
 }
@@ -0,0 +1,137 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::TaskTemplate;
+use crate::domain::repositories::TaskTemplateRepository;
+use crate::domain::value_objects::Priority;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct TaskTemplateRow {
+    id: Uuid,
+    project_id: Uuid,
+    name: String,
+    title_pattern: String,
+    description: Option<String>,
+    priority: Priority,
+    estimated_hours: Option<f32>,
+    tag_ids: Vec<Uuid>,
+    checklist: Vec<String>,
+    created_by: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<TaskTemplateRow> for TaskTemplate {
+    fn from(row: TaskTemplateRow) -> Self {
+        TaskTemplate {
+            id: row.id,
+            project_id: row.project_id,
+            name: row.name,
+            title_pattern: row.title_pattern,
+            description: row.description,
+            priority: row.priority,
+            estimated_hours: row.estimated_hours,
+            tag_ids: row.tag_ids,
+            checklist: row.checklist,
+            created_by: row.created_by,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgTaskTemplateRepository {
+    pool: PgPool,
+}
+
+impl PgTaskTemplateRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TaskTemplateRepository for PgTaskTemplateRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<TaskTemplate>, DomainError> {
+        let row = sqlx::query_as::<_, TaskTemplateRow>("SELECT * FROM task_templates WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Vec<TaskTemplate>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskTemplateRow>(
+            "SELECT * FROM task_templates WHERE project_id = $1 ORDER BY name ASC",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn create(&self, template: &TaskTemplate) -> Result<TaskTemplate, DomainError> {
+        let row = sqlx::query_as::<_, TaskTemplateRow>(
+            r#"
+            INSERT INTO task_templates (id, project_id, name, title_pattern, description, priority, estimated_hours,
+                                        tag_ids, checklist, created_by, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            RETURNING *
+            "#,
+        )
+        .bind(template.id)
+        .bind(template.project_id)
+        .bind(&template.name)
+        .bind(&template.title_pattern)
+        .bind(&template.description)
+        .bind(&template.priority)
+        .bind(template.estimated_hours)
+        .bind(&template.tag_ids)
+        .bind(&template.checklist)
+        .bind(template.created_by)
+        .bind(template.created_at)
+        .bind(template.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn update(&self, template: &TaskTemplate) -> Result<TaskTemplate, DomainError> {
+        let row = sqlx::query_as::<_, TaskTemplateRow>(
+            r#"
+            UPDATE task_templates
+            SET name = $1, title_pattern = $2, description = $3, priority = $4, estimated_hours = $5,
+                tag_ids = $6, checklist = $7
+            WHERE id = $8
+            RETURNING *
+            "#,
+        )
+        .bind(&template.name)
+        .bind(&template.title_pattern)
+        .bind(&template.description)
+        .bind(&template.priority)
+        .bind(template.estimated_hours)
+        .bind(&template.tag_ids)
+        .bind(&template.checklist)
+        .bind(template.id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM task_templates WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::TaskWatcher;
+use crate::domain::repositories::TaskWatcherRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct TaskWatcherRow {
+    task_id: Uuid,
+    user_id: Uuid,
+    created_at: DateTime<Utc>,
+    user_name: Option<String>,
+}
+
+impl From<TaskWatcherRow> for TaskWatcher {
+    fn from(row: TaskWatcherRow) -> Self {
+        TaskWatcher {
+            task_id: row.task_id,
+            user_id: row.user_id,
+            created_at: row.created_at,
+            user_name: row.user_name,
+        }
+    }
+}
+
+pub struct PgTaskWatcherRepository {
+    pool: PgPool,
+}
+
+impl PgTaskWatcherRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TaskWatcherRepository for PgTaskWatcherRepository {
+    async fn find_by_task(&self, task_id: Uuid) -> Result<Vec<TaskWatcher>, DomainError> {
+        let rows = sqlx::query_as::<_, TaskWatcherRow>(
+            r#"
+            SELECT w.task_id, w.user_id, w.created_at, u.name as user_name
+            FROM task_watchers w
+            LEFT JOIN users u ON w.user_id = u.id
+            WHERE w.task_id = $1
+            ORDER BY w.created_at ASC
+            "#,
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn watch(&self, watcher: &TaskWatcher) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO task_watchers (task_id, user_id, created_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (task_id, user_id) DO NOTHING
+            "#,
+        )
+        .bind(watcher.task_id)
+        .bind(watcher.user_id)
+        .bind(watcher.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn unwatch(&self, task_id: Uuid, user_id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM task_watchers WHERE task_id = $1 AND user_id = $2")
+            .bind(task_id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -64,7 +64,7 @@ impl PgTimeLogRepository {
             p.name as project_name,
             u.name as user_name
         FROM time_logs tl
-        JOIN tasks t ON tl.task_id = t.id
+        JOIN tasks t ON tl.task_id = t.id AND t.deleted_at IS NULL
         JOIN projects p ON t.project_id = p.id
         JOIN users u ON tl.user_id = u.id
         "#
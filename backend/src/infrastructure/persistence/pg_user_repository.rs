@@ -73,6 +73,23 @@ impl UserRepository for PgUserRepository {
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
+    async fn find_with_project_access(&self, project_id: Uuid) -> Result<Vec<User>, DomainError> {
+        let rows = sqlx::query_as::<_, UserRow>(
+            r#"
+            SELECT u.* FROM users u
+            WHERE u.role = 'admin'
+               OR u.id = (SELECT owner_id FROM projects WHERE id = $1)
+               OR u.id IN (SELECT user_id FROM project_members WHERE project_id = $1)
+            ORDER BY u.name ASC
+            "#,
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
     async fn create(&self, user: &User) -> Result<User, DomainError> {
         let row = sqlx::query_as::<_, UserRow>(
             r#"
@@ -0,0 +1,103 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::types::Json;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::entities::{ProjectWorkflow, WorkflowStatus, WorkflowTransition};
+use crate::domain::repositories::WorkflowRepository;
+use crate::shared::DomainError;
+
+#[derive(Debug, FromRow)]
+struct WorkflowRow {
+    id: Uuid,
+    project_id: Uuid,
+    statuses: Json<Vec<WorkflowStatus>>,
+    transitions: Json<Vec<WorkflowTransition>>,
+    require_subtasks_done: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<WorkflowRow> for ProjectWorkflow {
+    fn from(row: WorkflowRow) -> Self {
+        ProjectWorkflow {
+            id: row.id,
+            project_id: row.project_id,
+            statuses: row.statuses.0,
+            transitions: row.transitions.0,
+            require_subtasks_done: row.require_subtasks_done,
+            is_default: false,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+pub struct PgWorkflowRepository {
+    pool: PgPool,
+}
+
+impl PgWorkflowRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WorkflowRepository for PgWorkflowRepository {
+    async fn find_by_project(&self, project_id: Uuid) -> Result<Option<ProjectWorkflow>, DomainError> {
+        let row = sqlx::query_as::<_, WorkflowRow>(
+            "SELECT * FROM project_workflows WHERE project_id = $1",
+        )
+        .bind(project_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    async fn save(&self, workflow: &ProjectWorkflow) -> Result<ProjectWorkflow, DomainError> {
+        let row = sqlx::query_as::<_, WorkflowRow>(
+            r#"
+            INSERT INTO project_workflows (id, project_id, statuses, transitions, require_subtasks_done, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (project_id) DO UPDATE
+            SET statuses = EXCLUDED.statuses, transitions = EXCLUDED.transitions,
+                require_subtasks_done = EXCLUDED.require_subtasks_done, updated_at = NOW()
+            RETURNING *
+            "#,
+        )
+        .bind(workflow.id)
+        .bind(workflow.project_id)
+        .bind(Json(&workflow.statuses))
+        .bind(Json(&workflow.transitions))
+        .bind(workflow.require_subtasks_done)
+        .bind(workflow.created_at)
+        .bind(workflow.updated_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.into())
+    }
+
+    async fn delete_by_project(&self, project_id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM project_workflows WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_status_keys_in_use(&self, project_id: Uuid) -> Result<Vec<String>, DomainError> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            "SELECT DISTINCT workflow_status FROM tasks WHERE project_id = $1",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.0).collect())
+    }
+}
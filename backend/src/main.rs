@@ -8,20 +8,16 @@ use tower_http::cors::{AllowHeaders, AllowMethods, CorsLayer};
 use tower_http::trace::TraceLayer;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
-mod application;
-mod domain;
-mod infrastructure;
-mod presentation;
-mod shared;
-
-use application::services::{ActivityAppService, AttachmentAppService, AuthAppService, NotificationAppService, ProjectAppService, TagAppService, TaskAppService, TeamAppService, TimeLogAppService};
-use infrastructure::{
+use percival_backend::application::services::{ActivityAppService, AttachmentAppService, AuthAppService, BudgetAppService, ChangeHistoryAppService, ChecklistAppService, CustomFieldAppService, DueReminderAppService, MentionAppService, MilestoneAppService, NotificationAppService, ProjectAppService, ProjectHealthAppService, ProjectTemplateAppService, RecurringTaskAppService, ScheduleAppService, SearchAppService, SlaAppService, SprintAppService, TagAppService, TaskAppService, TaskCommentAppService, TaskTemplateAppService, TeamAppService, TimeLogAppService, TrashAppService, WorkflowAppService};
+use percival_backend::infrastructure::{
     config::AppConfig,
     database,
-    persistence::{PgActivityLogRepository, PgAttachmentRepository, PgNotificationRepository, PgProjectRepository, PgTagRepository, PgTaskRepository, PgTeamRepository, PgTimeLogRepository, PgUserRepository},
+    jobs,
+    persistence::{PgActivityLogRepository, PgAttachmentRepository, PgBudgetRepository, PgChangeHistoryRepository, PgChecklistRepository, PgCustomFieldRepository, PgDueReminderRepository, PgMilestoneRepository, PgNotificationRepository, PgProjectRepository, PgProjectTemplateRepository, PgRecurringTaskRepository, PgSearchRepository, PgSlaRepository, PgSprintRepository, PgTagRepository, PgTaskCommentRepository, PgTaskDependencyRepository, PgTaskRepository, PgTaskTemplateRepository, PgTaskWatcherRepository, PgTeamRepository, PgTimeLogRepository, PgUserRepository, PgWorkflowRepository},
 };
-use presentation::handlers::{activity_handler, attachment_handler, auth_handler, notification_handler, project_handler, tag_handler, task_handler, team_handler, time_log_handler};
-use presentation::middleware::auth_middleware;
+use percival_backend::domain::services::{HealthPolicy, HealthThreshold, ReminderSchedule};
+use percival_backend::presentation::handlers::{activity_handler, attachment_handler, auth_handler, budget_handler, change_history_handler, checklist_handler, custom_field_handler, milestone_handler, notification_handler, project_handler, project_health_handler, project_template_handler, recurring_task_handler, schedule_handler, search_handler, sla_handler, sprint_handler, tag_handler, task_comment_handler, task_handler, task_template_handler, team_handler, time_log_handler, workflow_handler};
+use percival_backend::presentation::middleware::auth_middleware;
 
 #[tokio::main]
 async fn main() {
@@ -52,6 +48,22 @@ async fn main() {
     let tag_repository = Arc::new(PgTagRepository::new(pool.clone()));
     let attachment_repository = Arc::new(PgAttachmentRepository::new(pool.clone()));
     let notification_repository = Arc::new(PgNotificationRepository::new(pool.clone()));
+    let workflow_repository = Arc::new(PgWorkflowRepository::new(pool.clone()));
+    let task_dependency_repository = Arc::new(PgTaskDependencyRepository::new(pool.clone()));
+    let task_comment_repository = Arc::new(PgTaskCommentRepository::new(pool.clone()));
+    let recurring_task_repository = Arc::new(PgRecurringTaskRepository::new(pool.clone()));
+    let checklist_repository = Arc::new(PgChecklistRepository::new(pool.clone()));
+    let task_template_repository = Arc::new(PgTaskTemplateRepository::new(pool.clone()));
+    let milestone_repository = Arc::new(PgMilestoneRepository::new(pool.clone()));
+    let task_watcher_repository = Arc::new(PgTaskWatcherRepository::new(pool.clone()));
+    let search_repository = Arc::new(PgSearchRepository::new(pool.clone()));
+    let custom_field_repository = Arc::new(PgCustomFieldRepository::new(pool.clone()));
+    let change_history_repository = Arc::new(PgChangeHistoryRepository::new(pool.clone()));
+    let sprint_repository = Arc::new(PgSprintRepository::new(pool.clone()));
+    let due_reminder_repository = Arc::new(PgDueReminderRepository::new(pool.clone()));
+    let sla_repository = Arc::new(PgSlaRepository::new(pool.clone()));
+    let budget_repository = Arc::new(PgBudgetRepository::new(pool.clone()));
+    let project_template_repository = Arc::new(PgProjectTemplateRepository::new(pool.clone()));
 
     // Setup upload directory
     let upload_dir = PathBuf::from(
@@ -62,19 +74,157 @@ async fn main() {
         .expect("Failed to create upload directory");
 
     // Create application services
+    let mention_service = Arc::new(MentionAppService::new(
+        user_repository.clone(),
+        notification_repository.clone(),
+    ));
+    let notification_service = Arc::new(NotificationAppService::new(
+        notification_repository,
+        task_watcher_repository,
+    ));
     let auth_service = Arc::new(AuthAppService::new(
-        user_repository,
+        user_repository.clone(),
         config.jwt_secret.clone(),
         config.jwt_expiration,
     ));
-    let project_service = Arc::new(ProjectAppService::new(project_repository));
-    let task_service = Arc::new(TaskAppService::new(task_repository));
+    let attachment_service = Arc::new(AttachmentAppService::new(
+        attachment_repository,
+        task_repository.clone(),
+        upload_dir,
+    ));
+    let project_service = Arc::new(ProjectAppService::new(
+        project_repository.clone(),
+        change_history_repository.clone(),
+        user_repository.clone(),
+        project_template_repository.clone(),
+    ));
+    let task_service = Arc::new(TaskAppService::new(
+        task_repository.clone(),
+        workflow_repository.clone(),
+        task_dependency_repository.clone(),
+        mention_service.clone(),
+        task_template_repository.clone(),
+        tag_repository.clone(),
+        checklist_repository.clone(),
+        milestone_repository.clone(),
+        custom_field_repository.clone(),
+        change_history_repository.clone(),
+        notification_service.clone(),
+        attachment_service.clone(),
+        time_log_repository.clone(),
+        activity_repository.clone(),
+    ));
     let team_service = Arc::new(TeamAppService::new(team_repository));
     let activity_service = Arc::new(ActivityAppService::new(activity_repository));
-    let time_log_service = Arc::new(TimeLogAppService::new(time_log_repository));
-    let tag_service = Arc::new(TagAppService::new(tag_repository));
-    let attachment_service = Arc::new(AttachmentAppService::new(attachment_repository, upload_dir));
-    let notification_service = Arc::new(NotificationAppService::new(notification_repository));
+    let time_log_service = Arc::new(TimeLogAppService::new(time_log_repository, task_repository.clone()));
+    let tag_service = Arc::new(TagAppService::new(tag_repository.clone()));
+    let workflow_service = Arc::new(WorkflowAppService::new(workflow_repository, project_repository.clone()));
+    let search_service = Arc::new(SearchAppService::new(search_repository));
+    let milestone_service = Arc::new(MilestoneAppService::new(
+        milestone_repository.clone(),
+        task_repository.clone(),
+        project_repository.clone(),
+    ));
+    let health_policy = HealthPolicy {
+        overdue_tasks: HealthThreshold::parse(&config.health_overdue_tasks).expect("HEALTH_OVERDUE_TASKS must be amber,red like 0.1,0.25"),
+        overdue_milestones: HealthThreshold::parse(&config.health_overdue_milestones).expect("HEALTH_OVERDUE_MILESTONES must be amber,red like 1,2"),
+        blocked_tasks: HealthThreshold::parse(&config.health_blocked_tasks).expect("HEALTH_BLOCKED_TASKS must be amber,red like 0.15,0.3"),
+        schedule_slip_days: HealthThreshold::parse(&config.health_schedule_slip_days).expect("HEALTH_SCHEDULE_SLIP_DAYS must be amber,red like 3,14"),
+        budget_consumption: HealthThreshold::parse(&config.health_budget_consumption).expect("HEALTH_BUDGET_CONSUMPTION must be amber,red like 0.9,1"),
+    };
+    let budget_service = Arc::new(BudgetAppService::new(
+        budget_repository,
+        project_repository.clone(),
+        user_repository,
+    ));
+    let project_health_service = Arc::new(ProjectHealthAppService::new(
+        project_repository.clone(),
+        milestone_repository.clone(),
+        budget_service.clone(),
+        health_policy,
+    ));
+    let schedule_service = Arc::new(ScheduleAppService::new(
+        project_repository.clone(),
+        task_dependency_repository.clone(),
+        milestone_repository.clone(),
+        config.schedule_hours_per_day,
+    ));
+    let project_template_service = Arc::new(ProjectTemplateAppService::new(
+        project_template_repository,
+        project_repository.clone(),
+        milestone_repository,
+        checklist_repository.clone(),
+        tag_repository.clone(),
+        task_dependency_repository,
+    ));
+    let sprint_service = Arc::new(SprintAppService::new(
+        sprint_repository,
+        task_repository.clone(),
+        project_repository.clone(),
+        change_history_repository.clone(),
+    ));
+    let due_reminder_service = Arc::new(DueReminderAppService::new(
+        due_reminder_repository,
+        notification_service.clone(),
+        ReminderSchedule::parse(&config.due_reminder_lead_times).expect("DUE_REMINDER_LEAD_TIMES must be a list like 24h,1h"),
+    ));
+    let sla_service = Arc::new(SlaAppService::new(
+        sla_repository,
+        project_repository.clone(),
+        task_repository.clone(),
+        notification_service.clone(),
+    ));
+    let trash_service = Arc::new(TrashAppService::new(
+        task_repository.clone(),
+        project_repository.clone(),
+        attachment_service.clone(),
+        config.trash_retention_days,
+    ));
+    let change_history_service = Arc::new(ChangeHistoryAppService::new(
+        change_history_repository,
+        project_repository.clone(),
+        task_repository.clone(),
+    ));
+    let custom_field_service = Arc::new(CustomFieldAppService::new(custom_field_repository, project_repository.clone()));
+    let checklist_service = Arc::new(ChecklistAppService::new(checklist_repository, task_repository.clone()));
+    let task_template_service = Arc::new(TaskTemplateAppService::new(
+        task_template_repository,
+        project_repository.clone(),
+        tag_repository.clone(),
+    ));
+    let recurring_task_service = Arc::new(RecurringTaskAppService::new(
+        recurring_task_repository,
+        project_repository,
+        task_service.clone(),
+    ));
+
+    // Background jobs
+    jobs::spawn_recurring_task_generator(
+        recurring_task_service.clone(),
+        Duration::from_secs(config.recurring_task_interval_secs),
+    );
+    jobs::spawn_sprint_snapshotter(
+        sprint_service.clone(),
+        Duration::from_secs(config.sprint_snapshot_interval_secs),
+    );
+    jobs::spawn_due_reminder_scheduler(
+        due_reminder_service,
+        Duration::from_secs(config.due_reminder_interval_secs),
+    );
+    jobs::spawn_sla_monitor(
+        sla_service.clone(),
+        Duration::from_secs(config.sla_check_interval_secs),
+    );
+    jobs::spawn_trash_purger(
+        trash_service,
+        Duration::from_secs(config.trash_purge_interval_secs),
+    );
+    let task_comment_service = Arc::new(TaskCommentAppService::new(
+        task_comment_repository,
+        task_repository,
+        notification_service.clone(),
+        mention_service,
+    ));
 
     // CORS configuration - restrict to allowed origins
     let cors = CorsLayer::new()
@@ -100,7 +250,7 @@ async fn main() {
         .route("/health", get(health_check))
         .nest(
             "/api/v1",
-            api_routes(auth_service, project_service, task_service, team_service, activity_service, time_log_service, tag_service, attachment_service, notification_service),
+            api_routes(auth_service, project_service, task_service, team_service, activity_service, time_log_service, tag_service, attachment_service, notification_service, workflow_service, task_comment_service, recurring_task_service, checklist_service, task_template_service, search_service, custom_field_service, change_history_service, milestone_service, project_health_service, budget_service, sprint_service, sla_service, schedule_service, project_template_service),
         )
         .layer(cors)
         .layer(TraceLayer::new_for_http());
@@ -118,6 +268,7 @@ async fn health_check() -> &'static str {
     "PMO Backend is running!"
 }
 
+#[allow(clippy::too_many_arguments)]
 fn api_routes(
     auth_service: Arc<AuthAppService>,
     project_service: Arc<ProjectAppService>,
@@ -128,6 +279,21 @@ fn api_routes(
     tag_service: Arc<TagAppService>,
     attachment_service: Arc<AttachmentAppService>,
     notification_service: Arc<NotificationAppService>,
+    workflow_service: Arc<WorkflowAppService>,
+    task_comment_service: Arc<TaskCommentAppService>,
+    recurring_task_service: Arc<RecurringTaskAppService>,
+    checklist_service: Arc<ChecklistAppService>,
+    task_template_service: Arc<TaskTemplateAppService>,
+    search_service: Arc<SearchAppService>,
+    custom_field_service: Arc<CustomFieldAppService>,
+    change_history_service: Arc<ChangeHistoryAppService>,
+    milestone_service: Arc<MilestoneAppService>,
+    project_health_service: Arc<ProjectHealthAppService>,
+    budget_service: Arc<BudgetAppService>,
+    sprint_service: Arc<SprintAppService>,
+    sla_service: Arc<SlaAppService>,
+    schedule_service: Arc<ScheduleAppService>,
+    project_template_service: Arc<ProjectTemplateAppService>,
 ) -> Router {
     // Public auth routes (no authentication required)
     let public_auth_routes = Router::new()
@@ -142,14 +308,16 @@ fn api_routes(
         .route("/projects/{id}", get(project_handler::get_project))
         .route("/projects/{id}", put(project_handler::update_project))
         .route("/projects/{id}", delete(project_handler::delete_project))
+        .route("/projects/{id}/restore", post(project_handler::restore_project))
+        .route("/trash/projects", get(project_handler::list_trashed_projects))
         .route(
             "/projects/{id}/tasks",
             get(project_handler::get_project_tasks),
         )
-        .route(
-            "/projects/{id}/milestones",
-            get(project_handler::get_project_milestones),
-        )
+        .route("/projects/{id}/members", get(project_handler::get_project_members))
+        .route("/projects/{id}/members", post(project_handler::add_project_member))
+        .route("/projects/{id}/members/{user_id}", put(project_handler::update_project_member))
+        .route("/projects/{id}/members/{user_id}", delete(project_handler::remove_project_member))
         .layer(middleware::from_fn(auth_middleware))
         .with_state(project_service);
 
@@ -157,9 +325,25 @@ fn api_routes(
     let task_routes = Router::new()
         .route("/tasks", get(task_handler::list_tasks))
         .route("/tasks", post(task_handler::create_task))
+        .route("/tasks/bulk", post(task_handler::bulk_update_tasks))
         .route("/tasks/{id}", get(task_handler::get_task))
         .route("/tasks/{id}", put(task_handler::update_task))
         .route("/tasks/{id}", delete(task_handler::delete_task))
+        .route("/tasks/{id}/restore", post(task_handler::restore_task))
+        .route("/projects/{id}/trash", get(task_handler::get_project_trash))
+        .route("/tasks/{id}/move", post(task_handler::move_task))
+        .route("/tasks/{id}/clone", post(task_handler::clone_task))
+        .route("/tasks/{id}/watchers", get(task_handler::get_task_watchers))
+        .route("/tasks/{id}/watch", post(task_handler::watch_task))
+        .route("/tasks/{id}/watch", delete(task_handler::unwatch_task))
+        .route("/tasks/{id}/subtasks", get(task_handler::get_subtasks))
+        .route("/tasks/{id}/tree", get(task_handler::get_task_tree))
+        .route("/tasks/{id}/dependencies", get(task_handler::get_task_dependencies))
+        .route("/tasks/{id}/dependencies", post(task_handler::add_task_dependency))
+        .route(
+            "/tasks/{id}/dependencies/{blocking_task_id}",
+            delete(task_handler::remove_task_dependency),
+        )
         .layer(middleware::from_fn(auth_middleware))
         .with_state(task_service);
 
@@ -226,6 +410,156 @@ fn api_routes(
         .layer(middleware::from_fn(auth_middleware))
         .with_state(notification_service);
 
+    // Protected workflow routes
+    let workflow_routes = Router::new()
+        .route("/projects/{id}/workflow", get(workflow_handler::get_workflow))
+        .route("/projects/{id}/workflow", put(workflow_handler::set_workflow))
+        .route("/projects/{id}/workflow", delete(workflow_handler::reset_workflow))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(workflow_service);
+
+    // Protected task comment routes
+    let task_comment_routes = Router::new()
+        .route("/tasks/{task_id}/comments", get(task_comment_handler::list_comments))
+        .route("/tasks/{task_id}/comments", post(task_comment_handler::create_comment))
+        .route("/tasks/{task_id}/comments/{comment_id}", put(task_comment_handler::update_comment))
+        .route("/tasks/{task_id}/comments/{comment_id}", delete(task_comment_handler::delete_comment))
+        .route(
+            "/tasks/{task_id}/comments/{comment_id}/history",
+            get(task_comment_handler::get_comment_history),
+        )
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(task_comment_service);
+
+    // Protected recurring task routes
+    let recurring_task_routes = Router::new()
+        .route("/projects/{id}/recurring-tasks", get(recurring_task_handler::list_recurring_tasks))
+        .route("/projects/{id}/recurring-tasks", post(recurring_task_handler::create_recurring_task))
+        .route("/recurring-tasks/{id}", get(recurring_task_handler::get_recurring_task))
+        .route("/recurring-tasks/{id}", put(recurring_task_handler::update_recurring_task))
+        .route("/recurring-tasks/{id}", delete(recurring_task_handler::delete_recurring_task))
+        .route("/recurring-tasks/{id}/occurrences", get(recurring_task_handler::list_occurrences))
+        .route("/recurring-tasks/{id}/occurrences/{date}", put(recurring_task_handler::update_occurrence))
+        .route("/recurring-tasks/{id}/occurrences/{date}", delete(recurring_task_handler::reset_occurrence))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(recurring_task_service);
+
+    // Protected checklist routes
+    let checklist_routes = Router::new()
+        .route("/tasks/{task_id}/checklist", get(checklist_handler::get_checklist))
+        .route("/tasks/{task_id}/checklist", post(checklist_handler::add_checklist_item))
+        .route("/tasks/{task_id}/checklist/order", put(checklist_handler::reorder_checklist))
+        .route("/tasks/{task_id}/checklist/{item_id}", put(checklist_handler::update_checklist_item))
+        .route("/tasks/{task_id}/checklist/{item_id}", delete(checklist_handler::delete_checklist_item))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(checklist_service);
+
+    // Protected task template routes
+    let task_template_routes = Router::new()
+        .route("/projects/{id}/task-templates", get(task_template_handler::list_task_templates))
+        .route("/projects/{id}/task-templates", post(task_template_handler::create_task_template))
+        .route("/task-templates/{id}", get(task_template_handler::get_task_template))
+        .route("/task-templates/{id}", put(task_template_handler::update_task_template))
+        .route("/task-templates/{id}", delete(task_template_handler::delete_task_template))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(task_template_service);
+
+    // Protected custom field routes
+    let custom_field_routes = Router::new()
+        .route("/projects/{id}/custom-fields", get(custom_field_handler::list_custom_fields))
+        .route("/projects/{id}/custom-fields", post(custom_field_handler::create_custom_field))
+        .route("/custom-fields/{id}", put(custom_field_handler::update_custom_field))
+        .route("/custom-fields/{id}", delete(custom_field_handler::delete_custom_field))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(custom_field_service);
+
+    // Protected change history routes
+    let change_history_routes = Router::new()
+        .route("/tasks/{id}/history", get(change_history_handler::get_task_history))
+        .route("/projects/{id}/history", get(change_history_handler::get_project_history))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(change_history_service);
+
+    // Protected milestone routes
+    let milestone_routes = Router::new()
+        .route("/projects/{id}/milestones", get(milestone_handler::list_milestones))
+        .route("/projects/{id}/milestones", post(milestone_handler::create_milestone))
+        .route("/milestones/{id}", get(milestone_handler::get_milestone))
+        .route("/milestones/{id}", put(milestone_handler::update_milestone))
+        .route("/milestones/{id}", delete(milestone_handler::delete_milestone))
+        .route("/milestones/{id}/complete", post(milestone_handler::complete_milestone))
+        .route("/milestones/{id}/tasks", get(milestone_handler::get_milestone_tasks))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(milestone_service);
+
+    // Protected project health routes
+    let project_health_routes = Router::new()
+        .route("/projects/health", get(project_health_handler::get_portfolio_health))
+        .route("/projects/{id}/health", get(project_health_handler::get_project_health))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(project_health_service);
+
+    // Protected budget routes
+    let budget_routes = Router::new()
+        .route("/users/{user_id}/cost-rates", get(budget_handler::list_cost_rates))
+        .route("/users/{user_id}/cost-rates", post(budget_handler::create_cost_rate))
+        .route("/cost-rates/{id}", delete(budget_handler::delete_cost_rate))
+        .route("/projects/{id}/expenses", get(budget_handler::list_expenses))
+        .route("/projects/{id}/expenses", post(budget_handler::create_expense))
+        .route("/expenses/{id}", put(budget_handler::update_expense))
+        .route("/expenses/{id}", delete(budget_handler::delete_expense))
+        .route("/projects/{id}/budget", get(budget_handler::get_budget_report))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(budget_service);
+
+    // Protected sprint routes
+    let sprint_routes = Router::new()
+        .route("/projects/{id}/sprints", get(sprint_handler::list_sprints))
+        .route("/projects/{id}/sprints", post(sprint_handler::create_sprint))
+        .route("/sprints/{id}", get(sprint_handler::get_sprint))
+        .route("/sprints/{id}", put(sprint_handler::update_sprint))
+        .route("/sprints/{id}", delete(sprint_handler::delete_sprint))
+        .route("/sprints/{id}/tasks", get(sprint_handler::get_sprint_tasks))
+        .route("/sprints/{id}/tasks", post(sprint_handler::plan_sprint_tasks))
+        .route("/sprints/{id}/tasks/{task_id}", delete(sprint_handler::unplan_sprint_task))
+        .route("/sprints/{id}/start", post(sprint_handler::start_sprint))
+        .route("/sprints/{id}/close", post(sprint_handler::close_sprint))
+        .route("/sprints/{id}/burndown", get(sprint_handler::get_sprint_burndown))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(sprint_service);
+
+    // Protected SLA routes
+    let sla_routes = Router::new()
+        .route("/projects/{id}/sla-policies", get(sla_handler::list_sla_policies))
+        .route("/projects/{id}/sla-policies", post(sla_handler::create_sla_policy))
+        .route("/projects/{id}/sla-breaches", get(sla_handler::get_sla_breach_report))
+        .route("/sla-policies/{id}", put(sla_handler::update_sla_policy))
+        .route("/sla-policies/{id}", delete(sla_handler::delete_sla_policy))
+        .route("/tasks/{id}/sla", get(sla_handler::get_task_sla))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(sla_service);
+
+    // Protected schedule routes
+    let schedule_routes = Router::new()
+        .route("/projects/{id}/schedule", get(schedule_handler::get_project_schedule))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(schedule_service);
+
+    // Protected project template routes
+    let project_template_routes = Router::new()
+        .route("/project-templates", get(project_template_handler::list_project_templates))
+        .route("/project-templates", post(project_template_handler::save_project_template))
+        .route("/project-templates/{id}", get(project_template_handler::get_project_template))
+        .route("/project-templates/{id}", delete(project_template_handler::delete_project_template))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(project_template_service);
+
+    // Protected search routes
+    let search_routes = Router::new()
+        .route("/search", get(search_handler::search))
+        .layer(middleware::from_fn(auth_middleware))
+        .with_state(search_service);
+
     Router::new()
         .merge(public_auth_routes)
         .merge(project_routes)
@@ -236,4 +570,19 @@ fn api_routes(
         .merge(tag_routes)
         .merge(attachment_routes)
         .merge(notification_routes)
+        .merge(workflow_routes)
+        .merge(task_comment_routes)
+        .merge(recurring_task_routes)
+        .merge(checklist_routes)
+        .merge(task_template_routes)
+        .merge(search_routes)
+        .merge(custom_field_routes)
+        .merge(change_history_routes)
+        .merge(milestone_routes)
+        .merge(project_health_routes)
+        .merge(budget_routes)
+        .merge(sprint_routes)
+        .merge(sla_routes)
+        .merge(schedule_routes)
+        .merge(project_template_routes)
 }
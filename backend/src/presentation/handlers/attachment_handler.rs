@@ -16,6 +16,7 @@ use uuid::Uuid;
 
 use crate::application::services::AttachmentAppService;
 use crate::domain::entities::Attachment;
+use crate::domain::value_objects::{ProjectRole, UserRole};
 use crate::presentation::dto::ApiResponse;
 use crate::presentation::middleware::AuthUser;
 use crate::shared::DomainError;
@@ -35,6 +36,11 @@ pub async fn upload_attachment(
     Path(task_id): Path<Uuid>,
     mut multipart: Multipart,
 ) -> Result<Json<ApiResponse<Attachment>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_role(task_id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this task".into()));
+    }
+
     while let Some(field) = multipart.next_field().await.map_err(|e| {
         DomainError::ValidationError(format!("Failed to read multipart field: {}", e))
     })? {
@@ -106,6 +112,11 @@ pub async fn delete_attachment(
     Extension(auth_user): Extension<AuthUser>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_attachment_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to delete this attachment".into()));
+    }
+
     tracing::info!(
         user_id = %auth_user.id,
         attachment_id = %id,
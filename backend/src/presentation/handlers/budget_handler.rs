@@ -0,0 +1,159 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateCostRateCommand, CreateExpenseCommand, UpdateExpenseCommand};
+use crate::application::services::BudgetAppService;
+use crate::domain::entities::{CostRate, Expense};
+use crate::domain::services::BudgetReport;
+use crate::domain::value_objects::{ProjectRole, UserRole};
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+/// GET /users/:user_id/cost-rates - List cost rates of a user (admin or the user)
+pub async fn list_cost_rates(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<CostRate>>>, DomainError> {
+    if auth_user.role != UserRole::Admin && auth_user.id != user_id {
+        return Err(DomainError::Forbidden("You can only view your own cost rates".into()));
+    }
+    let rates = service.list_rates(user_id).await?;
+    Ok(Json(ApiResponse::success(rates)))
+}
+
+/// POST /users/:user_id/cost-rates - Set a cost rate effective from a date (admin only)
+pub async fn create_cost_rate(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(user_id): Path<Uuid>,
+    Json(cmd): Json<CreateCostRateCommand>,
+) -> Result<Json<ApiResponse<CostRate>>, DomainError> {
+    if auth_user.role != UserRole::Admin {
+        return Err(DomainError::Forbidden("Only admins can manage cost rates".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        rate_user_id = %user_id,
+        effective_from = %cmd.effective_from,
+        "User creating cost rate"
+    );
+    let rate = service.create_rate(user_id, cmd).await?;
+    Ok(Json(ApiResponse::success(rate)))
+}
+
+/// DELETE /cost-rates/:id - Delete a cost rate (admin only)
+pub async fn delete_cost_rate(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    if auth_user.role != UserRole::Admin {
+        return Err(DomainError::Forbidden("Only admins can manage cost rates".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        rate_id = %id,
+        "User deleting cost rate"
+    );
+    service.delete_rate(id).await?;
+    Ok(Json(ApiResponse::ok("Cost rate deleted successfully")))
+}
+
+/// GET /projects/:id/expenses - List the expense ledger of a project
+pub async fn list_expenses(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<Expense>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_project(project_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this project".into()));
+    }
+    let expenses = service.list_expenses(project_id).await?;
+    Ok(Json(ApiResponse::success(expenses)))
+}
+
+/// POST /projects/:id/expenses - Book an expense against the project budget
+pub async fn create_expense(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+    Json(cmd): Json<CreateExpenseCommand>,
+) -> Result<Json<ApiResponse<Expense>>, DomainError> {
+    // Only project owner, maintainers or admin can manage the budget
+    if auth_user.role != UserRole::Admin && !service.has_project_role(project_id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can manage the budget".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %project_id,
+        category = %cmd.category,
+        "User booking expense"
+    );
+    let expense = service.create_expense(project_id, cmd, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(expense)))
+}
+
+/// PUT /expenses/:id - Update an expense
+pub async fn update_expense(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<UpdateExpenseCommand>,
+) -> Result<Json<ApiResponse<Expense>>, DomainError> {
+    // Only project owner, maintainers or admin can manage the budget
+    if auth_user.role != UserRole::Admin && !service.has_expense_role(id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can manage the budget".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        expense_id = %id,
+        "User updating expense"
+    );
+    let expense = service.update_expense(id, cmd).await?;
+    Ok(Json(ApiResponse::success(expense)))
+}
+
+/// DELETE /expenses/:id - Delete an expense
+pub async fn delete_expense(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Only project owner, maintainers or admin can manage the budget
+    if auth_user.role != UserRole::Admin && !service.has_expense_role(id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can manage the budget".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        expense_id = %id,
+        "User deleting expense"
+    );
+    service.delete_expense(id).await?;
+    Ok(Json(ApiResponse::ok("Expense deleted successfully")))
+}
+
+/// GET /projects/:id/budget - Spend, remaining budget, burn rate and forecast by user, team and month
+pub async fn get_budget_report(
+    State(service): State<Arc<BudgetAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<BudgetReport>>, DomainError> {
+    // Costs per user reveal rates, so only project owner, maintainers or admin can see them
+    if auth_user.role != UserRole::Admin && !service.has_project_role(project_id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can view the budget".into()));
+    }
+    let report = service.get_report(project_id).await?;
+    Ok(Json(ApiResponse::success(report)))
+}
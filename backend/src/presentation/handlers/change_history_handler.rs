@@ -0,0 +1,41 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::services::ChangeHistoryAppService;
+use crate::domain::entities::{ChangeEntityType, ChangeRecord};
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+/// GET /tasks/:id/history - Field-level change history of a task, also after deletion
+pub async fn get_task_history(
+    State(service): State<Arc<ChangeHistoryAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<ChangeRecord>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(ChangeEntityType::Task, id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this task".into()));
+    }
+    let history = service.get_history(ChangeEntityType::Task, id).await?;
+    Ok(Json(ApiResponse::success(history)))
+}
+
+/// GET /projects/:id/history - Field-level change history of a project, also after deletion
+pub async fn get_project_history(
+    State(service): State<Arc<ChangeHistoryAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<ChangeRecord>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(ChangeEntityType::Project, id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this project".into()));
+    }
+    let history = service.get_history(ChangeEntityType::Project, id).await?;
+    Ok(Json(ApiResponse::success(history)))
+}
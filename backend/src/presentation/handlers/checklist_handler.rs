@@ -0,0 +1,115 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{
+    CreateChecklistItemCommand, ReorderChecklistCommand, UpdateChecklistItemCommand,
+};
+use crate::application::services::ChecklistAppService;
+use crate::domain::entities::{Checklist, ChecklistItem};
+use crate::domain::value_objects::{ProjectRole, UserRole};
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+/// GET /tasks/:task_id/checklist - Get the checklist of a task with its completion
+pub async fn get_checklist(
+    State(service): State<Arc<ChecklistAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Checklist>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(task_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this task".into()));
+    }
+    let checklist = service.get_checklist(task_id).await?;
+    Ok(Json(ApiResponse::success(checklist)))
+}
+
+/// POST /tasks/:task_id/checklist - Add a checklist item
+pub async fn add_checklist_item(
+    State(service): State<Arc<ChecklistAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+    Json(cmd): Json<CreateChecklistItemCommand>,
+) -> Result<Json<ApiResponse<ChecklistItem>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_role(task_id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this task".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %task_id,
+        "User adding checklist item"
+    );
+    let item = service.add_item(task_id, cmd).await?;
+    Ok(Json(ApiResponse::success(item)))
+}
+
+/// PUT /tasks/:task_id/checklist/order - Reorder the checklist
+pub async fn reorder_checklist(
+    State(service): State<Arc<ChecklistAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+    Json(cmd): Json<ReorderChecklistCommand>,
+) -> Result<Json<ApiResponse<Checklist>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_role(task_id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this task".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %task_id,
+        "User reordering checklist"
+    );
+    let checklist = service.reorder(task_id, cmd).await?;
+    Ok(Json(ApiResponse::success(checklist)))
+}
+
+/// PUT /tasks/:task_id/checklist/:item_id - Edit or check off a checklist item
+pub async fn update_checklist_item(
+    State(service): State<Arc<ChecklistAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((task_id, item_id)): Path<(Uuid, Uuid)>,
+    Json(cmd): Json<UpdateChecklistItemCommand>,
+) -> Result<Json<ApiResponse<ChecklistItem>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_role(task_id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this task".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %task_id,
+        item_id = %item_id,
+        "User updating checklist item"
+    );
+    let item = service.update_item(task_id, item_id, cmd, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(item)))
+}
+
+/// DELETE /tasks/:task_id/checklist/:item_id - Remove a checklist item
+pub async fn delete_checklist_item(
+    State(service): State<Arc<ChecklistAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((task_id, item_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_role(task_id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this task".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %task_id,
+        item_id = %item_id,
+        "User deleting checklist item"
+    );
+    service.delete_item(task_id, item_id).await?;
+    Ok(Json(ApiResponse::ok("Checklist item deleted successfully")))
+}
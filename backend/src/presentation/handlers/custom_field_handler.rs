@@ -0,0 +1,91 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateCustomFieldCommand, UpdateCustomFieldCommand};
+use crate::application::services::CustomFieldAppService;
+use crate::domain::entities::CustomField;
+use crate::domain::value_objects::{ProjectRole, UserRole};
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+/// GET /projects/:id/custom-fields - List custom fields of a project
+pub async fn list_custom_fields(
+    State(service): State<Arc<CustomFieldAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<CustomField>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_project(project_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this project".into()));
+    }
+    let fields = service.list_fields(project_id).await?;
+    Ok(Json(ApiResponse::success(fields)))
+}
+
+/// POST /projects/:id/custom-fields - Define a custom field
+pub async fn create_custom_field(
+    State(service): State<Arc<CustomFieldAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+    Json(cmd): Json<CreateCustomFieldCommand>,
+) -> Result<Json<ApiResponse<CustomField>>, DomainError> {
+    // Only project owner, maintainers or admin can manage custom fields
+    if auth_user.role != UserRole::Admin && !service.has_project_role(project_id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can manage custom fields".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %project_id,
+        name = %cmd.name,
+        "User creating custom field"
+    );
+    let field = service.create_field(project_id, cmd).await?;
+    Ok(Json(ApiResponse::success(field)))
+}
+
+/// PUT /custom-fields/:id - Update a custom field
+pub async fn update_custom_field(
+    State(service): State<Arc<CustomFieldAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<UpdateCustomFieldCommand>,
+) -> Result<Json<ApiResponse<CustomField>>, DomainError> {
+    // Only project owner, maintainers or admin can manage custom fields
+    if auth_user.role != UserRole::Admin && !service.has_field_role(id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can manage custom fields".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        field_id = %id,
+        "User updating custom field"
+    );
+    let field = service.update_field(id, cmd).await?;
+    Ok(Json(ApiResponse::success(field)))
+}
+
+/// DELETE /custom-fields/:id - Delete a custom field and its values
+pub async fn delete_custom_field(
+    State(service): State<Arc<CustomFieldAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Only project owner, maintainers or admin can manage custom fields
+    if auth_user.role != UserRole::Admin && !service.has_field_role(id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can manage custom fields".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        field_id = %id,
+        "User deleting custom field"
+    );
+    service.delete_field(id).await?;
+    Ok(Json(ApiResponse::ok("Custom field deleted successfully")))
+}
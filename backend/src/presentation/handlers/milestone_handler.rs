@@ -0,0 +1,139 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateMilestoneCommand, UpdateMilestoneCommand};
+use crate::application::services::{MilestoneAppService, MilestoneWithProgress};
+use crate::domain::entities::{Milestone, Task};
+use crate::domain::value_objects::{ProjectRole, UserRole};
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+/// GET /projects/:id/milestones - List milestones of a project with their progress
+pub async fn list_milestones(
+    State(service): State<Arc<MilestoneAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<MilestoneWithProgress>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_project(project_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this project".into()));
+    }
+    let milestones = service.list_milestones(project_id).await?;
+    Ok(Json(ApiResponse::success(milestones)))
+}
+
+/// POST /projects/:id/milestones - Create a milestone
+pub async fn create_milestone(
+    State(service): State<Arc<MilestoneAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+    Json(cmd): Json<CreateMilestoneCommand>,
+) -> Result<Json<ApiResponse<Milestone>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_project_role(project_id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this project".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %project_id,
+        name = %cmd.name,
+        "User creating milestone"
+    );
+    let milestone = service.create_milestone(project_id, cmd).await?;
+    Ok(Json(ApiResponse::success(milestone)))
+}
+
+/// GET /milestones/:id - Get a milestone with its progress
+pub async fn get_milestone(
+    State(service): State<Arc<MilestoneAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<MilestoneWithProgress>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_milestone(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this milestone".into()));
+    }
+    let milestone = service.get_milestone(id).await?;
+    Ok(Json(ApiResponse::success(milestone)))
+}
+
+/// PUT /milestones/:id - Update a milestone
+pub async fn update_milestone(
+    State(service): State<Arc<MilestoneAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<UpdateMilestoneCommand>,
+) -> Result<Json<ApiResponse<MilestoneWithProgress>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_milestone_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this milestone".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        milestone_id = %id,
+        "User updating milestone"
+    );
+    let milestone = service.update_milestone(id, cmd).await?;
+    Ok(Json(ApiResponse::success(milestone)))
+}
+
+/// DELETE /milestones/:id - Delete a milestone, keeping its tasks
+pub async fn delete_milestone(
+    State(service): State<Arc<MilestoneAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_milestone_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this milestone".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        milestone_id = %id,
+        "User deleting milestone"
+    );
+    service.delete_milestone(id).await?;
+    Ok(Json(ApiResponse::ok("Milestone deleted successfully")))
+}
+
+/// POST /milestones/:id/complete - Mark a milestone completed
+pub async fn complete_milestone(
+    State(service): State<Arc<MilestoneAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<MilestoneWithProgress>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_milestone_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this milestone".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        milestone_id = %id,
+        "User completing milestone"
+    );
+    let milestone = service.complete_milestone(id).await?;
+    Ok(Json(ApiResponse::success(milestone)))
+}
+
+/// GET /milestones/:id/tasks - List tasks assigned to a milestone
+pub async fn get_milestone_tasks(
+    State(service): State<Arc<MilestoneAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<Task>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_milestone(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this milestone".into()));
+    }
+    let tasks = service.get_milestone_tasks(id).await?;
+    Ok(Json(ApiResponse::success(tasks)))
+}
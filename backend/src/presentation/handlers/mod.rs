@@ -1,9 +1,24 @@
 pub mod activity_handler;
 pub mod attachment_handler;
 pub mod auth_handler;
+pub mod budget_handler;
+pub mod change_history_handler;
+pub mod checklist_handler;
+pub mod custom_field_handler;
+pub mod milestone_handler;
 pub mod notification_handler;
 pub mod project_handler;
+pub mod project_health_handler;
+pub mod project_template_handler;
+pub mod recurring_task_handler;
+pub mod schedule_handler;
+pub mod search_handler;
+pub mod sla_handler;
+pub mod sprint_handler;
 pub mod tag_handler;
+pub mod task_comment_handler;
 pub mod task_handler;
+pub mod task_template_handler;
 pub mod team_handler;
 pub mod time_log_handler;
+pub mod workflow_handler;
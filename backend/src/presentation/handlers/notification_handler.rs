@@ -8,8 +8,9 @@ use serde::Serialize;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::application::services::{Claims, NotificationAppService};
+use crate::application::services::NotificationAppService;
 use crate::domain::entities::Notification;
+use crate::presentation::middleware::AuthUser;
 
 #[derive(Serialize)]
 struct ApiResponse<T> {
@@ -25,9 +26,9 @@ struct UnreadCountResponse {
 
 pub async fn list_notifications(
     State(service): State<Arc<NotificationAppService>>,
-    Extension(claims): Extension<Claims>,
+    Extension(auth_user): Extension<AuthUser>,
 ) -> impl IntoResponse {
-    match service.get_user_notifications(claims.sub).await {
+    match service.get_user_notifications(auth_user.id).await {
         Ok(notifications) => (
             StatusCode::OK,
             Json(ApiResponse {
@@ -49,9 +50,9 @@ pub async fn list_notifications(
 
 pub async fn get_unread_count(
     State(service): State<Arc<NotificationAppService>>,
-    Extension(claims): Extension<Claims>,
+    Extension(auth_user): Extension<AuthUser>,
 ) -> impl IntoResponse {
-    match service.get_unread_count(claims.sub).await {
+    match service.get_unread_count(auth_user.id).await {
         Ok(count) => (
             StatusCode::OK,
             Json(ApiResponse {
@@ -73,10 +74,10 @@ pub async fn get_unread_count(
 
 pub async fn mark_as_read(
     State(service): State<Arc<NotificationAppService>>,
-    Extension(claims): Extension<Claims>,
+    Extension(auth_user): Extension<AuthUser>,
     Path(id): Path<Uuid>,
 ) -> impl IntoResponse {
-    match service.mark_as_read(id, claims.sub).await {
+    match service.mark_as_read(id, auth_user.id).await {
         Ok(_) => (
             StatusCode::OK,
             Json(ApiResponse::<()> {
@@ -98,9 +99,9 @@ pub async fn mark_as_read(
 
 pub async fn mark_all_as_read(
     State(service): State<Arc<NotificationAppService>>,
-    Extension(claims): Extension<Claims>,
+    Extension(auth_user): Extension<AuthUser>,
 ) -> impl IntoResponse {
-    match service.mark_all_as_read(claims.sub).await {
+    match service.mark_all_as_read(auth_user.id).await {
         Ok(_) => (
             StatusCode::OK,
             Json(ApiResponse::<()> {
@@ -122,10 +123,10 @@ pub async fn mark_all_as_read(
 
 pub async fn delete_notification(
     State(service): State<Arc<NotificationAppService>>,
-    Extension(claims): Extension<Claims>,
+    Extension(auth_user): Extension<AuthUser>,
     Path(id): Path<Uuid>,
 ) -> impl IntoResponse {
-    match service.delete_notification(id, claims.sub).await {
+    match service.delete_notification(id, auth_user.id).await {
         Ok(_) => (
             StatusCode::OK,
             Json(ApiResponse::<()> {
@@ -5,9 +5,9 @@ use axum::{
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::application::commands::{CreateProjectCommand, UpdateProjectCommand};
+use crate::application::commands::{AddProjectMemberCommand, CreateProjectCommand, UpdateProjectCommand, UpdateProjectMemberCommand};
 use crate::application::services::ProjectAppService;
-use crate::domain::entities::{Milestone, Project, Task};
+use crate::domain::entities::{Project, ProjectMember, Task, Trashed};
 use crate::domain::value_objects::UserRole;
 use crate::presentation::dto::ApiResponse;
 use crate::presentation::middleware::AuthUser;
@@ -32,10 +32,8 @@ pub async fn get_project(
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<Project>>, DomainError> {
     // Check access permission (admin can access all)
-    if auth_user.role != UserRole::Admin {
-        if !service.can_user_access(id, auth_user.id).await? {
-            return Err(DomainError::Forbidden("You don't have access to this project".into()));
-        }
+    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this project".into()));
     }
     let project = service.get_project(id).await?;
     Ok(Json(ApiResponse::success(project)))
@@ -62,10 +60,8 @@ pub async fn update_project(
     Json(cmd): Json<UpdateProjectCommand>,
 ) -> Result<Json<ApiResponse<Project>>, DomainError> {
     // Only owner or admin can update project
-    if auth_user.role != UserRole::Admin {
-        if !service.is_owner(id, auth_user.id).await? {
-            return Err(DomainError::Forbidden("Only project owner can update this project".into()));
-        }
+    if auth_user.role != UserRole::Admin && !service.is_owner(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("Only project owner can update this project".into()));
     }
 
     tracing::info!(
@@ -73,7 +69,7 @@ pub async fn update_project(
         project_id = %id,
         "User updating project"
     );
-    let project = service.update_project(id, cmd).await?;
+    let project = service.update_project(id, cmd, auth_user.id).await?;
     Ok(Json(ApiResponse::success(project)))
 }
 
@@ -83,10 +79,8 @@ pub async fn delete_project(
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<()>>, DomainError> {
     // Only owner or admin can delete project
-    if auth_user.role != UserRole::Admin {
-        if !service.is_owner(id, auth_user.id).await? {
-            return Err(DomainError::Forbidden("Only project owner can delete this project".into()));
-        }
+    if auth_user.role != UserRole::Admin && !service.is_owner(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("Only project owner can delete this project".into()));
     }
 
     tracing::info!(
@@ -94,36 +88,130 @@ pub async fn delete_project(
         project_id = %id,
         "User deleting project"
     );
-    service.delete_project(id).await?;
+    service.delete_project(id, auth_user.id).await?;
     Ok(Json(ApiResponse::ok("Project deleted successfully")))
 }
 
+/// GET /trash/projects - List trashed projects (admin sees all, others their own)
+pub async fn list_trashed_projects(
+    State(service): State<Arc<ProjectAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ApiResponse<Vec<Trashed<Project>>>>, DomainError> {
+    let projects = service
+        .get_trashed_projects(auth_user.id, auth_user.role == UserRole::Admin)
+        .await?;
+    Ok(Json(ApiResponse::success(projects)))
+}
+
+/// POST /projects/:id/restore - Restore a trashed project with its tasks
+pub async fn restore_project(
+    State(service): State<Arc<ProjectAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Project>>, DomainError> {
+    // Only owner or admin can restore project
+    if auth_user.role != UserRole::Admin && !service.is_owner(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("Only project owner can restore this project".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %id,
+        "User restoring project"
+    );
+    let project = service.restore_project(id, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(project)))
+}
+
 pub async fn get_project_tasks(
     State(service): State<Arc<ProjectAppService>>,
     Extension(auth_user): Extension<AuthUser>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<Vec<Task>>>, DomainError> {
     // Check access permission (admin can access all)
-    if auth_user.role != UserRole::Admin {
-        if !service.can_user_access(id, auth_user.id).await? {
-            return Err(DomainError::Forbidden("You don't have access to this project".into()));
-        }
+    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this project".into()));
     }
     let tasks = service.get_project_tasks(id).await?;
     Ok(Json(ApiResponse::success(tasks)))
 }
 
-pub async fn get_project_milestones(
+/// GET /projects/:id/members - List project members with their roles
+pub async fn get_project_members(
     State(service): State<Arc<ProjectAppService>>,
     Extension(auth_user): Extension<AuthUser>,
     Path(id): Path<Uuid>,
-) -> Result<Json<ApiResponse<Vec<Milestone>>>, DomainError> {
+) -> Result<Json<ApiResponse<Vec<ProjectMember>>>, DomainError> {
     // Check access permission (admin can access all)
-    if auth_user.role != UserRole::Admin {
-        if !service.can_user_access(id, auth_user.id).await? {
-            return Err(DomainError::Forbidden("You don't have access to this project".into()));
-        }
+    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this project".into()));
+    }
+    let members = service.get_members(id).await?;
+    Ok(Json(ApiResponse::success(members)))
+}
+
+/// POST /projects/:id/members - Add a member with a role
+pub async fn add_project_member(
+    State(service): State<Arc<ProjectAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<AddProjectMemberCommand>,
+) -> Result<Json<ApiResponse<ProjectMember>>, DomainError> {
+    // Only owner or admin can manage members
+    if auth_user.role != UserRole::Admin && !service.is_owner(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("Only project owner can manage members".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %id,
+        new_member_id = %cmd.user_id,
+        "User adding project member"
+    );
+    let member = service.add_member(id, cmd).await?;
+    Ok(Json(ApiResponse::success(member)))
+}
+
+/// PUT /projects/:id/members/:user_id - Change the role of a member
+pub async fn update_project_member(
+    State(service): State<Arc<ProjectAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((id, user_id)): Path<(Uuid, Uuid)>,
+    Json(cmd): Json<UpdateProjectMemberCommand>,
+) -> Result<Json<ApiResponse<ProjectMember>>, DomainError> {
+    // Only owner or admin can manage members
+    if auth_user.role != UserRole::Admin && !service.is_owner(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("Only project owner can manage members".into()));
     }
-    let milestones = service.get_project_milestones(id).await?;
-    Ok(Json(ApiResponse::success(milestones)))
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %id,
+        member_id = %user_id,
+        role = cmd.role.as_str(),
+        "User changing project member role"
+    );
+    let member = service.update_member(id, user_id, cmd).await?;
+    Ok(Json(ApiResponse::success(member)))
+}
+
+/// DELETE /projects/:id/members/:user_id - Remove a member
+pub async fn remove_project_member(
+    State(service): State<Arc<ProjectAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((id, user_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Only owner or admin can manage members
+    if auth_user.role != UserRole::Admin && !service.is_owner(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("Only project owner can manage members".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %id,
+        member_id = %user_id,
+        "User removing project member"
+    );
+    service.remove_member(id, user_id).await?;
+    Ok(Json(ApiResponse::ok("Project member removed successfully")))
 }
@@ -0,0 +1,41 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::services::ProjectHealthAppService;
+use crate::domain::services::ProjectHealth;
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+/// GET /projects/health - RAG status of every accessible project, worst first
+pub async fn get_portfolio_health(
+    State(service): State<Arc<ProjectHealthAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<ApiResponse<Vec<ProjectHealth>>>, DomainError> {
+    let portfolio = service
+        .get_portfolio_health(auth_user.id, auth_user.role == UserRole::Admin)
+        .await?;
+    Ok(Json(ApiResponse::success(portfolio)))
+}
+
+/// GET /projects/:id/health - RAG status of a project with its contributing factors;
+/// the budget factor is only shown to maintainers
+pub async fn get_project_health(
+    State(service): State<Arc<ProjectHealthAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ProjectHealth>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_project(project_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this project".into()));
+    }
+    let health = service
+        .get_health(project_id, auth_user.id, auth_user.role == UserRole::Admin)
+        .await?;
+    Ok(Json(ApiResponse::success(health)))
+}
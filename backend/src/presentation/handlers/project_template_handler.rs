@@ -0,0 +1,71 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::SaveProjectTemplateCommand;
+use crate::application::services::ProjectTemplateAppService;
+use crate::domain::entities::ProjectTemplate;
+use crate::domain::value_objects::{ProjectRole, UserRole};
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+/// GET /project-templates - List project templates
+pub async fn list_project_templates(
+    State(service): State<Arc<ProjectTemplateAppService>>,
+) -> Result<Json<ApiResponse<Vec<ProjectTemplate>>>, DomainError> {
+    let templates = service.list_templates().await?;
+    Ok(Json(ApiResponse::success(templates)))
+}
+
+/// GET /project-templates/:id - Get a project template with its milestones, tasks and members
+pub async fn get_project_template(
+    State(service): State<Arc<ProjectTemplateAppService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ProjectTemplate>>, DomainError> {
+    let template = service.get_template(id).await?;
+    Ok(Json(ApiResponse::success(template)))
+}
+
+/// POST /project-templates - Save a project as a template
+pub async fn save_project_template(
+    State(service): State<Arc<ProjectTemplateAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(cmd): Json<SaveProjectTemplateCommand>,
+) -> Result<Json<ApiResponse<ProjectTemplate>>, DomainError> {
+    // Member roles are copied, so only project owner, maintainers or admin can save the project
+    if auth_user.role != UserRole::Admin && !service.has_project_role(cmd.project_id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can save the project as a template".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %cmd.project_id,
+        template_name = %cmd.name,
+        "User saving project template"
+    );
+    let template = service.save_template(cmd, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(template)))
+}
+
+/// DELETE /project-templates/:id - Delete a project template (creator or admin)
+pub async fn delete_project_template(
+    State(service): State<Arc<ProjectTemplateAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    if auth_user.role != UserRole::Admin && !service.is_creator(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("Only the creator can delete this template".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        template_id = %id,
+        "User deleting project template"
+    );
+    service.delete_template(id).await?;
+    Ok(Json(ApiResponse::ok("Project template deleted successfully")))
+}
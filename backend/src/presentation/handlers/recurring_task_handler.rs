@@ -0,0 +1,174 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{
+    CreateRecurringTaskCommand, UpdateRecurringOccurrenceCommand, UpdateRecurringTaskCommand,
+};
+use crate::application::services::{RecurringTaskAppService, ScheduledOccurrence};
+use crate::domain::entities::{RecurringTask, RecurringTaskOccurrence};
+use crate::domain::value_objects::{ProjectRole, UserRole};
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+#[derive(Debug, Deserialize)]
+pub struct ListOccurrencesQuery {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+/// GET /projects/:id/recurring-tasks - List recurring tasks of a project
+pub async fn list_recurring_tasks(
+    State(service): State<Arc<RecurringTaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<RecurringTask>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_project(project_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this project".into()));
+    }
+    let recurring_tasks = service.list_recurring_tasks(project_id).await?;
+    Ok(Json(ApiResponse::success(recurring_tasks)))
+}
+
+/// POST /projects/:id/recurring-tasks - Create a recurring task
+pub async fn create_recurring_task(
+    State(service): State<Arc<RecurringTaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+    Json(cmd): Json<CreateRecurringTaskCommand>,
+) -> Result<Json<ApiResponse<RecurringTask>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_project_role(project_id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this project".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %project_id,
+        rrule = %cmd.rrule,
+        "User creating recurring task"
+    );
+    let recurring_task = service.create_recurring_task(project_id, auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(recurring_task)))
+}
+
+/// GET /recurring-tasks/:id - Get a recurring task
+pub async fn get_recurring_task(
+    State(service): State<Arc<RecurringTaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<RecurringTask>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this recurring task".into()));
+    }
+    let recurring_task = service.get_recurring_task(id).await?;
+    Ok(Json(ApiResponse::success(recurring_task)))
+}
+
+/// PUT /recurring-tasks/:id - Update the whole series
+pub async fn update_recurring_task(
+    State(service): State<Arc<RecurringTaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<UpdateRecurringTaskCommand>,
+) -> Result<Json<ApiResponse<RecurringTask>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this recurring task".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        recurring_task_id = %id,
+        "User updating recurring task"
+    );
+    let recurring_task = service.update_recurring_task(id, cmd).await?;
+    Ok(Json(ApiResponse::success(recurring_task)))
+}
+
+/// DELETE /recurring-tasks/:id - Delete the series, keeping generated tasks
+pub async fn delete_recurring_task(
+    State(service): State<Arc<RecurringTaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Only project owner, maintainers or admin can delete recurring tasks
+    if auth_user.role != UserRole::Admin && !service.has_role(id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can delete recurring tasks".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        recurring_task_id = %id,
+        "User deleting recurring task"
+    );
+    service.delete_recurring_task(id).await?;
+    Ok(Json(ApiResponse::ok("Recurring task deleted successfully")))
+}
+
+/// GET /recurring-tasks/:id/occurrences - List occurrences in a date range
+pub async fn list_occurrences(
+    State(service): State<Arc<RecurringTaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Query(params): Query<ListOccurrencesQuery>,
+) -> Result<Json<ApiResponse<Vec<ScheduledOccurrence>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this recurring task".into()));
+    }
+    let occurrences = service.list_occurrences(id, params.from, params.to).await?;
+    Ok(Json(ApiResponse::success(occurrences)))
+}
+
+/// PUT /recurring-tasks/:id/occurrences/:date - Skip or edit a single occurrence
+pub async fn update_occurrence(
+    State(service): State<Arc<RecurringTaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((id, date)): Path<(Uuid, NaiveDate)>,
+    Json(cmd): Json<UpdateRecurringOccurrenceCommand>,
+) -> Result<Json<ApiResponse<RecurringTaskOccurrence>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this recurring task".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        recurring_task_id = %id,
+        occurrence_date = %date,
+        skipped = cmd.skipped,
+        "User updating recurring task occurrence"
+    );
+    let occurrence = service.update_occurrence(id, date, cmd).await?;
+    Ok(Json(ApiResponse::success(occurrence)))
+}
+
+/// DELETE /recurring-tasks/:id/occurrences/:date - Restore an occurrence to follow the series
+pub async fn reset_occurrence(
+    State(service): State<Arc<RecurringTaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((id, date)): Path<(Uuid, NaiveDate)>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this recurring task".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        recurring_task_id = %id,
+        occurrence_date = %date,
+        "User resetting recurring task occurrence"
+    );
+    service.reset_occurrence(id, date).await?;
+    Ok(Json(ApiResponse::ok("Occurrence reset successfully")))
+}
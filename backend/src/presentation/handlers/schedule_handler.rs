@@ -0,0 +1,27 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::services::ScheduleAppService;
+use crate::domain::services::ProjectSchedule;
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+/// GET /projects/:id/schedule - Earliest and latest dates, float and critical path of the open tasks
+pub async fn get_project_schedule(
+    State(service): State<Arc<ScheduleAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ProjectSchedule>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_project(project_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this project".into()));
+    }
+    let schedule = service.get_schedule(project_id).await?;
+    Ok(Json(ApiResponse::success(schedule)))
+}
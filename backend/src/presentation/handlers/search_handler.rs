@@ -0,0 +1,25 @@
+use axum::{
+    extract::{Query, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+
+use crate::application::queries::SearchQuery;
+use crate::application::services::SearchAppService;
+use crate::domain::entities::SearchHit;
+use crate::domain::value_objects::UserRole;
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+/// GET /search?q= - Full-text search across accessible projects, tasks and comments
+pub async fn search(
+    State(service): State<Arc<SearchAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Query(query): Query<SearchQuery>,
+) -> Result<Json<ApiResponse<Vec<SearchHit>>>, DomainError> {
+    let hits = service
+        .search(query, auth_user.id, auth_user.role == UserRole::Admin)
+        .await?;
+    Ok(Json(ApiResponse::success(hits)))
+}
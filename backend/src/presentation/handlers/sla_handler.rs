@@ -0,0 +1,121 @@
+use axum::{
+    extract::{Path, Query, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateSlaPolicyCommand, UpdateSlaPolicyCommand};
+use crate::application::queries::SlaReportQuery;
+use crate::application::services::{SlaAppService, TaskSla};
+use crate::domain::entities::{SlaBreachCount, SlaPolicy};
+use crate::domain::value_objects::{ProjectRole, UserRole};
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+/// GET /projects/:id/sla-policies - List SLA policies of a project
+pub async fn list_sla_policies(
+    State(service): State<Arc<SlaAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<SlaPolicy>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_project(project_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this project".into()));
+    }
+    let policies = service.list_policies(project_id).await?;
+    Ok(Json(ApiResponse::success(policies)))
+}
+
+/// POST /projects/:id/sla-policies - Define the SLA policy of a priority
+pub async fn create_sla_policy(
+    State(service): State<Arc<SlaAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+    Json(cmd): Json<CreateSlaPolicyCommand>,
+) -> Result<Json<ApiResponse<SlaPolicy>>, DomainError> {
+    // Only project owner, maintainers or admin can manage SLA policies
+    if auth_user.role != UserRole::Admin && !service.has_project_role(project_id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can manage SLA policies".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %project_id,
+        priority = cmd.priority.as_str(),
+        "User creating SLA policy"
+    );
+    let policy = service.create_policy(project_id, cmd).await?;
+    Ok(Json(ApiResponse::success(policy)))
+}
+
+/// PUT /sla-policies/:id - Update an SLA policy
+pub async fn update_sla_policy(
+    State(service): State<Arc<SlaAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<UpdateSlaPolicyCommand>,
+) -> Result<Json<ApiResponse<SlaPolicy>>, DomainError> {
+    // Only project owner, maintainers or admin can manage SLA policies
+    if auth_user.role != UserRole::Admin && !service.has_policy_role(id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can manage SLA policies".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        policy_id = %id,
+        "User updating SLA policy"
+    );
+    let policy = service.update_policy(id, cmd).await?;
+    Ok(Json(ApiResponse::success(policy)))
+}
+
+/// DELETE /sla-policies/:id - Delete an SLA policy
+pub async fn delete_sla_policy(
+    State(service): State<Arc<SlaAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Only project owner, maintainers or admin can manage SLA policies
+    if auth_user.role != UserRole::Admin && !service.has_policy_role(id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can manage SLA policies".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        policy_id = %id,
+        "User deleting SLA policy"
+    );
+    service.delete_policy(id).await?;
+    Ok(Json(ApiResponse::ok("SLA policy deleted successfully")))
+}
+
+/// GET /projects/:id/sla-breaches - Count SLA breaches per priority and target
+pub async fn get_sla_breach_report(
+    State(service): State<Arc<SlaAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+    Query(query): Query<SlaReportQuery>,
+) -> Result<Json<ApiResponse<Vec<SlaBreachCount>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_project(project_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this project".into()));
+    }
+    let report = service.breach_report(project_id, query).await?;
+    Ok(Json(ApiResponse::success(report)))
+}
+
+/// GET /tasks/:id/sla - Get SLA timers and breaches of a task
+pub async fn get_task_sla(
+    State(service): State<Arc<SlaAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<TaskSla>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_task(task_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this task".into()));
+    }
+    let sla = service.get_task_sla(task_id).await?;
+    Ok(Json(ApiResponse::success(sla)))
+}
@@ -0,0 +1,218 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CloseSprintCommand, CreateSprintCommand, PlanSprintCommand, UpdateSprintCommand};
+use crate::application::services::{ClosedSprint, SprintAppService};
+use crate::domain::entities::{Sprint, Task};
+use crate::domain::services::SprintBurndown;
+use crate::domain::value_objects::{ProjectRole, UserRole};
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+/// GET /projects/:id/sprints - List sprints of a project
+pub async fn list_sprints(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<Sprint>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_project(project_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this project".into()));
+    }
+    let sprints = service.list_sprints(project_id).await?;
+    Ok(Json(ApiResponse::success(sprints)))
+}
+
+/// POST /projects/:id/sprints - Plan a new sprint
+pub async fn create_sprint(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+    Json(cmd): Json<CreateSprintCommand>,
+) -> Result<Json<ApiResponse<Sprint>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_project_role(project_id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this project".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %project_id,
+        name = %cmd.name,
+        "User creating sprint"
+    );
+    let sprint = service.create_sprint(project_id, cmd).await?;
+    Ok(Json(ApiResponse::success(sprint)))
+}
+
+/// GET /sprints/:id - Get a sprint
+pub async fn get_sprint(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Sprint>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_sprint(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this sprint".into()));
+    }
+    let sprint = service.get_sprint(id).await?;
+    Ok(Json(ApiResponse::success(sprint)))
+}
+
+/// PUT /sprints/:id - Update a sprint that is not closed
+pub async fn update_sprint(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<UpdateSprintCommand>,
+) -> Result<Json<ApiResponse<Sprint>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_sprint_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this sprint".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        sprint_id = %id,
+        "User updating sprint"
+    );
+    let sprint = service.update_sprint(id, cmd).await?;
+    Ok(Json(ApiResponse::success(sprint)))
+}
+
+/// DELETE /sprints/:id - Delete a planned sprint
+pub async fn delete_sprint(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_sprint_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this sprint".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        sprint_id = %id,
+        "User deleting sprint"
+    );
+    service.delete_sprint(id).await?;
+    Ok(Json(ApiResponse::ok("Sprint deleted successfully")))
+}
+
+/// GET /sprints/:id/tasks - List tasks planned into a sprint
+pub async fn get_sprint_tasks(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<Task>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_sprint(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this sprint".into()));
+    }
+    let tasks = service.get_sprint_tasks(id).await?;
+    Ok(Json(ApiResponse::success(tasks)))
+}
+
+/// POST /sprints/:id/tasks - Plan tasks into a sprint
+pub async fn plan_sprint_tasks(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<PlanSprintCommand>,
+) -> Result<Json<ApiResponse<Vec<Task>>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_sprint_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this sprint".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        sprint_id = %id,
+        count = cmd.task_ids.len(),
+        "User planning tasks into sprint"
+    );
+    let tasks = service.plan_tasks(id, cmd, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(tasks)))
+}
+
+/// DELETE /sprints/:id/tasks/:task_id - Move a task out of a sprint, back into the backlog
+pub async fn unplan_sprint_task(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_sprint_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this sprint".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        sprint_id = %id,
+        task_id = %task_id,
+        "User removing task from sprint"
+    );
+    service.unplan_task(id, task_id, auth_user.id).await?;
+    Ok(Json(ApiResponse::ok("Task removed from sprint")))
+}
+
+/// POST /sprints/:id/start - Start a planned sprint
+pub async fn start_sprint(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Sprint>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_sprint_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this sprint".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        sprint_id = %id,
+        "User starting sprint"
+    );
+    let sprint = service.start_sprint(id).await?;
+    Ok(Json(ApiResponse::success(sprint)))
+}
+
+/// POST /sprints/:id/close - Close the active sprint, carrying over unfinished tasks
+pub async fn close_sprint(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<CloseSprintCommand>,
+) -> Result<Json<ApiResponse<ClosedSprint>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_sprint_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this sprint".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        sprint_id = %id,
+        "User closing sprint"
+    );
+    let closed = service.close_sprint(id, cmd, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(closed)))
+}
+
+/// GET /sprints/:id/burndown - Daily burndown and burnup series of a sprint
+pub async fn get_sprint_burndown(
+    State(service): State<Arc<SprintAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<SprintBurndown>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_sprint(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this sprint".into()));
+    }
+    let burndown = service.get_burndown(id).await?;
+    Ok(Json(ApiResponse::success(burndown)))
+}
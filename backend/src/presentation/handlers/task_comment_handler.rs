@@ -0,0 +1,104 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateTaskCommentCommand, UpdateTaskCommentCommand};
+use crate::application::services::{TaskCommentAppService, WithMentions};
+use crate::domain::entities::{TaskComment, TaskCommentEdit};
+use crate::domain::services::CommentThread;
+use crate::domain::value_objects::{ProjectRole, UserRole};
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+/// GET /tasks/:task_id/comments - List threaded comments of a task
+pub async fn list_comments(
+    State(service): State<Arc<TaskCommentAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<CommentThread>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(task_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this task".into()));
+    }
+    let comments = service.list_comments(task_id).await?;
+    Ok(Json(ApiResponse::success(comments)))
+}
+
+/// POST /tasks/:task_id/comments - Add a comment or reply
+pub async fn create_comment(
+    State(service): State<Arc<TaskCommentAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+    Json(cmd): Json<CreateTaskCommentCommand>,
+) -> Result<Json<ApiResponse<WithMentions<TaskComment>>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_role(task_id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to comment on this task".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %task_id,
+        "User adding task comment"
+    );
+    let comment = service.create_comment(task_id, auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(comment)))
+}
+
+/// PUT /tasks/:task_id/comments/:comment_id - Edit own comment
+pub async fn update_comment(
+    State(service): State<Arc<TaskCommentAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((task_id, comment_id)): Path<(Uuid, Uuid)>,
+    Json(cmd): Json<UpdateTaskCommentCommand>,
+) -> Result<Json<ApiResponse<WithMentions<TaskComment>>>, DomainError> {
+    tracing::info!(
+        user_id = %auth_user.id,
+        comment_id = %comment_id,
+        "User editing task comment"
+    );
+    let comment = service.update_comment(task_id, comment_id, auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(comment)))
+}
+
+/// DELETE /tasks/:task_id/comments/:comment_id - Delete a comment and its replies
+pub async fn delete_comment(
+    State(service): State<Arc<TaskCommentAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((task_id, comment_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Author, project owner, maintainers or admin can delete comments
+    let comment = service.get_comment(task_id, comment_id).await?;
+    if auth_user.role != UserRole::Admin
+        && comment.user_id != auth_user.id
+        && !service.has_role(task_id, auth_user.id, ProjectRole::Maintainer).await?
+    {
+        return Err(DomainError::Forbidden("You can't delete this comment".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        comment_id = %comment_id,
+        "User deleting task comment"
+    );
+    service.delete_comment(task_id, comment_id).await?;
+    Ok(Json(ApiResponse::ok("Comment deleted successfully")))
+}
+
+/// GET /tasks/:task_id/comments/:comment_id/history - List previous versions of a comment
+pub async fn get_comment_history(
+    State(service): State<Arc<TaskCommentAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((task_id, comment_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<Vec<TaskCommentEdit>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(task_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this task".into()));
+    }
+    let history = service.get_comment_history(task_id, comment_id).await?;
+    Ok(Json(ApiResponse::success(history)))
+}
@@ -1,28 +1,35 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     Extension, Json,
 };
+use std::collections::HashMap;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::application::commands::{CreateTaskCommand, UpdateTaskCommand};
-use crate::application::services::TaskAppService;
-use crate::domain::entities::Task;
-use crate::domain::value_objects::UserRole;
+use crate::application::commands::{
+    AddTaskDependencyCommand, BulkTaskCommand, CloneTaskCommand, CreateTaskCommand, MoveTaskCommand, UpdateTaskCommand,
+};
+use crate::application::queries::{Paginated, TaskQuery};
+use crate::application::services::{BulkTaskResponse, TaskAppService, TaskDependencies, WithMentions};
+use crate::domain::services::TaskTreeNode;
+use crate::domain::entities::{Task, TaskDependency, TaskWatcher, Trashed};
+use crate::domain::value_objects::{ProjectRole, UserRole};
 use crate::presentation::dto::ApiResponse;
 use crate::presentation::middleware::AuthUser;
 use crate::shared::DomainError;
 
+/// GET /tasks - Filter, sort and paginate tasks
 pub async fn list_tasks(
     State(service): State<Arc<TaskAppService>>,
     Extension(auth_user): Extension<AuthUser>,
-) -> Result<Json<ApiResponse<Vec<Task>>>, DomainError> {
+    Query(query): Query<TaskQuery>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<ApiResponse<Paginated<Task>>>, DomainError> {
+    let query = query.with_custom_field_params(params)?;
     // Admin can see all tasks, others only see tasks from accessible projects
-    let tasks = if auth_user.role == UserRole::Admin {
-        service.list_tasks().await?
-    } else {
-        service.list_accessible_tasks(auth_user.id).await?
-    };
+    let tasks = service
+        .list_tasks(query, auth_user.id, auth_user.role == UserRole::Admin)
+        .await?;
     Ok(Json(ApiResponse::success(tasks)))
 }
 
@@ -30,14 +37,12 @@ pub async fn get_task(
     State(service): State<Arc<TaskAppService>>,
     Extension(auth_user): Extension<AuthUser>,
     Path(id): Path<Uuid>,
-) -> Result<Json<ApiResponse<Task>>, DomainError> {
+) -> Result<Json<ApiResponse<WithMentions<Task>>>, DomainError> {
     // Check access permission (admin can access all)
-    if auth_user.role != UserRole::Admin {
-        if !service.can_user_access(id, auth_user.id).await? {
-            return Err(DomainError::Forbidden("You don't have access to this task".into()));
-        }
+    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this task".into()));
     }
-    let task = service.get_task(id).await?;
+    let task = service.get_task_with_mentions(id).await?;
     Ok(Json(ApiResponse::success(task)))
 }
 
@@ -45,12 +50,10 @@ pub async fn create_task(
     State(service): State<Arc<TaskAppService>>,
     Extension(auth_user): Extension<AuthUser>,
     Json(cmd): Json<CreateTaskCommand>,
-) -> Result<Json<ApiResponse<Task>>, DomainError> {
+) -> Result<Json<ApiResponse<WithMentions<Task>>>, DomainError> {
     // Check access to project (admin can access all)
-    if auth_user.role != UserRole::Admin {
-        if !service.can_access_project(cmd.project_id, auth_user.id).await? {
-            return Err(DomainError::Forbidden("You don't have access to this project".into()));
-        }
+    if auth_user.role != UserRole::Admin && !service.has_project_role(cmd.project_id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to add tasks to this project".into()));
     }
 
     tracing::info!(
@@ -58,29 +61,105 @@ pub async fn create_task(
         project_id = %cmd.project_id,
         "User creating new task"
     );
-    let task = service.create_task(cmd).await?;
+    let task = service.create_task(cmd, auth_user.id).await?;
     Ok(Json(ApiResponse::success(task)))
 }
 
+/// POST /tasks/bulk - Apply one set of changes to many tasks in a single transaction
+pub async fn bulk_update_tasks(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(cmd): Json<BulkTaskCommand>,
+) -> Result<Json<ApiResponse<BulkTaskResponse>>, DomainError> {
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_count = cmd.task_ids.len(),
+        delete = cmd.delete,
+        "User applying bulk task changes"
+    );
+    // Access is checked for each task by the service
+    let response = service
+        .bulk_update(cmd, auth_user.id, auth_user.role == UserRole::Admin)
+        .await?;
+    Ok(Json(ApiResponse::success(response)))
+}
+
 pub async fn update_task(
     State(service): State<Arc<TaskAppService>>,
     Extension(auth_user): Extension<AuthUser>,
     Path(id): Path<Uuid>,
     Json(cmd): Json<UpdateTaskCommand>,
+) -> Result<Json<ApiResponse<WithMentions<Task>>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this task".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %id,
+        "User updating task"
+    );
+    let task = service.update_task(id, cmd, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(task)))
+}
+
+/// POST /tasks/:id/move - Reorder a task on the board, optionally into another column or project
+pub async fn move_task(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<MoveTaskCommand>,
+) -> Result<Json<ApiResponse<Task>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this task".into()));
+    }
+    if let Some(project_id) = cmd.project_id {
+        if auth_user.role != UserRole::Admin && !service.has_project_role(project_id, auth_user.id, ProjectRole::Contributor).await? {
+            return Err(DomainError::Forbidden("You don't have permission to add tasks to the target project".into()));
+        }
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %id,
+        "User moving task on board"
+    );
+    let task = service.move_task(id, cmd, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(task)))
+}
+
+/// POST /tasks/:id/clone - Copy a task, optionally into another project
+pub async fn clone_task(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<CloneTaskCommand>,
 ) -> Result<Json<ApiResponse<Task>>, DomainError> {
     // Check access permission (admin can access all)
-    if auth_user.role != UserRole::Admin {
-        if !service.can_user_access(id, auth_user.id).await? {
-            return Err(DomainError::Forbidden("You don't have access to this task".into()));
+    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this task".into()));
+    }
+    // A copy in the same project adds a task there, so it needs edit permission on the source
+    if cmd.project_id.is_none()
+        && auth_user.role != UserRole::Admin
+        && !service.has_role(id, auth_user.id, ProjectRole::Contributor).await?
+    {
+        return Err(DomainError::Forbidden("You don't have permission to add tasks to this project".into()));
+    }
+    if let Some(project_id) = cmd.project_id {
+        if auth_user.role != UserRole::Admin && !service.has_project_role(project_id, auth_user.id, ProjectRole::Contributor).await? {
+            return Err(DomainError::Forbidden("You don't have permission to add tasks to the target project".into()));
         }
     }
 
     tracing::info!(
         user_id = %auth_user.id,
         task_id = %id,
-        "User updating task"
+        "User cloning task"
     );
-    let task = service.update_task(id, cmd).await?;
+    let task = service.clone_task(id, cmd, auth_user.id).await?;
     Ok(Json(ApiResponse::success(task)))
 }
 
@@ -89,11 +168,9 @@ pub async fn delete_task(
     Extension(auth_user): Extension<AuthUser>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<()>>, DomainError> {
-    // Only project owner or admin can delete tasks
-    if auth_user.role != UserRole::Admin {
-        if !service.is_project_owner(id, auth_user.id).await? {
-            return Err(DomainError::Forbidden("Only project owner can delete tasks".into()));
-        }
+    // Only project owner, maintainers or admin can delete tasks
+    if auth_user.role != UserRole::Admin && !service.has_role(id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can delete tasks".into()));
     }
 
     tracing::info!(
@@ -101,6 +178,174 @@ pub async fn delete_task(
         task_id = %id,
         "User deleting task"
     );
-    service.delete_task(id).await?;
+    service.delete_task(id, auth_user.id).await?;
     Ok(Json(ApiResponse::ok("Task deleted successfully")))
 }
+
+/// GET /projects/:id/trash - List the trashed tasks of a project
+pub async fn get_project_trash(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<Trashed<Task>>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_project(project_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this project".into()));
+    }
+    let tasks = service.get_trash(project_id).await?;
+    Ok(Json(ApiResponse::success(tasks)))
+}
+
+/// POST /tasks/:id/restore - Restore a trashed task with its subtasks
+pub async fn restore_task(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Task>>, DomainError> {
+    // Only project owner, maintainers or admin can restore tasks
+    if auth_user.role != UserRole::Admin && !service.has_trashed_role(id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can restore tasks".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %id,
+        "User restoring task"
+    );
+    let task = service.restore_task(id, auth_user.id).await?;
+    Ok(Json(ApiResponse::success(task)))
+}
+
+/// GET /tasks/:id/watchers - List users watching a task
+pub async fn get_task_watchers(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<TaskWatcher>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this task".into()));
+    }
+    let watchers = service.get_watchers(id).await?;
+    Ok(Json(ApiResponse::success(watchers)))
+}
+
+/// POST /tasks/:id/watch - Start watching a task
+pub async fn watch_task(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this task".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %id,
+        "User watching task"
+    );
+    service.watch_task(id, auth_user.id).await?;
+    Ok(Json(ApiResponse::ok("Task watched successfully")))
+}
+
+/// DELETE /tasks/:id/watch - Stop watching a task
+pub async fn unwatch_task(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %id,
+        "User unwatching task"
+    );
+    service.unwatch_task(id, auth_user.id).await?;
+    Ok(Json(ApiResponse::ok("Task unwatched successfully")))
+}
+
+pub async fn get_subtasks(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<Task>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this task".into()));
+    }
+    let subtasks = service.get_subtasks(id).await?;
+    Ok(Json(ApiResponse::success(subtasks)))
+}
+
+pub async fn get_task_tree(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<TaskTreeNode>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this task".into()));
+    }
+    let tree = service.get_task_tree(id).await?;
+    Ok(Json(ApiResponse::success(tree)))
+}
+
+pub async fn get_task_dependencies(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<TaskDependencies>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this task".into()));
+    }
+    let dependencies = service.get_dependencies(id).await?;
+    Ok(Json(ApiResponse::success(dependencies)))
+}
+
+pub async fn add_task_dependency(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<AddTaskDependencyCommand>,
+) -> Result<Json<ApiResponse<TaskDependency>>, DomainError> {
+    // Dependencies may cross projects: the blocked task must be editable, the blocking one accessible
+    if auth_user.role != UserRole::Admin
+        && (!service.has_role(id, auth_user.id, ProjectRole::Contributor).await?
+            || !service.can_user_access(cmd.blocking_task_id, auth_user.id).await?)
+    {
+        return Err(DomainError::Forbidden("You don't have access to this task".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %id,
+        blocking_task_id = %cmd.blocking_task_id,
+        "User adding task dependency"
+    );
+    let dependency = service
+        .add_dependency(id, cmd.blocking_task_id, auth_user.id)
+        .await?;
+    Ok(Json(ApiResponse::success(dependency)))
+}
+
+pub async fn remove_task_dependency(
+    State(service): State<Arc<TaskAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((id, blocking_task_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this task".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        task_id = %id,
+        blocking_task_id = %blocking_task_id,
+        "User removing task dependency"
+    );
+    service.remove_dependency(id, blocking_task_id).await?;
+    Ok(Json(ApiResponse::ok("Dependency removed successfully")))
+}
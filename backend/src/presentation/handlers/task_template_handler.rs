@@ -0,0 +1,105 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::{CreateTaskTemplateCommand, UpdateTaskTemplateCommand};
+use crate::application::services::TaskTemplateAppService;
+use crate::domain::entities::TaskTemplate;
+use crate::domain::value_objects::{ProjectRole, UserRole};
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+/// GET /projects/:id/task-templates - List task templates of a project
+pub async fn list_task_templates(
+    State(service): State<Arc<TaskTemplateAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<TaskTemplate>>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_access_project(project_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this project".into()));
+    }
+    let templates = service.list_templates(project_id).await?;
+    Ok(Json(ApiResponse::success(templates)))
+}
+
+/// POST /projects/:id/task-templates - Create a task template
+pub async fn create_task_template(
+    State(service): State<Arc<TaskTemplateAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+    Json(cmd): Json<CreateTaskTemplateCommand>,
+) -> Result<Json<ApiResponse<TaskTemplate>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_project_role(project_id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this project".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %project_id,
+        name = %cmd.name,
+        "User creating task template"
+    );
+    let template = service.create_template(project_id, auth_user.id, cmd).await?;
+    Ok(Json(ApiResponse::success(template)))
+}
+
+/// GET /task-templates/:id - Get a task template
+pub async fn get_task_template(
+    State(service): State<Arc<TaskTemplateAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<TaskTemplate>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this task template".into()));
+    }
+    let template = service.get_template(id).await?;
+    Ok(Json(ApiResponse::success(template)))
+}
+
+/// PUT /task-templates/:id - Update a task template
+pub async fn update_task_template(
+    State(service): State<Arc<TaskTemplateAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+    Json(cmd): Json<UpdateTaskTemplateCommand>,
+) -> Result<Json<ApiResponse<TaskTemplate>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this task template".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        template_id = %id,
+        "User updating task template"
+    );
+    let template = service.update_template(id, cmd).await?;
+    Ok(Json(ApiResponse::success(template)))
+}
+
+/// DELETE /task-templates/:id - Delete a task template
+pub async fn delete_task_template(
+    State(service): State<Arc<TaskTemplateAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Only project owner, maintainers or admin can delete task templates
+    if auth_user.role != UserRole::Admin && !service.has_role(id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can delete task templates".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        template_id = %id,
+        "User deleting task template"
+    );
+    service.delete_template(id).await?;
+    Ok(Json(ApiResponse::ok("Task template deleted successfully")))
+}
@@ -10,6 +10,7 @@ use uuid::Uuid;
 
 use crate::application::services::{TimeLogAppService, CreateTimeLogDto, UpdateTimeLogDto};
 use crate::domain::entities::TimeLog;
+use crate::domain::value_objects::{ProjectRole, UserRole};
 use crate::presentation::dto::ApiResponse;
 use crate::presentation::middleware::AuthUser;
 use crate::shared::DomainError;
@@ -81,6 +82,11 @@ pub async fn create_time_log(
     Extension(auth_user): Extension<AuthUser>,
     Json(payload): Json<CreateTimeLogRequest>,
 ) -> Result<Json<ApiResponse<TimeLog>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_role(payload.task_id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to log time on this task".into()));
+    }
+
     let dto = CreateTimeLogDto {
         task_id: payload.task_id,
         user_id: auth_user.id,
@@ -96,9 +102,15 @@ pub async fn create_time_log(
 /// PUT /time-logs/:id - Update a time log
 pub async fn update_time_log(
     State(service): State<Arc<TimeLogAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
     Path(id): Path<Uuid>,
     Json(payload): Json<UpdateTimeLogRequest>,
 ) -> Result<Json<ApiResponse<TimeLog>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_time_log_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to edit this time log".into()));
+    }
+
     let dto = UpdateTimeLogDto {
         hours: payload.hours,
         date: payload.date,
@@ -112,8 +124,14 @@ pub async fn update_time_log(
 /// DELETE /time-logs/:id - Delete a time log
 pub async fn delete_time_log(
     State(service): State<Arc<TimeLogAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<()>>, DomainError> {
+    // Check edit permission (admin can edit all)
+    if auth_user.role != UserRole::Admin && !service.has_time_log_role(id, auth_user.id, ProjectRole::Contributor).await? {
+        return Err(DomainError::Forbidden("You don't have permission to delete this time log".into()));
+    }
+
     service.delete_time_log(id).await?;
     Ok(Json(ApiResponse::success(())))
 }
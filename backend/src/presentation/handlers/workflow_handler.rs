@@ -0,0 +1,67 @@
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::commands::SetWorkflowCommand;
+use crate::application::services::WorkflowAppService;
+use crate::domain::entities::ProjectWorkflow;
+use crate::domain::value_objects::{ProjectRole, UserRole};
+use crate::presentation::dto::ApiResponse;
+use crate::presentation::middleware::AuthUser;
+use crate::shared::DomainError;
+
+pub async fn get_workflow(
+    State(service): State<Arc<WorkflowAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ProjectWorkflow>>, DomainError> {
+    // Check access permission (admin can access all)
+    if auth_user.role != UserRole::Admin && !service.can_user_access(project_id, auth_user.id).await? {
+        return Err(DomainError::Forbidden("You don't have access to this project".into()));
+    }
+    let workflow = service.get_workflow(project_id).await?;
+    Ok(Json(ApiResponse::success(workflow)))
+}
+
+pub async fn set_workflow(
+    State(service): State<Arc<WorkflowAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+    Json(cmd): Json<SetWorkflowCommand>,
+) -> Result<Json<ApiResponse<ProjectWorkflow>>, DomainError> {
+    // Only project owner, maintainers or admin can change the workflow
+    if auth_user.role != UserRole::Admin && !service.has_role(project_id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can change the workflow".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %project_id,
+        status_count = cmd.statuses.len(),
+        "User setting project workflow"
+    );
+    let workflow = service.set_workflow(project_id, cmd).await?;
+    Ok(Json(ApiResponse::success(workflow)))
+}
+
+pub async fn reset_workflow(
+    State(service): State<Arc<WorkflowAppService>>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ProjectWorkflow>>, DomainError> {
+    // Only project owner, maintainers or admin can change the workflow
+    if auth_user.role != UserRole::Admin && !service.has_role(project_id, auth_user.id, ProjectRole::Maintainer).await? {
+        return Err(DomainError::Forbidden("Only project owner or maintainers can change the workflow".into()));
+    }
+
+    tracing::info!(
+        user_id = %auth_user.id,
+        project_id = %project_id,
+        "User resetting project workflow"
+    );
+    let workflow = service.reset_workflow(project_id).await?;
+    Ok(Json(ApiResponse::success(workflow)))
+}
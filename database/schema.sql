@@ -11,6 +11,8 @@ CREATE TYPE project_status AS ENUM ('planning', 'active', 'onhold', 'completed',
 CREATE TYPE priority AS ENUM ('low', 'medium', 'high', 'critical');
 CREATE TYPE task_status AS ENUM ('todo', 'inprogress', 'review', 'done', 'blocked');
 CREATE TYPE team_member_role AS ENUM ('lead', 'member');
+-- Ordered from fewest to most rights
+CREATE TYPE project_role AS ENUM ('viewer', 'contributor', 'maintainer');
 
 -- ==================== USERS TABLE ====================
 CREATE TABLE users (
@@ -60,19 +62,28 @@ CREATE TABLE projects (
     end_date TIMESTAMPTZ,
     budget DECIMAL(15, 2),
     owner_id UUID NOT NULL REFERENCES users(id) ON DELETE RESTRICT,
+    -- Set while the project is in the trash; its tasks share the timestamp
+    deleted_at TIMESTAMPTZ,
+    -- Full-text search: name ranks above description
+    search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('english', coalesce(name, '')), 'A') ||
+        setweight(to_tsvector('english', coalesce(description, '')), 'B')
+    ) STORED,
     created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
     updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
 );
 
 CREATE INDEX idx_projects_status ON projects(status);
 CREATE INDEX idx_projects_owner ON projects(owner_id);
+CREATE INDEX idx_projects_search ON projects USING GIN(search_vector);
+CREATE INDEX idx_projects_deleted ON projects(deleted_at) WHERE deleted_at IS NOT NULL;
 
 -- ==================== PROJECT MEMBERS TABLE ====================
 CREATE TABLE project_members (
     id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
     project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
     user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
-    role VARCHAR(100),
+    role project_role NOT NULL DEFAULT 'contributor',
     joined_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
     UNIQUE(project_id, user_id)
 );
@@ -94,39 +105,114 @@ CREATE TABLE milestones (
 
 CREATE INDEX idx_milestones_project ON milestones(project_id);
 
+-- ==================== SPRINTS TABLE ====================
+CREATE TYPE sprint_status AS ENUM ('planned', 'active', 'closed');
+
+CREATE TABLE sprints (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    goal TEXT,
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL,
+    status sprint_status NOT NULL DEFAULT 'planned',
+    -- Story points planned at start and finished at close
+    committed_points INTEGER,
+    completed_points INTEGER,
+    started_at TIMESTAMPTZ,
+    closed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (end_date >= start_date)
+);
+
+CREATE INDEX idx_sprints_project ON sprints(project_id);
+-- At most one running sprint per project
+CREATE UNIQUE INDEX idx_sprints_active ON sprints(project_id) WHERE status = 'active';
+
+-- Daily story point totals for burndown/burnup; one row per sprint and day
+CREATE TABLE sprint_snapshots (
+    sprint_id UUID NOT NULL REFERENCES sprints(id) ON DELETE CASCADE,
+    snapshot_date DATE NOT NULL,
+    total_points INTEGER NOT NULL,
+    completed_points INTEGER NOT NULL,
+    total_tasks INTEGER NOT NULL,
+    completed_tasks INTEGER NOT NULL,
+    captured_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (sprint_id, snapshot_date)
+);
+
 -- ==================== TASKS TABLE ====================
 CREATE TABLE tasks (
     id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
     project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    parent_id UUID REFERENCES tasks(id) ON DELETE CASCADE,
     milestone_id UUID REFERENCES milestones(id) ON DELETE SET NULL,
+    sprint_id UUID REFERENCES sprints(id) ON DELETE SET NULL,
     title VARCHAR(255) NOT NULL,
     description TEXT,
     status task_status NOT NULL DEFAULT 'todo',
+    workflow_status VARCHAR(50) NOT NULL DEFAULT 'todo',
     priority priority NOT NULL DEFAULT 'medium',
     assignee_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    start_date TIMESTAMPTZ,
     due_date TIMESTAMPTZ,
     estimated_hours REAL,
     actual_hours REAL,
+    story_points INTEGER CHECK (story_points >= 0),
+    -- Order within the (project, workflow_status) board column
+    rank DOUBLE PRECISION NOT NULL DEFAULT 0,
+    -- Full-text search: title ranks above description
+    search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('english', coalesce(title, '')), 'A') ||
+        setweight(to_tsvector('english', coalesce(description, '')), 'B')
+    ) STORED,
+    -- Custom field values keyed by custom_fields.id
+    custom_fields JSONB NOT NULL DEFAULT '{}',
+    -- Set while the task is in the trash; subtasks deleted with it share the timestamp
+    deleted_at TIMESTAMPTZ,
     created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
     updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
 );
 
 CREATE INDEX idx_tasks_project ON tasks(project_id);
+CREATE INDEX idx_tasks_board_rank ON tasks(project_id, workflow_status, rank);
 CREATE INDEX idx_tasks_assignee ON tasks(assignee_id);
 CREATE INDEX idx_tasks_status ON tasks(status);
 CREATE INDEX idx_tasks_milestone ON tasks(milestone_id);
+CREATE INDEX idx_tasks_sprint ON tasks(sprint_id);
+CREATE INDEX idx_tasks_due_date ON tasks(due_date);
+CREATE INDEX idx_tasks_parent ON tasks(parent_id);
+CREATE INDEX idx_tasks_search ON tasks USING GIN(search_vector);
+CREATE INDEX idx_tasks_custom_fields ON tasks USING GIN(custom_fields jsonb_path_ops);
+CREATE INDEX idx_tasks_deleted ON tasks(project_id, deleted_at) WHERE deleted_at IS NOT NULL;
 
 -- ==================== TASK COMMENTS TABLE ====================
 CREATE TABLE task_comments (
     id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
     task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
     user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    parent_id UUID REFERENCES task_comments(id) ON DELETE CASCADE,
     content TEXT NOT NULL,
+    search_vector TSVECTOR GENERATED ALWAYS AS (to_tsvector('english', content)) STORED,
+    edited_at TIMESTAMPTZ,
     created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
     updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
 );
 
 CREATE INDEX idx_task_comments_task ON task_comments(task_id);
+CREATE INDEX idx_task_comments_parent ON task_comments(parent_id);
+CREATE INDEX idx_task_comments_search ON task_comments USING GIN(search_vector);
+
+-- Previous versions of edited comments
+CREATE TABLE task_comment_edits (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    comment_id UUID NOT NULL REFERENCES task_comments(id) ON DELETE CASCADE,
+    previous_content TEXT NOT NULL,
+    edited_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_task_comment_edits_comment ON task_comment_edits(comment_id);
 
 -- ==================== ACTIVITY LOG TABLE ====================
 CREATE TABLE activity_logs (
@@ -166,6 +252,9 @@ CREATE TRIGGER update_projects_updated_at BEFORE UPDATE ON projects
 CREATE TRIGGER update_milestones_updated_at BEFORE UPDATE ON milestones
     FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
 
+CREATE TRIGGER update_sprints_updated_at BEFORE UPDATE ON sprints
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
 CREATE TRIGGER update_tasks_updated_at BEFORE UPDATE ON tasks
     FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
 
@@ -240,6 +329,10 @@ CREATE TYPE notification_type AS ENUM (
     'task_updated',
     'task_completed',
     'task_due_soon',
+    'task_overdue',
+    'milestone_due_soon',
+    'milestone_overdue',
+    'sla_breached',
     'project_updated',
     'comment_added',
     'mention'
@@ -259,3 +352,282 @@ CREATE TABLE notifications (
 CREATE INDEX idx_notifications_user ON notifications(user_id);
 CREATE INDEX idx_notifications_user_unread ON notifications(user_id, is_read) WHERE is_read = false;
 CREATE INDEX idx_notifications_created ON notifications(created_at DESC);
+
+-- ==================== DUE REMINDERS TABLE ====================
+-- Due date reminders already sent; lead_minutes 0 is the overdue reminder.
+-- Keyed by due date so moving it re-arms the reminders; no FK as items are tasks or milestones
+CREATE TYPE due_item_type AS ENUM ('task', 'milestone');
+
+CREATE TABLE due_reminders (
+    item_type due_item_type NOT NULL,
+    item_id UUID NOT NULL,
+    due_date TIMESTAMPTZ NOT NULL,
+    lead_minutes INTEGER NOT NULL,
+    sent_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (item_type, item_id, due_date, lead_minutes)
+);
+
+-- ==================== PROJECT WORKFLOWS TABLE ====================
+-- Custom task workflow per project; projects without a row use the built-in workflow
+CREATE TABLE project_workflows (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID NOT NULL UNIQUE REFERENCES projects(id) ON DELETE CASCADE,
+    statuses JSONB NOT NULL,
+    transitions JSONB NOT NULL,
+    require_subtasks_done BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER update_project_workflows_updated_at BEFORE UPDATE ON project_workflows
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- ==================== TASK DEPENDENCIES TABLE ====================
+-- blocking_task_id blocks blocked_task_id; tasks may belong to different projects
+CREATE TABLE task_dependencies (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    blocking_task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    blocked_task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(blocking_task_id, blocked_task_id),
+    CHECK (blocking_task_id <> blocked_task_id)
+);
+
+CREATE INDEX idx_task_dependencies_blocking ON task_dependencies(blocking_task_id);
+CREATE INDEX idx_task_dependencies_blocked ON task_dependencies(blocked_task_id);
+
+-- ==================== RECURRING TASKS TABLE ====================
+-- Series of tasks generated from an RFC 5545 recurrence rule
+CREATE TABLE recurring_tasks (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    title VARCHAR(255) NOT NULL,
+    description TEXT,
+    priority priority NOT NULL DEFAULT 'medium',
+    assignee_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    estimated_hours REAL,
+    rrule TEXT NOT NULL,
+    dtstart TIMESTAMPTZ NOT NULL,
+    lead_days INTEGER NOT NULL DEFAULT 0 CHECK (lead_days >= 0),
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    generated_through DATE,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_recurring_tasks_project ON recurring_tasks(project_id);
+CREATE INDEX idx_recurring_tasks_active ON recurring_tasks(is_active) WHERE is_active = TRUE;
+
+CREATE TRIGGER update_recurring_tasks_updated_at BEFORE UPDATE ON recurring_tasks
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- Skipped, edited or generated occurrences of a series
+CREATE TABLE recurring_task_occurrences (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    recurring_task_id UUID NOT NULL REFERENCES recurring_tasks(id) ON DELETE CASCADE,
+    occurrence_date DATE NOT NULL,
+    skipped BOOLEAN NOT NULL DEFAULT FALSE,
+    title VARCHAR(255),
+    description TEXT,
+    priority priority,
+    assignee_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    due_date TIMESTAMPTZ,
+    estimated_hours REAL,
+    generated_at TIMESTAMPTZ,
+    task_id UUID REFERENCES tasks(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(recurring_task_id, occurrence_date)
+);
+
+CREATE TRIGGER update_recurring_task_occurrences_updated_at BEFORE UPDATE ON recurring_task_occurrences
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- ==================== TASK CHECKLIST ITEMS TABLE ====================
+CREATE TABLE task_checklist_items (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    content TEXT NOT NULL,
+    is_checked BOOLEAN NOT NULL DEFAULT FALSE,
+    position INTEGER NOT NULL DEFAULT 0,
+    checked_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    checked_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_task_checklist_items_task ON task_checklist_items(task_id, position);
+
+CREATE TRIGGER update_task_checklist_items_updated_at BEFORE UPDATE ON task_checklist_items
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- ==================== TASK TEMPLATES TABLE ====================
+-- Per-project defaults, tags and checklist applied when creating a task
+CREATE TABLE task_templates (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    title_pattern VARCHAR(255) NOT NULL,
+    description TEXT,
+    priority priority NOT NULL DEFAULT 'medium',
+    estimated_hours REAL,
+    tag_ids UUID[] NOT NULL DEFAULT '{}',
+    checklist TEXT[] NOT NULL DEFAULT '{}',
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_task_templates_project ON task_templates(project_id);
+
+CREATE TRIGGER update_task_templates_updated_at BEFORE UPDATE ON task_templates
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- ==================== TASK WATCHERS TABLE ====================
+-- Users notified about changes to a task besides its assignee
+CREATE TABLE task_watchers (
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (task_id, user_id)
+);
+
+CREATE INDEX idx_task_watchers_user ON task_watchers(user_id);
+
+-- ==================== CUSTOM FIELDS TABLE ====================
+CREATE TYPE custom_field_type AS ENUM ('text', 'number', 'date', 'single_select', 'multi_select', 'user');
+
+-- Per-project field definitions; values live in tasks.custom_fields
+CREATE TABLE custom_fields (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    field_type custom_field_type NOT NULL,
+    -- Allowed values of select fields
+    options TEXT[] NOT NULL DEFAULT '{}',
+    required BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(project_id, name)
+);
+
+CREATE INDEX idx_custom_fields_project ON custom_fields(project_id);
+
+CREATE TRIGGER update_custom_fields_updated_at BEFORE UPDATE ON custom_fields
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- ==================== CHANGE HISTORY TABLE ====================
+CREATE TYPE change_entity_type AS ENUM ('task', 'project');
+CREATE TYPE change_action AS ENUM ('created', 'updated', 'deleted', 'restored');
+
+-- Field-level before/after values per mutation. No foreign keys to the
+-- entity or project so history survives their deletion.
+CREATE TABLE change_history (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    entity_type change_entity_type NOT NULL,
+    entity_id UUID NOT NULL,
+    project_id UUID NOT NULL,
+    action change_action NOT NULL,
+    -- [{"field": ..., "old_value": ..., "new_value": ...}]
+    changes JSONB NOT NULL DEFAULT '[]',
+    changed_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_change_history_entity ON change_history(entity_type, entity_id, changed_at DESC);
+
+-- ==================== SLA POLICIES TABLE ====================
+CREATE TYPE sla_target AS ENUM ('response', 'resolution');
+
+-- Time limits per project and priority, in minutes from task creation:
+-- response until the task leaves Todo, resolution until it is done
+CREATE TABLE sla_policies (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    priority priority NOT NULL,
+    response_minutes INTEGER CHECK (response_minutes > 0),
+    resolution_minutes INTEGER CHECK (resolution_minutes > 0),
+    -- Count Monday to Friday 09:00-17:00 UTC only
+    business_hours BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(project_id, priority),
+    CHECK (response_minutes IS NOT NULL OR resolution_minutes IS NOT NULL)
+);
+
+CREATE TRIGGER update_sla_policies_updated_at BEFORE UPDATE ON sla_policies
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- ==================== SLA BREACHES TABLE ====================
+-- One row per task and missed target; kept when the policy is deleted
+CREATE TABLE sla_breaches (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    policy_id UUID REFERENCES sla_policies(id) ON DELETE SET NULL,
+    priority priority NOT NULL,
+    target sla_target NOT NULL,
+    target_minutes INTEGER NOT NULL,
+    breached_at TIMESTAMPTZ NOT NULL,
+    escalated_to UUID[] NOT NULL DEFAULT '{}',
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(task_id, target)
+);
+
+CREATE INDEX idx_sla_breaches_project ON sla_breaches(project_id, breached_at);
+
+-- ==================== COST RATES TABLE ====================
+-- Hourly cost of a user; a rate applies to time logged from its effective date
+-- until the user's next rate
+CREATE TABLE cost_rates (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    hourly_rate DECIMAL(10, 2) NOT NULL CHECK (hourly_rate >= 0),
+    effective_from DATE NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(user_id, effective_from)
+);
+
+CREATE TRIGGER update_cost_rates_updated_at BEFORE UPDATE ON cost_rates
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- ==================== EXPENSES TABLE ====================
+-- Non-labour costs booked against a project's budget
+CREATE TABLE expenses (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    category VARCHAR(100) NOT NULL,
+    description TEXT,
+    amount DECIMAL(15, 2) NOT NULL CHECK (amount > 0),
+    incurred_on DATE NOT NULL,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_expenses_project ON expenses(project_id, incurred_on);
+
+CREATE TRIGGER update_expenses_updated_at BEFORE UPDATE ON expenses
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- ==================== PROJECT TEMPLATES TABLE ====================
+-- Project skeletons; dates are seconds from the start of the source project
+CREATE TABLE project_templates (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    priority priority NOT NULL DEFAULT 'medium',
+    end_offset_secs BIGINT,
+    milestones JSONB NOT NULL DEFAULT '[]',
+    tasks JSONB NOT NULL DEFAULT '[]',
+    members JSONB NOT NULL DEFAULT '[]',
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER update_project_templates_updated_at BEFORE UPDATE ON project_templates
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
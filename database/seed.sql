@@ -27,11 +27,11 @@ INSERT INTO projects (id, name, description, status, priority, start_date, end_d
 
 -- Insert project members
 INSERT INTO project_members (project_id, user_id, role) VALUES
-    ('10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', 'b0eebc99-9c0b-4ef8-bb6d-6bb9bd380a12', 'Project Manager'),
-    ('10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', 'c0eebc99-9c0b-4ef8-bb6d-6bb9bd380a13', 'Frontend Developer'),
-    ('10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', 'd0eebc99-9c0b-4ef8-bb6d-6bb9bd380a14', 'Backend Developer'),
-    ('20eebc99-9c0b-4ef8-bb6d-6bb9bd380a18', 'b0eebc99-9c0b-4ef8-bb6d-6bb9bd380a12', 'Project Manager'),
-    ('20eebc99-9c0b-4ef8-bb6d-6bb9bd380a18', 'c0eebc99-9c0b-4ef8-bb6d-6bb9bd380a13', 'Mobile Developer');
+    ('10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', 'b0eebc99-9c0b-4ef8-bb6d-6bb9bd380a12', 'maintainer'),
+    ('10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', 'c0eebc99-9c0b-4ef8-bb6d-6bb9bd380a13', 'contributor'),
+    ('10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', 'd0eebc99-9c0b-4ef8-bb6d-6bb9bd380a14', 'contributor'),
+    ('20eebc99-9c0b-4ef8-bb6d-6bb9bd380a18', 'b0eebc99-9c0b-4ef8-bb6d-6bb9bd380a12', 'maintainer'),
+    ('20eebc99-9c0b-4ef8-bb6d-6bb9bd380a18', 'c0eebc99-9c0b-4ef8-bb6d-6bb9bd380a13', 'contributor');
 
 -- Insert milestones
 INSERT INTO milestones (id, project_id, name, description, due_date, completed) VALUES
@@ -40,12 +40,12 @@ INSERT INTO milestones (id, project_id, name, description, due_date, completed)
     ('60eebc99-9c0b-4ef8-bb6d-6bb9bd380a22', '10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', 'Launch', 'Go live with new website', '2024-06-30', false);
 
 -- Insert tasks
-INSERT INTO tasks (id, project_id, milestone_id, title, description, status, priority, assignee_id, due_date, estimated_hours) VALUES
-    ('70eebc99-9c0b-4ef8-bb6d-6bb9bd380a23', '10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', '50eebc99-9c0b-4ef8-bb6d-6bb9bd380a21', 'Setup Next.js project', 'Initialize Next.js with TypeScript', 'done', 'high', 'c0eebc99-9c0b-4ef8-bb6d-6bb9bd380a13', '2024-02-20', 8),
-    ('80eebc99-9c0b-4ef8-bb6d-6bb9bd380a24', '10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', '50eebc99-9c0b-4ef8-bb6d-6bb9bd380a21', 'Implement homepage', 'Build the new homepage design', 'inprogress', 'high', 'c0eebc99-9c0b-4ef8-bb6d-6bb9bd380a13', '2024-03-01', 24),
-    ('90eebc99-9c0b-4ef8-bb6d-6bb9bd380a25', '10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', '50eebc99-9c0b-4ef8-bb6d-6bb9bd380a21', 'Setup API endpoints', 'Create REST API for the website', 'inprogress', 'high', 'd0eebc99-9c0b-4ef8-bb6d-6bb9bd380a14', '2024-03-15', 40),
-    ('a1eebc99-9c0b-4ef8-bb6d-6bb9bd380a26', '10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', '50eebc99-9c0b-4ef8-bb6d-6bb9bd380a21', 'User authentication', 'Implement login/register flow', 'todo', 'critical', 'd0eebc99-9c0b-4ef8-bb6d-6bb9bd380a14', '2024-03-20', 32),
-    ('b1eebc99-9c0b-4ef8-bb6d-6bb9bd380a27', '10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', '60eebc99-9c0b-4ef8-bb6d-6bb9bd380a22', 'Deploy to production', 'Setup CI/CD and deploy', 'todo', 'medium', NULL, '2024-06-25', 16);
+INSERT INTO tasks (id, project_id, milestone_id, title, description, status, workflow_status, priority, assignee_id, due_date, estimated_hours) VALUES
+    ('70eebc99-9c0b-4ef8-bb6d-6bb9bd380a23', '10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', '50eebc99-9c0b-4ef8-bb6d-6bb9bd380a21', 'Setup Next.js project', 'Initialize Next.js with TypeScript', 'done', 'done', 'high', 'c0eebc99-9c0b-4ef8-bb6d-6bb9bd380a13', '2024-02-20', 8),
+    ('80eebc99-9c0b-4ef8-bb6d-6bb9bd380a24', '10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', '50eebc99-9c0b-4ef8-bb6d-6bb9bd380a21', 'Implement homepage', 'Build the new homepage design', 'inprogress', 'inprogress', 'high', 'c0eebc99-9c0b-4ef8-bb6d-6bb9bd380a13', '2024-03-01', 24),
+    ('90eebc99-9c0b-4ef8-bb6d-6bb9bd380a25', '10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', '50eebc99-9c0b-4ef8-bb6d-6bb9bd380a21', 'Setup API endpoints', 'Create REST API for the website', 'inprogress', 'inprogress', 'high', 'd0eebc99-9c0b-4ef8-bb6d-6bb9bd380a14', '2024-03-15', 40),
+    ('a1eebc99-9c0b-4ef8-bb6d-6bb9bd380a26', '10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', '50eebc99-9c0b-4ef8-bb6d-6bb9bd380a21', 'User authentication', 'Implement login/register flow', 'todo', 'todo', 'critical', 'd0eebc99-9c0b-4ef8-bb6d-6bb9bd380a14', '2024-03-20', 32),
+    ('b1eebc99-9c0b-4ef8-bb6d-6bb9bd380a27', '10eebc99-9c0b-4ef8-bb6d-6bb9bd380a17', '60eebc99-9c0b-4ef8-bb6d-6bb9bd380a22', 'Deploy to production', 'Setup CI/CD and deploy', 'todo', 'todo', 'medium', NULL, '2024-06-25', 16);
 
 -- Insert task comments
 INSERT INTO task_comments (task_id, user_id, content) VALUES
@@ -66,7 +66,13 @@ export default function NotificationsPage() {
       case 'task_completed':
         return '✅';
       case 'task_due_soon':
+      case 'milestone_due_soon':
         return '⏰';
+      case 'task_overdue':
+      case 'milestone_overdue':
+        return '⚠️';
+      case 'sla_breached':
+        return '🚨';
       case 'project_updated':
         return '📁';
       case 'comment_added':
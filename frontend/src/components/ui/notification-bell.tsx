@@ -91,7 +91,13 @@ export function NotificationBell() {
       case 'task_completed':
         return '✅';
       case 'task_due_soon':
+      case 'milestone_due_soon':
         return '⏰';
+      case 'task_overdue':
+      case 'milestone_overdue':
+        return '⚠️';
+      case 'sla_breached':
+        return '🚨';
       case 'project_updated':
         return '📁';
       case 'comment_added':
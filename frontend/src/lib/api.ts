@@ -13,6 +13,7 @@ import type {
   Tag,
   Attachment,
   Notification,
+  Paginated,
 } from '@/types';
 
 const API_URL = process.env.NEXT_PUBLIC_API_URL || 'http://localhost:8080/api/v1';
@@ -96,8 +97,24 @@ export const projectsApi = {
 
 // Tasks
 export const tasksApi = {
-  list: async () => {
-    const { data } = await api.get<ApiResponse<Task[]>>('/tasks');
+  // Fetches every page so callers get the full list
+  list: async (): Promise<ApiResponse<Task[]>> => {
+    const tasks: Task[] = [];
+    for (let page = 1; ; page++) {
+      const { data } = await api.get<ApiResponse<Paginated<Task>>>('/tasks', {
+        params: { page, per_page: 100 },
+      });
+      if (!data.success || !data.data) {
+        return { success: data.success, message: data.message };
+      }
+      tasks.push(...data.data.items);
+      if (page >= data.data.total_pages) {
+        return { success: true, data: tasks };
+      }
+    }
+  },
+  filter: async (params: Record<string, string | number | boolean>) => {
+    const { data } = await api.get<ApiResponse<Paginated<Task>>>('/tasks', { params });
     return data;
   },
   get: async (id: string) => {
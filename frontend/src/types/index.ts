@@ -160,6 +160,10 @@ export type NotificationType =
   | 'task_updated'
   | 'task_completed'
   | 'task_due_soon'
+  | 'task_overdue'
+  | 'milestone_due_soon'
+  | 'milestone_overdue'
+  | 'sla_breached'
   | 'project_updated'
   | 'comment_added'
   | 'mention';
@@ -181,3 +185,11 @@ export interface ApiResponse<T> {
   data?: T;
   message?: string;
 }
+
+export interface Paginated<T> {
+  items: T[];
+  total: number;
+  page: number;
+  per_page: number;
+  total_pages: number;
+}